@@ -0,0 +1,38 @@
+// Replaces `next/dist/shared/lib/image-loader` when images are pregenerated
+// at build time. Images with pregenerated variants are served from the static
+// files, all other images are passed to the default loader.
+import defaultLoader from 'next/dist/shared/lib/image-loader.js'
+import type { ImageLoaderPropsWithConfig } from 'next/dist/shared/lib/image-config'
+
+type ImageVariant = {
+  src: string
+  width: number
+  type: string | null
+}
+
+const variantsBySrc = new Map<string, ImageVariant[]>()
+
+// Called by statically imported images with the variants generated for them,
+// ordered by preference of their format.
+export function registerImageVariants(src: string, variants: ImageVariant[]) {
+  variantsBySrc.set(src, variants)
+}
+
+export default function imageLoader(props: ImageLoaderPropsWithConfig) {
+  const variants = variantsBySrc.get(props.src)
+  if (!variants || variants.length === 0) {
+    return defaultLoader(props)
+  }
+  // Static files can't negotiate the format, so the preferred one is used.
+  const candidates = variants
+    .filter((variant) => variant.type === variants[0].type)
+    .sort((a, b) => a.width - b.width)
+  const variant =
+    candidates.find((candidate) => candidate.width >= props.width) ??
+    candidates[candidates.length - 1]
+  return variant.src
+}
+
+// Marks this as the default loader, so `next/image` passes the image config
+// to it, which is needed by the default loader for other images.
+imageLoader.__next_img_default = true
@@ -24,6 +24,7 @@ use turbopack_core::{
     free_var_references,
     resolve::{parse::Request, pattern::Pattern},
 };
+use turbopack_json::DEFAULT_OBJECT_LITERAL_THRESHOLD;
 use turbopack_node::{
     execution_context::ExecutionContext,
     transforms::postcss::{PostCssConfigLocation, PostCssTransformOptions},
@@ -37,6 +38,7 @@ use crate::{
     next_client::runtime_entry::{RuntimeEntries, RuntimeEntry},
     next_config::NextConfig,
     next_font::local::NextFontLocalResolvePlugin,
+    next_image::get_svg_minify_options,
     next_import_map::{
         get_next_client_fallback_import_map, get_next_client_import_map,
         get_next_client_resolved_map,
@@ -278,6 +280,8 @@ pub async fn get_client_module_options_context(
     let module_options_context = ModuleOptionsContext {
         ecmascript: EcmascriptOptionsContext {
             enable_typeof_window_inlining: Some(TypeofWindow::Object),
+            // The chunking context enables hot module replacement in development
+            enable_hot_module_replacement: next_mode.is_development(),
             ..Default::default()
         },
         preset_env_versions: Some(env),
@@ -285,6 +289,9 @@ pub async fn get_client_module_options_context(
         tree_shaking_mode: tree_shaking_mode_for_user_code,
         enable_postcss_transform,
         side_effect_free_packages: next_config.optimize_package_imports().await?.clone_value(),
+        enable_svg_minification: get_svg_minify_options(next_config, mode).await?,
+        enable_svg_components: *next_config.enable_svg_components().await?,
+        json_object_literal_threshold: Some(DEFAULT_OBJECT_LITERAL_THRESHOLD),
         ..Default::default()
     };
 
@@ -20,6 +20,7 @@ use turbopack_ecmascript_plugins::transform::{
     styled_components::StyledComponentsTransformConfig,
 };
 use turbopack_node::transforms::webpack::{WebpackLoaderItem, WebpackLoaderItems};
+use turbopack_wasm::wasi::WasiOptions;
 
 use crate::{
     next_import_map::mdx_import_source_file, next_shared::transforms::ModularizeImportPackageConfig,
@@ -399,6 +400,29 @@ pub struct ExperimentalTurboConfig {
     pub resolve_alias: Option<IndexMap<RcStr, JsonValue>>,
     pub resolve_extensions: Option<Vec<RcStr>>,
     pub use_swc_css: Option<bool>,
+    /// Generates resized and converted variants of statically imported images
+    /// at build time, which are served by the image loader instead of the
+    /// image optimization API.
+    pub pregenerate_images: Option<bool>,
+    /// Turns SVGs imported with the `?react` query into React components.
+    pub svg_components: Option<bool>,
+    /// Options for WebAssembly modules importing WASI preview 1 in the
+    /// Node.js runtime.
+    pub wasi: Option<WasiConfig>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs)]
+#[serde(rename_all = "camelCase")]
+pub struct WasiConfig {
+    /// Passes the command line arguments and the environment variables of the
+    /// server process to WebAssembly modules. Disabled by default, so modules
+    /// can't read secrets from the environment.
+    #[serde(default)]
+    pub inherit_env: bool,
+    /// The directories WebAssembly modules can access, mapped from the path
+    /// seen by the module to the path on the host.
+    #[serde(default)]
+    pub preopens: IndexMap<RcStr, RcStr>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs)]
@@ -1089,6 +1113,47 @@ impl NextConfig {
         ))
     }
 
+    #[turbo_tasks::function]
+    pub async fn pregenerate_images(self: Vc<Self>) -> Result<Vc<bool>> {
+        Ok(Vc::cell(
+            self.await?
+                .experimental
+                .turbo
+                .as_ref()
+                .and_then(|turbo| turbo.pregenerate_images)
+                .unwrap_or(false),
+        ))
+    }
+
+    #[turbo_tasks::function]
+    pub async fn enable_svg_components(self: Vc<Self>) -> Result<Vc<bool>> {
+        Ok(Vc::cell(
+            self.await?
+                .experimental
+                .turbo
+                .as_ref()
+                .and_then(|turbo| turbo.svg_components)
+                .unwrap_or(false),
+        ))
+    }
+
+    #[turbo_tasks::function]
+    pub async fn wasi_options(self: Vc<Self>) -> Result<Vc<WasiOptions>> {
+        let this = self.await?;
+        let wasi = this
+            .experimental
+            .turbo
+            .as_ref()
+            .and_then(|turbo| turbo.wasi.as_ref());
+        Ok(WasiOptions {
+            inherit_env: wasi.is_some_and(|wasi| wasi.inherit_env),
+            preopens: wasi
+                .map(|wasi| wasi.preopens.clone().into_iter().collect())
+                .unwrap_or_default(),
+        }
+        .cell())
+    }
+
     #[turbo_tasks::function]
     pub async fn optimize_package_imports(self: Vc<Self>) -> Result<Vc<Vec<RcStr>>> {
         Ok(Vc::cell(
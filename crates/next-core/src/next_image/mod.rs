@@ -1,4 +1,25 @@
 pub(crate) mod module;
 pub(crate) mod source_asset;
+pub(crate) mod variant_source;
 
-pub use module::StructuredImageModuleType;
+use anyhow::Result;
+pub use module::{ImageVariantOptions, StructuredImageModuleType};
+use turbo_tasks::Vc;
+use turbopack_image::svg::SvgMinifyOptions;
+
+use crate::{mode::NextMode, next_config::NextConfig};
+
+/// Returns the options to minify SVGs with. Unless `images.dangerouslyAllowSVG`
+/// is enabled, scripts are removed from SVGs in every mode, as the Content
+/// Security Policy of the image optimization API would block them. Otherwise
+/// SVGs are only minified in builds.
+pub async fn get_svg_minify_options(
+    next_config: Vc<NextConfig>,
+    mode: Vc<NextMode>,
+) -> Result<Option<Vc<SvgMinifyOptions>>> {
+    let remove_scripts = !next_config.image_config().await?.dangerously_allow_svg;
+    if !remove_scripts && *mode.await? != NextMode::Build {
+        return Ok(None);
+    }
+    Ok(Some(SvgMinifyOptions { remove_scripts }.cell()))
+}
@@ -3,12 +3,16 @@ use indexmap::indexmap;
 use turbo_tasks::{TaskInput, Value, Vc};
 use turbopack::{module_options::CustomModuleType, ModuleAssetContext};
 use turbopack_core::{
-    context::AssetContext, module::Module, reference_type::ReferenceType, resolve::ModulePart,
-    source::Source,
+    asset::Asset, context::AssetContext, module::Module, reference_type::ReferenceType,
+    resolve::ModulePart, source::Source,
 };
+use turbopack_image::process::{get_meta_data, ImageOutputFormat};
 use turbopack_static::StaticModuleAsset;
 
-use super::source_asset::StructuredImageFileSource;
+use super::{
+    source_asset::StructuredImageFileSource,
+    variant_source::{variant_request, ImageVariant, ImageVariantSource},
+};
 
 #[turbo_tasks::value(serialization = "auto_for_input")]
 #[derive(Clone, Copy, Debug, PartialOrd, Ord, Hash, TaskInput)]
@@ -26,11 +30,72 @@ pub enum BlurPlaceholderMode {
     NextImageUrl,
 }
 
+/// Configures the responsive variants which are generated for each image.
+#[turbo_tasks::value(shared)]
+pub struct ImageVariantOptions {
+    /// The widths to generate, in ascending order. Widths which aren't
+    /// smaller than the image itself are replaced by its intrinsic width.
+    pub widths: Vec<u32>,
+    /// The formats to convert images to, in addition to their original
+    /// format, in order of preference.
+    pub formats: Vec<ImageOutputFormat>,
+    pub quality: u8,
+}
+
+/// Returns the variants which should be generated for `source`. Only images
+/// which can be decoded get variants, and vector or animated formats are left
+/// as they are.
+async fn image_variants(
+    source: Vc<Box<dyn Source>>,
+    options: Vc<ImageVariantOptions>,
+) -> Result<Vec<ImageVariant>> {
+    let info = get_meta_data(source.ident(), source.content().file_content(), None).await?;
+    // list should match the formats supported by the image optimization API
+    if !matches!(
+        info.mime_type.as_ref().map(|mime| mime.essence_str()),
+        Some("image/png" | "image/jpeg" | "image/webp" | "image/avif")
+    ) {
+        return Ok(Vec::new());
+    }
+    Ok(variants_for_width(info.width, &*options.await?))
+}
+
+/// Returns the variants of an image `image_width` pixels wide, grouped by
+/// format in order of preference, with the original format last. The image
+/// is never upscaled, but there is always a variant with its intrinsic width.
+fn variants_for_width(image_width: u32, options: &ImageVariantOptions) -> Vec<ImageVariant> {
+    let mut widths = options
+        .widths
+        .iter()
+        .copied()
+        .filter(|&width| width < image_width)
+        .collect::<Vec<_>>();
+    widths.push(image_width);
+    let mut variants = Vec::new();
+    for &format in options.formats.iter() {
+        variants.extend(widths.iter().map(|&width| ImageVariant {
+            width,
+            format: Some(format),
+            quality: options.quality,
+        }));
+    }
+    variants.extend(widths.iter().map(|&width| ImageVariant {
+        width,
+        format: None,
+        quality: options.quality,
+    }));
+    variants
+}
+
 /// Module type that analyzes images and offers some meta information like
 /// width, height and blur placeholder as export from the module.
+///
+/// When `variants` are configured, resized and converted versions of the image
+/// are emitted as assets as well, and exported as `srcSet` and `variants`.
 #[turbo_tasks::value]
 pub struct StructuredImageModuleType {
     pub blur_placeholder_mode: BlurPlaceholderMode,
+    pub variants: Option<Vc<ImageVariantOptions>>,
 }
 
 #[turbo_tasks::value_impl]
@@ -39,30 +104,62 @@ impl StructuredImageModuleType {
     pub(crate) async fn create_module(
         source: Vc<Box<dyn Source>>,
         blur_placeholder_mode: BlurPlaceholderMode,
+        variant_options: Option<Vc<ImageVariantOptions>>,
         module_asset_context: Vc<ModuleAssetContext>,
     ) -> Result<Vc<Box<dyn Module>>> {
         let static_asset = StaticModuleAsset::new(source, Vc::upcast(module_asset_context));
+        let variants = match variant_options {
+            Some(options) => image_variants(source, options).await?,
+            None => Vec::new(),
+        };
+        // The variants are only encoded when the code of the image module is
+        // generated, see [StructuredImageFileSource].
+        let variants = variants
+            .into_iter()
+            .map(|variant| {
+                ImageVariantSource {
+                    image: source,
+                    variant,
+                }
+                .cell()
+            })
+            .collect::<Vec<_>>();
+        let mut inner_assets = indexmap!(
+            "IMAGE".into() => Vc::upcast(static_asset)
+        );
+        for (i, &variant_source) in variants.iter().enumerate() {
+            inner_assets.insert(
+                variant_request(i),
+                Vc::upcast(StaticModuleAsset::new(
+                    Vc::upcast(variant_source),
+                    Vc::upcast(module_asset_context),
+                )),
+            );
+        }
         let module = module_asset_context
             .process(
                 Vc::upcast(
                     StructuredImageFileSource {
                         image: source,
                         blur_placeholder_mode,
+                        variants,
                     }
                     .cell(),
                 ),
-                Value::new(ReferenceType::Internal(Vc::cell(indexmap!(
-                    "IMAGE".into() => Vc::upcast(static_asset)
-                )))),
+                Value::new(ReferenceType::Internal(Vc::cell(inner_assets))),
             )
             .module();
         Ok(module)
     }
 
     #[turbo_tasks::function]
-    pub fn new(blur_placeholder_mode: Value<BlurPlaceholderMode>) -> Vc<Self> {
+    pub fn new(
+        blur_placeholder_mode: Value<BlurPlaceholderMode>,
+        variants: Option<Vc<ImageVariantOptions>>,
+    ) -> Vc<Self> {
         StructuredImageModuleType::cell(StructuredImageModuleType {
             blur_placeholder_mode: blur_placeholder_mode.into_value(),
+            variants,
         })
     }
 }
@@ -79,7 +176,62 @@ impl CustomModuleType for StructuredImageModuleType {
         StructuredImageModuleType::create_module(
             source,
             self.blur_placeholder_mode,
+            self.variants,
             module_asset_context,
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use turbopack_image::process::ImageOutputFormat;
+
+    use super::{variants_for_width, ImageVariantOptions};
+    use crate::next_image::variant_source::ImageVariant;
+
+    #[test]
+    fn test_variants_for_width() {
+        let options = ImageVariantOptions {
+            widths: vec![320, 640, 1280],
+            formats: vec![ImageOutputFormat::Avif, ImageOutputFormat::Webp],
+            quality: 75,
+        };
+        let variant = |width, format| ImageVariant {
+            width,
+            format,
+            quality: 75,
+        };
+        assert_eq!(
+            variants_for_width(800, &options),
+            vec![
+                variant(320, Some(ImageOutputFormat::Avif)),
+                variant(640, Some(ImageOutputFormat::Avif)),
+                variant(800, Some(ImageOutputFormat::Avif)),
+                variant(320, Some(ImageOutputFormat::Webp)),
+                variant(640, Some(ImageOutputFormat::Webp)),
+                variant(800, Some(ImageOutputFormat::Webp)),
+                variant(320, None),
+                variant(640, None),
+                variant(800, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_variants_for_small_image() {
+        let options = ImageVariantOptions {
+            widths: vec![320, 640],
+            formats: vec![ImageOutputFormat::Webp],
+            quality: 75,
+        };
+        let variant = |format| ImageVariant {
+            width: 100,
+            format,
+            quality: 75,
+        };
+        assert_eq!(
+            variants_for_width(100, &options),
+            vec![variant(Some(ImageOutputFormat::Webp)), variant(None)]
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use std::io::Write;
 
 use anyhow::{bail, Result};
-use turbo_tasks::{RcStr, Vc};
+use turbo_tasks::{RcStr, TryJoinIterExt, Vc};
 use turbo_tasks_fs::{rope::RopeBuilder, FileContent};
 use turbopack_core::{
     asset::{Asset, AssetContent},
@@ -11,7 +11,11 @@ use turbopack_core::{
 use turbopack_ecmascript::utils::StringifyJs;
 use turbopack_image::process::{get_meta_data, BlurPlaceholderOptions};
 
-use super::module::BlurPlaceholderMode;
+use super::{
+    module::BlurPlaceholderMode,
+    variant_source::{variant_request, ImageVariant, ImageVariantSource},
+};
+use crate::embed_js::VIRTUAL_PACKAGE_NAME;
 
 fn modifier() -> Vc<RcStr> {
     Vc::cell("structured image object".into())
@@ -28,10 +32,16 @@ fn blur_options() -> Vc<BlurPlaceholderOptions> {
 
 /// An source asset that transforms an image into javascript code which exports
 /// an object with meta information like width, height and a blur placeholder.
+///
+/// The `variants` are imported from `VARIANT_{index}` and exported as
+/// `srcSet` and `variants`, and registered with the image loader. Variants
+/// which can't be encoded are skipped, so a file is never served with a
+/// format it doesn't have. The failure has been reported as an issue already.
 #[turbo_tasks::value(shared)]
 pub struct StructuredImageFileSource {
     pub image: Vc<Box<dyn Source>>,
     pub blur_placeholder_mode: BlurPlaceholderMode,
+    pub variants: Vec<Vc<ImageVariantSource>>,
 }
 
 #[turbo_tasks::value_impl]
@@ -55,8 +65,23 @@ impl Asset for StructuredImageFileSource {
         };
         let mut result = RopeBuilder::from("");
         writeln!(result, "import src from \"IMAGE\";",)?;
+        let variants = self.encoded_variants().await?;
+        let variant_exports = if variants.is_empty() {
+            ""
+        } else {
+            self.write_variants(&mut result, content, &variants).await?;
+            ", srcSet, variants"
+        };
         let blur_options = blur_options();
-        match self.blur_placeholder_mode {
+        let is_svg = self.image.ident().path().await?.extension_ref() == Some("svg");
+        let blur_placeholder_mode = match self.blur_placeholder_mode {
+            // The image optimization API only serves SVGs with `dangerouslyAllowSVG`
+            // and doesn't rasterize them, so SVGs get no blur placeholder, like
+            // with data urls.
+            BlurPlaceholderMode::NextImageUrl if is_svg => BlurPlaceholderMode::None,
+            mode => mode,
+        };
+        match blur_placeholder_mode {
             BlurPlaceholderMode::NextImageUrl => {
                 let info = get_meta_data(self.image.ident(), content, None).await?;
                 let width = info.width;
@@ -77,7 +102,7 @@ impl Asset for StructuredImageFileSource {
                     result,
                     "export default {{ src, width: {width}, height: {height}, blurDataURL: \
                      `/_next/image?w={blur_width}&q={quality}&url=${{encodeURIComponent(src)}}`, \
-                     blurWidth: {blur_width}, blurHeight: {blur_height} }}",
+                     blurWidth: {blur_width}, blurHeight: {blur_height}{variant_exports} }}",
                     width = StringifyJs(&info.width),
                     height = StringifyJs(&info.height),
                     quality = StringifyJs(&blur_options.quality),
@@ -90,7 +115,8 @@ impl Asset for StructuredImageFileSource {
                 writeln!(
                     result,
                     "export default {{ src, width: {width}, height: {height}, blurDataURL: \
-                     {blur_data_url}, blurWidth: {blur_width}, blurHeight: {blur_height} }}",
+                     {blur_data_url}, blurWidth: {blur_width}, blurHeight: \
+                     {blur_height}{variant_exports} }}",
                     width = StringifyJs(&info.width),
                     height = StringifyJs(&info.height),
                     blur_data_url =
@@ -105,7 +131,7 @@ impl Asset for StructuredImageFileSource {
                 let info = get_meta_data(self.image.ident(), content, None).await?;
                 writeln!(
                     result,
-                    "export default {{ src, width: {width}, height: {height} }}",
+                    "export default {{ src, width: {width}, height: {height}{variant_exports} }}",
                     width = StringifyJs(&info.width),
                     height = StringifyJs(&info.height),
                 )?;
@@ -114,3 +140,70 @@ impl Asset for StructuredImageFileSource {
         Ok(AssetContent::File(FileContent::Content(result.build().into()).cell()).cell())
     }
 }
+
+impl StructuredImageFileSource {
+    /// Returns the variants which could be encoded, with their index. They are
+    /// encoded concurrently.
+    async fn encoded_variants(&self) -> Result<Vec<(usize, ImageVariant)>> {
+        let variants = self
+            .variants
+            .iter()
+            .enumerate()
+            .map(|(i, &variant_source)| async move {
+                let content = Vc::upcast::<Box<dyn Source>>(variant_source)
+                    .content()
+                    .file_content()
+                    .await?;
+                Ok(match &*content {
+                    FileContent::Content(_) => Some((i, variant_source.await?.variant)),
+                    FileContent::NotFound => None,
+                })
+            })
+            .try_join()
+            .await?;
+        Ok(variants.into_iter().flatten().collect())
+    }
+
+    /// Imports the variants and declares `variants` and a `srcSet` of the
+    /// variants in the preferred format.
+    async fn write_variants(
+        &self,
+        result: &mut RopeBuilder,
+        content: Vc<FileContent>,
+        variants: &[(usize, ImageVariant)],
+    ) -> Result<()> {
+        let info = get_meta_data(self.image.ident(), content, None).await?;
+        let original_type = info.mime_type.as_ref().map(|mime| mime.essence_str());
+        for &(i, _) in variants {
+            writeln!(
+                result,
+                "import variant{i} from {};",
+                StringifyJs(&variant_request(i))
+            )?;
+        }
+        writeln!(
+            result,
+            "import {{ registerImageVariants }} from \
+             \"{VIRTUAL_PACKAGE_NAME}/internal/image-loader\";"
+        )?;
+        writeln!(result, "const variants = [")?;
+        for (i, variant) in variants {
+            writeln!(
+                result,
+                "  {{ src: variant{i}, width: {width}, type: {ty} }},",
+                width = StringifyJs(&variant.width),
+                ty = StringifyJs(&variant.mime_type().or(original_type)),
+            )?;
+        }
+        writeln!(result, "];")?;
+        // Variants are ordered by preference, so the srcSet uses the format of
+        // the first one.
+        writeln!(
+            result,
+            "const srcSet = variants\n  .filter((variant) => variant.type === variants[0].type)\n  \
+             .map((variant) => `${{variant.src}} ${{variant.width}}w`)\n  .join(\", \");"
+        )?;
+        writeln!(result, "registerImageVariants(src, variants);")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use turbo_tasks::{debug::ValueDebugFormat, trace::TraceRawVcs, RcStr, Vc};
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    ident::AssetIdent,
+    source::Source,
+};
+use turbopack_image::process::{optimize, optimize_as, ImageOutputFormat};
+
+/// A resized and optionally converted version of an image.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, ValueDebugFormat,
+)]
+pub struct ImageVariant {
+    pub width: u32,
+    /// The format to convert the image to, or `None` to keep its format.
+    pub format: Option<ImageOutputFormat>,
+    pub quality: u8,
+}
+
+impl ImageVariant {
+    /// The mime type of the variant, or `None` when it has the format of the
+    /// original image.
+    pub fn mime_type(&self) -> Option<&'static str> {
+        self.format.map(|format| format.mime_type())
+    }
+}
+
+/// A source of an image variant, resized to the width of the variant.
+///
+/// The content is [turbo_tasks_fs::FileContent::NotFound] when the image
+/// can't be encoded as the variant.
+#[turbo_tasks::value(shared)]
+pub struct ImageVariantSource {
+    pub image: Vc<Box<dyn Source>>,
+    pub variant: ImageVariant,
+}
+
+#[turbo_tasks::value_impl]
+impl Source for ImageVariantSource {
+    #[turbo_tasks::function]
+    async fn ident(&self) -> Result<Vc<AssetIdent>> {
+        let ImageVariant {
+            width,
+            format,
+            quality,
+        } = self.variant;
+        let path = self.image.ident().path();
+        let path_ref = path.await?;
+        let file_name = path_ref.file_name();
+        let (stem, extension) = match path_ref.extension_ref() {
+            Some(extension) => (
+                &file_name[..file_name.len() - extension.len() - 1],
+                extension,
+            ),
+            None => (file_name, ""),
+        };
+        let extension = format.map_or(extension, |format| format.extension());
+        Ok(AssetIdent::from_path(
+            path.parent()
+                .join(format!("{stem}-{width}w.{extension}").into()),
+        )
+        .with_modifier(Vc::cell(
+            format!("image variant {width}w q{quality}").into(),
+        )))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for ImageVariantSource {
+    #[turbo_tasks::function]
+    async fn content(&self) -> Result<Vc<AssetContent>> {
+        let ImageVariant {
+            width,
+            format,
+            quality,
+        } = self.variant;
+        let ident = self.image.ident();
+        let original = self.image.content().file_content();
+        let content = match format {
+            Some(format) => optimize_as(ident, original, width, u32::MAX, quality, format),
+            None => optimize(ident, original, width, u32::MAX, quality),
+        };
+        Ok(AssetContent::file(content))
+    }
+}
+
+/// Returns a name for the variant to be used in generated code.
+pub fn variant_request(index: usize) -> RcStr {
+    format!("VARIANT_{index}").into()
+}
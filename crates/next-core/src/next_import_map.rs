@@ -922,6 +922,16 @@ async fn insert_next_shared_aliases(
                 request_to_import_mapping(project_path, loader_file),
             );
         }
+    } else if *next_config.pregenerate_images().await? {
+        let loader = request_to_import_mapping(
+            project_path,
+            &format!("{VIRTUAL_PACKAGE_NAME}/internal/image-loader"),
+        );
+        import_map.insert_exact_alias("next/dist/shared/lib/image-loader", loader);
+
+        if is_runtime_edge {
+            import_map.insert_exact_alias("next/dist/esm/shared/lib/image-loader", loader);
+        }
     }
 
     Ok(())
@@ -25,6 +25,7 @@ use turbopack_ecmascript::{references::esm::UrlRewriteBehavior, TreeShakingMode}
 use turbopack_ecmascript_plugins::transform::directives::{
     client::ClientDirectiveTransformer, client_disallowed::ClientDisallowedDirectiveTransformer,
 };
+use turbopack_json::DEFAULT_OBJECT_LITERAL_THRESHOLD;
 use turbopack_node::{
     execution_context::ExecutionContext,
     transforms::postcss::{PostCssConfigLocation, PostCssTransformOptions},
@@ -42,6 +43,7 @@ use crate::{
     next_client::RuntimeEntries,
     next_config::NextConfig,
     next_font::local::NextFontLocalResolvePlugin,
+    next_image::get_svg_minify_options,
     next_import_map::get_next_server_import_map,
     next_server::resolve::ExternalPredicate,
     next_shared::{
@@ -494,6 +496,12 @@ pub async fn get_server_module_options_context(
         },
         tree_shaking_mode: tree_shaking_mode_for_user_code,
         side_effect_free_packages: next_config.optimize_package_imports().await?.clone_value(),
+        enable_svg_minification: get_svg_minify_options(next_config, mode).await?,
+        enable_svg_components: *next_config.enable_svg_components().await?,
+        // WASI modules can only be bundled for the Node.js runtime.
+        enable_wasi: matches!(next_runtime, NextRuntime::NodeJs)
+            .then(|| next_config.wasi_options()),
+        json_object_literal_threshold: Some(DEFAULT_OBJECT_LITERAL_THRESHOLD),
         ..Default::default()
     };
 
@@ -21,6 +21,7 @@ pub(crate) mod styled_components;
 pub(crate) mod styled_jsx;
 pub(crate) mod swc_ecma_transform_plugins;
 
+use anyhow::Result;
 pub use modularize_imports::{get_next_modularize_imports_rule, ModularizeImportPackageConfig};
 pub use next_dynamic::get_next_dynamic_transform_rule;
 pub use next_font::get_next_font_transform_rule;
@@ -31,11 +32,53 @@ use turbo_tasks_fs::FileSystemPath;
 use turbopack::module_options::{ModuleRule, ModuleRuleCondition, ModuleRuleEffect, ModuleType};
 use turbopack_core::reference_type::{ReferenceType, UrlReferenceSubType};
 use turbopack_ecmascript::{CustomTransformer, EcmascriptInputTransform};
+use turbopack_image::process::ImageOutputFormat;
 
-use crate::next_image::{module::BlurPlaceholderMode, StructuredImageModuleType};
+use crate::{
+    mode::NextMode,
+    next_config::{ImageFormat, NextConfig},
+    next_image::{module::BlurPlaceholderMode, ImageVariantOptions, StructuredImageModuleType},
+};
 
-pub fn get_next_image_rule() -> ModuleRule {
-    ModuleRule::new(
+/// Returns the rule for statically imported images. Responsive variants of the
+/// images are generated in builds when `experimental.turbo.pregenerateImages`
+/// is enabled.
+pub async fn get_next_image_rule(
+    next_config: Vc<NextConfig>,
+    mode: Vc<NextMode>,
+) -> Result<ModuleRule> {
+    let variants = if *mode.await? == NextMode::Build && *next_config.pregenerate_images().await? {
+        let image_config = next_config.image_config().await?;
+        let mut widths = image_config
+            .device_sizes
+            .iter()
+            .chain(image_config.image_sizes.iter())
+            .map(|&width| width as u32)
+            .collect::<Vec<_>>();
+        widths.sort_unstable();
+        widths.dedup();
+        let formats = image_config
+            .formats
+            .iter()
+            .map(|format| match format {
+                ImageFormat::Webp => ImageOutputFormat::Webp,
+                ImageFormat::Avif => ImageOutputFormat::Avif,
+            })
+            .filter(|format| format.is_supported())
+            .collect();
+        Some(
+            ImageVariantOptions {
+                widths,
+                formats,
+                // The default quality of `next/image`
+                quality: 75,
+            }
+            .cell(),
+        )
+    } else {
+        None
+    };
+    Ok(ModuleRule::new(
         ModuleRuleCondition::All(vec![
             // avoid urlAssetReference to be affected by this rule, since urlAssetReference
             // requires raw module to have its paths in the export
@@ -59,11 +102,12 @@ pub fn get_next_image_rule() -> ModuleRule {
             ]),
         ]),
         vec![ModuleRuleEffect::ModuleType(ModuleType::Custom(
-            Vc::upcast(StructuredImageModuleType::new(Value::new(
-                BlurPlaceholderMode::DataUrl,
-            ))),
+            Vc::upcast(StructuredImageModuleType::new(
+                Value::new(BlurPlaceholderMode::DataUrl),
+                variants,
+            )),
         ))],
-    )
+    ))
 }
 
 fn match_js_extension(enable_mdx_rs: bool) -> Vec<ModuleRuleCondition> {
@@ -387,8 +387,15 @@ export const configSchema: zod.ZodType<NextConfig> = z.lazy(() =>
               .optional(),
             resolveExtensions: z.array(z.string()).optional(),
             useSwcCss: z.boolean().optional(),
+            svgComponents: z.boolean().optional(),
             treeShaking: z.boolean().optional(),
             memoryLimit: z.number().optional(),
+            wasi: z
+              .object({
+                inheritEnv: z.boolean().optional(),
+                preopens: z.record(z.string(), z.string()).optional(),
+              })
+              .optional(),
           })
           .optional(),
         optimizePackageImports: z.array(z.string()).optional(),
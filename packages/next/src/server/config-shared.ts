@@ -149,10 +149,32 @@ export interface ExperimentalTurboOptions {
    */
   useSwcCss?: boolean
 
+  /**
+   * Turns SVGs imported with the `?react` query into React components.
+   */
+  svgComponents?: boolean
+
   /**
    * A target memory limit for turbo, in bytes.
    */
   memoryLimit?: number
+
+  /**
+   * Options for WebAssembly modules importing WASI preview 1 in the Node.js
+   * runtime.
+   */
+  wasi?: {
+    /**
+     * Passes the command line arguments and the environment variables of the
+     * server process to WebAssembly modules. Defaults to `false`.
+     */
+    inheritEnv?: boolean
+    /**
+     * The directories WebAssembly modules can access, mapped from the path seen
+     * by the module to the path on the host.
+     */
+    preopens?: Record<string, string>
+  }
 }
 
 export interface WebpackConfigContext {
@@ -12,6 +12,7 @@ import url from 'url'
 import path from 'path'
 import loadConfig from '../config'
 import { serveStatic } from '../serve-static'
+import contentDisposition from 'next/dist/compiled/content-disposition'
 import setupDebug from 'next/dist/compiled/debug'
 import { DecodeError } from '../../shared/lib/utils'
 import { findPagesDir } from '../../lib/find-pages-dir'
@@ -434,6 +435,23 @@ export async function initialize(opts: {
             )
           }
         }
+        if (
+          matchedOutput.type === 'nextStaticFolder' &&
+          matchedOutput.itemPath.endsWith('.svg')
+        ) {
+          // SVGs can contain scripts, so they are served with the same headers
+          // as the image optimizer serves them with.
+          res.setHeader(
+            'Content-Disposition',
+            contentDisposition(path.basename(matchedOutput.itemPath), {
+              type: config.images.contentDispositionType,
+            })
+          )
+          res.setHeader(
+            'Content-Security-Policy',
+            config.images.contentSecurityPolicy
+          )
+        }
         if (!(req.method === 'GET' || req.method === 'HEAD')) {
           res.setHeader('Allow', ['GET', 'HEAD'])
           res.statusCode = 405
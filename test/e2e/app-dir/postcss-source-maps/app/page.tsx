@@ -0,0 +1,3 @@
+export default function HomePage() {
+  return <p className="text">hello world</p>
+}
@@ -0,0 +1,45 @@
+import { nextTestSetup } from 'e2e-utils'
+
+// Plugin objects in the PostCSS config are only supported by Turbopack
+const describeTurbopack = process.env.TURBOPACK ? describe : describe.skip
+
+describeTurbopack('postcss-source-maps', () => {
+  const { next, isNextDev } = nextTestSetup({
+    files: __dirname,
+    skipDeployment: true,
+    dependencies: {
+      postcss: '8.4.38',
+    },
+  })
+
+  it('should apply the PostCSS transform', async () => {
+    const browser = await next.browser('/')
+    try {
+      const color = await browser.elementByCss('.text').getComputedCss('color')
+      expect(color).toBe('rgb(0, 128, 0)')
+    } finally {
+      await browser.close()
+    }
+  })
+
+  if (isNextDev) {
+    it('should map the transformed CSS to the original source', async () => {
+      const $ = await next.render$('/')
+      const href = $('link[rel="stylesheet"]').attr('href')
+      expect(href).toBeDefined()
+
+      const css = await (await next.fetch(href)).text()
+      const match = css.match(/\/\*#\s*sourceMappingURL=(\S+)\s*\*\//)
+      expect(match).toBeTruthy()
+
+      const sourceMapUrl = new URL(match[1], new URL(href, next.url))
+      const sourceMap = await (await next.fetch(sourceMapUrl.pathname)).json()
+
+      const sourcesContent = (sourceMap.sections ?? [{ map: sourceMap }])
+        .flatMap((section) => section.map.sourcesContent ?? [])
+        .join('\n')
+      expect(sourcesContent).toContain('color: red;')
+      expect(sourcesContent).not.toContain('color: green;')
+    })
+  }
+})
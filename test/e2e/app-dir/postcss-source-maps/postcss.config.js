@@ -0,0 +1,14 @@
+module.exports = {
+  plugins: [
+    {
+      postcssPlugin: 'replace-red',
+      Declaration: {
+        color(decl) {
+          if (decl.value === 'red') {
+            decl.value = 'green'
+          }
+        },
+      },
+    },
+  ],
+}
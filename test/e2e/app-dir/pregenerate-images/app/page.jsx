@@ -0,0 +1,11 @@
+import Image from 'next/image'
+import testImage from './test.png'
+
+export default function Page() {
+  return (
+    <>
+      <Image id="static" src={testImage} alt="static" width={256} />
+      <Image id="public" src="/public.png" alt="public" width={64} height={64} />
+    </>
+  )
+}
@@ -0,0 +1,12 @@
+/**
+ * @type {import('next').NextConfig}
+ */
+const nextConfig = {
+  experimental: {
+    turbo: {
+      pregenerateImages: true,
+    },
+  },
+}
+
+module.exports = nextConfig
@@ -0,0 +1,30 @@
+import { nextTestSetup } from 'e2e-utils'
+
+describe('pregenerate-images', () => {
+  const { next, isNextStart, isTurbopack } = nextTestSetup({
+    files: __dirname,
+  })
+
+  // Variants are only generated in production builds with Turbopack.
+  if (!isNextStart || !isTurbopack) {
+    it('should skip', () => {})
+    return
+  }
+
+  it('should serve statically imported images from pregenerated variants', async () => {
+    const $ = await next.render$('/')
+    const src = $('#static').attr('src')
+    expect(src).toMatch(/^\/_next\/static\/media\/test-\d+w\.[0-9a-f]+\.\w+$/)
+    expect($('#static').attr('srcset')).not.toContain('/_next/image')
+
+    const res = await next.fetch(src)
+    expect(res.status).toBe(200)
+  })
+
+  it('should pass other images to the default loader', async () => {
+    const $ = await next.render$('/')
+    expect($('#public').attr('src')).toBe(
+      '/_next/image?url=%2Fpublic.png&w=128&q=75'
+    )
+  })
+})
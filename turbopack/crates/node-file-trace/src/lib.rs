@@ -45,7 +45,10 @@ use turbopack_core::{
     reference::all_modules_and_affecting_sources,
     resolve::options::{ImportMapping, ResolvedMap},
 };
-use turbopack_resolve::resolve_options_context::ResolveOptionsContext;
+use turbopack_resolve::{
+    node_native_binding::default_native_binding_detectors,
+    resolve_options_context::ResolveOptionsContext,
+};
 
 use crate::nft_json::NftJsonAsset;
 
@@ -648,6 +651,11 @@ async fn create_module_asset(
             ImportMapping::Ignore.into(),
         ),
     ];
+    let mut module_options = ModuleOptionsContext::clone(&*module_options);
+    if module_options.ecmascript.native_binding_detectors.is_none() {
+        module_options.ecmascript.native_binding_detectors =
+            Some(default_native_binding_detectors());
+    }
     let mut resolve_options = ResolveOptionsContext::clone(&*resolve_options);
     if resolve_options.emulate_environment.is_none() {
         resolve_options.emulate_environment = Some(env);
@@ -664,7 +672,7 @@ async fn create_module_asset(
     Ok(ModuleAssetContext::new(
         Vc::cell(HashMap::new()),
         compile_time_info,
-        ModuleOptionsContext::clone(&*module_options).cell(),
+        module_options.cell(),
         resolve_options.cell(),
         Vc::cell("node_file_trace".into()),
     ))
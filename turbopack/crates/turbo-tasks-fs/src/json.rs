@@ -1,14 +1,104 @@
 use std::{
     borrow::Cow,
-    fmt::{Display, Formatter, Write},
+    fmt::{self, Display, Formatter, Write},
 };
 
 use anyhow::Result;
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+use serde_json::{Map, Number, Value};
 use turbo_tasks::trace::TraceRawVcs;
 
 use crate::{rope::Rope, source_context::get_source_context};
 
+/// A JSON value parsed from JSON5.
+///
+/// Unlike deserializing into a [Value] directly, which silently turns them into
+/// `null`, this reports an error for the non-finite numbers JSON5 allows
+/// (`Infinity`, `-Infinity` and `NaN`), as JSON can't represent them.
+pub(crate) struct Json5Value(pub Value);
+
+impl<'de> Deserialize<'de> for Json5Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_any(Json5ValueVisitor)
+            .map(Json5Value)
+    }
+}
+
+struct Json5ValueVisitor;
+
+impl<'de> Visitor<'de> for Json5ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a JSON5 value")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Value, E> {
+        Ok(Value::Bool(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Value, E> {
+        Ok(value.into())
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Value, E> {
+        Ok(value.into())
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Value, E> {
+        Number::from_f64(value).map(Value::Number).ok_or_else(|| {
+            let number = if value.is_nan() {
+                "NaN"
+            } else if value.is_sign_positive() {
+                "Infinity"
+            } else {
+                "-Infinity"
+            };
+            E::custom(format!("{number} can't be represented in JSON"))
+        })
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Value, E> {
+        Ok(Value::String(value.to_string()))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Value, E> {
+        Ok(Value::String(value))
+    }
+
+    fn visit_unit<E>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_none<E>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        Json5Value::deserialize(deserializer).map(|value| value.0)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut values = Vec::new();
+        while let Some(Json5Value(value)) = seq.next_element()? {
+            values.push(value);
+        }
+        Ok(Value::Array(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut values = Map::new();
+        while let Some((key, Json5Value(value))) = map.next_entry::<String, Json5Value>()? {
+            values.insert(key, value);
+        }
+        Ok(Value::Object(values))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, TraceRawVcs)]
 pub struct UnparseableJson {
     #[turbo_tasks(trace_ignore)]
@@ -42,6 +132,21 @@ impl UnparseableJson {
         }
     }
 
+    pub fn from_json5_error(e: json5::Error) -> Self {
+        let json5::Error::Message { msg, location } = e;
+        Self {
+            message: msg.into(),
+            path: None,
+            start_location: location.map(|location| {
+                (
+                    location.line.saturating_sub(1),
+                    location.column.saturating_sub(1),
+                )
+            }),
+            end_location: None,
+        }
+    }
+
     pub fn from_serde_path_to_error(e: serde_path_to_error::Error<serde_json::Error>) -> Self {
         let inner = e.inner();
         Self {
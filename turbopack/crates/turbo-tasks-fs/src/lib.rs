@@ -65,7 +65,11 @@ use util::{extract_disk_access, join_path, normalize_path, sys_to_unix, unix_to_
 pub use virtual_fs::VirtualFileSystem;
 use watcher::DiskWatcher;
 
-use self::{invalidation::Write, json::UnparseableJson, mutex_map::MutexMap};
+use self::{
+    invalidation::Write,
+    json::{Json5Value, UnparseableJson},
+    mutex_map::MutexMap,
+};
 use crate::{
     attach::AttachedFileSystem,
     retry::{retry_blocking, retry_future},
@@ -1686,6 +1690,21 @@ impl FileContent {
         }
     }
 
+    pub fn parse_json5_ref(&self) -> FileJsonContent {
+        match self {
+            FileContent::Content(file) => match file.content.to_str() {
+                Ok(string) => match json5::from_str(&string) {
+                    Ok(Json5Value(data)) => FileJsonContent::Content(data),
+                    Err(e) => {
+                        FileJsonContent::Unparseable(Box::new(UnparseableJson::from_json5_error(e)))
+                    }
+                },
+                Err(_) => FileJsonContent::unparseable("binary is not valid utf-8 text"),
+            },
+            FileContent::NotFound => FileJsonContent::NotFound,
+        }
+    }
+
     pub fn lines_ref(&self) -> FileLinesContent {
         match self {
             FileContent::Content(file) => match file.content.to_str() {
@@ -1726,6 +1745,12 @@ impl FileContent {
         Ok(this.parse_json_with_comments_ref().into())
     }
 
+    #[turbo_tasks::function]
+    pub async fn parse_json5(self: Vc<Self>) -> Result<Vc<FileJsonContent>> {
+        let this = self.await?;
+        Ok(this.parse_json5_ref().into())
+    }
+
     #[turbo_tasks::function]
     pub async fn lines(self: Vc<Self>) -> Result<Vc<FileLinesContent>> {
         let this = self.await?;
@@ -2018,4 +2043,25 @@ mod tests {
         .await
         .unwrap()
     }
+
+    #[test]
+    fn parse_json5_rejects_non_finite_numbers() {
+        let content = FileContent::Content(File::from("{ a: 1.5, b: [Infinity] }"));
+        let FileJsonContent::Unparseable(error) = content.parse_json5_ref() else {
+            panic!("Infinity should not be parsed as JSON");
+        };
+        assert!(
+            error
+                .message
+                .contains("Infinity can't be represented in JSON"),
+            "{}",
+            error.message
+        );
+
+        let content = FileContent::Content(File::from("{ a: 1.5, b: ['x'], // comment\n }"));
+        let FileJsonContent::Content(value) = content.parse_json5_ref() else {
+            panic!("valid JSON5 should be parsed");
+        };
+        assert_eq!(value, serde_json::json!({ "a": 1.5, "b": ["x"] }));
+    }
 }
@@ -72,6 +72,11 @@ impl BrowserChunkingContextBuilder {
         self
     }
 
+    pub fn single_ordered_chunk(mut self, single_ordered_chunk: bool) -> Self {
+        self.chunking_context.single_ordered_chunk = single_ordered_chunk;
+        self
+    }
+
     pub fn minify_type(mut self, minify_type: MinifyType) -> Self {
         self.chunking_context.minify_type = minify_type;
         self
@@ -122,6 +127,8 @@ pub struct BrowserChunkingContext {
     minify_type: MinifyType,
     /// Whether to use manifest chunks for lazy compilation
     manifest_chunks: bool,
+    /// Whether to put all CSS of a chunk group into a single chunk
+    single_ordered_chunk: bool,
 }
 
 impl BrowserChunkingContext {
@@ -151,6 +158,7 @@ impl BrowserChunkingContext {
                 runtime_type,
                 minify_type: MinifyType::NoMinify,
                 manifest_chunks: false,
+                single_ordered_chunk: false,
             },
         }
     }
@@ -295,6 +303,39 @@ impl ChunkingContext for BrowserChunkingContext {
         ))
     }
 
+    #[turbo_tasks::function]
+    async fn chunk_url(self: Vc<Self>, ident: Vc<AssetIdent>) -> Result<Vc<RcStr>> {
+        let this = self.await?;
+        let output_root = this.output_root.await?;
+        let chunk_path = ident.path().await?;
+        let Some(chunk_path) = output_root.get_path_to(&chunk_path) else {
+            bail!(
+                "chunk path {} is not in output root {}",
+                chunk_path.to_string(),
+                output_root.to_string()
+            );
+        };
+        // Mirrors `getChunkRelativeUrl` in the browser runtime.
+        let chunk_path = chunk_path
+            .split('/')
+            .map(|segment| urlencoding::encode(segment))
+            .collect::<Vec<_>>()
+            .join("/");
+
+        Ok(Vc::cell(
+            format!(
+                "{}{}",
+                this.chunk_base_path
+                    .await?
+                    .as_ref()
+                    .map(|s| s.as_str())
+                    .unwrap_or(""),
+                chunk_path
+            )
+            .into(),
+        ))
+    }
+
     #[turbo_tasks::function]
     async fn reference_chunk_source_maps(
         &self,
@@ -340,6 +381,11 @@ impl ChunkingContext for BrowserChunkingContext {
         Vc::cell(self.enable_hot_module_replacement)
     }
 
+    #[turbo_tasks::function]
+    fn single_ordered_chunk(&self) -> Vc<bool> {
+        Vc::cell(self.single_ordered_chunk)
+    }
+
     #[turbo_tasks::function]
     async fn chunk_group(
         self: Vc<Self>,
@@ -152,11 +152,12 @@ impl EcmascriptDevEvaluateChunk {
                 code.push_code(&*runtime_code.await?);
             }
             RuntimeType::Production => {
-                let runtime_code = turbopack_ecmascript_runtime::get_browser_runtime_code(
-                    environment,
-                    chunking_context.chunk_base_path(),
-                    Vc::cell(output_root.to_string().into()),
-                );
+                let runtime_code =
+                    turbopack_ecmascript_runtime::get_browser_production_runtime_code(
+                        environment,
+                        chunking_context.chunk_base_path(),
+                        Vc::cell(output_root.to_string().into()),
+                    );
                 code.push_code(&*runtime_code.await?);
             }
             #[cfg(feature = "test")]
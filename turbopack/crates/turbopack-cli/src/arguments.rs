@@ -11,6 +11,7 @@ use turbopack_cli_utils::issue::IssueSeverityCliOption;
 pub enum Arguments {
     Build(BuildArguments),
     Dev(DevArguments),
+    Resolve(ResolveArguments),
 }
 
 impl Arguments {
@@ -19,6 +20,7 @@ impl Arguments {
         match self {
             Arguments::Build(args) => args.common.dir.as_deref(),
             Arguments::Dev(args) => args.common.dir.as_deref(),
+            Arguments::Resolve(args) => args.dir.as_deref(),
         }
     }
 }
@@ -61,6 +63,11 @@ pub struct CommonArguments {
     /// MB.
     #[clap(long)]
     pub memory_limit: Option<usize>,
+
+    /// Inline static assets smaller than this many bytes as data URLs when
+    /// they are imported from ES modules.
+    #[clap(long, value_name = "BYTES")]
+    pub static_inline_limit: Option<usize>,
 }
 
 #[derive(Debug, Args)]
@@ -107,4 +114,85 @@ pub struct BuildArguments {
     /// Don't minify build output.
     #[clap(long)]
     pub no_minify: bool,
+
+    /// Put all CSS of an entry into a single chunk, instead of splitting it
+    /// into multiple chunks.
+    #[clap(long)]
+    pub single_css_chunk: bool,
+
+    /// Write a `.d.ts` file with the exported class names of every CSS module
+    /// to this directory, mirroring the project's structure, e.g.
+    /// `<DIR>/src/button.module.css.d.ts`. Add the directory to `rootDirs` in
+    /// `tsconfig.json` to use them. Relative paths are resolved from the
+    /// project's directory (`--dir`).
+    #[clap(long, value_name = "DIR", value_parser)]
+    pub css_module_typings: Option<PathBuf>,
+
+    /// Write a JSON report of unused exports, files not reachable from any
+    /// entry and dependencies which are never resolved to this path.
+    /// Relative paths are resolved from the project's directory (`--dir`).
+    #[clap(long, value_parser)]
+    pub unused_report: Option<PathBuf>,
+
+    /// Report unused exports, unreachable files and unused dependencies as
+    /// warnings.
+    #[clap(long)]
+    pub warn_unused: bool,
+
+    /// A glob of files, relative to the project's directory (`--dir`), which
+    /// are never reported as unreachable. Type declarations, tests, tool
+    /// configurations and `dist` are ignored by default. Can be passed
+    /// multiple times.
+    #[clap(long = "unused-ignore", value_name = "GLOB")]
+    pub unused_ignore: Vec<String>,
+
+    /// Report cycles of synchronous imports between modules.
+    #[clap(long)]
+    pub circular_dependencies: bool,
+
+    /// The severity of reported circular dependencies. Use `error` to fail the
+    /// build when a cycle is found. Defaults to `warning`.
+    #[clap(long)]
+    pub circular_dependencies_severity: Option<IssueSeverityCliOption>,
+
+    /// A glob of module paths, relative to the project's directory (`--dir`),
+    /// which are allowed to be part of a circular dependency. A cycle is only
+    /// reported when one of its modules is not matched. Can be passed multiple
+    /// times.
+    #[clap(long = "allow-circular-dependency", value_name = "GLOB")]
+    pub allow_circular_dependencies: Vec<String>,
+}
+
+#[derive(Debug, Args)]
+#[clap(author, version, about, long_about = None)]
+pub struct ResolveArguments {
+    /// The request to resolve, as written in source code.
+    #[clap(value_parser)]
+    pub request: String,
+
+    /// The file the request is made from. Resolved relative to the project's
+    /// directory (`--dir`).
+    #[clap(long, value_parser)]
+    pub from: String,
+
+    /// The directory of the application.
+    /// If no directory is provided, the current directory will be used.
+    #[clap(short, long, value_parser)]
+    pub dir: Option<PathBuf>,
+
+    /// The root directory of the project. Nothing outside of this directory can
+    /// be accessed. e. g. the monorepo root.
+    /// If no directory is provided, `dir` will be used.
+    #[clap(long, value_parser)]
+    pub root: Option<PathBuf>,
+
+    /// Resolve the request as a CommonJS `require()` instead of an ESM import.
+    #[clap(long)]
+    pub commonjs: bool,
+
+    /// Print the full resolution trace: import map and tsconfig paths hits,
+    /// probed modules directories, package.json fields and conditions,
+    /// extensions and resolve plugin decisions.
+    #[clap(long)]
+    pub explain: bool,
 }
@@ -0,0 +1,60 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use turbo_tasks::Vc;
+use turbo_tasks_fs::FileSystemPath;
+use turbopack::graph::is_in_node_modules;
+use turbopack_core::{
+    module::{Module, Modules},
+    reference::all_modules_and_affecting_sources,
+};
+use turbopack_css::ModuleCssAsset;
+
+/// Writes a `*.module.css.d.ts` file with the exported class names of every
+/// CSS module in the module graph starting at `entries` to `typings_root`, so
+/// TypeScript can check the class names used by the application.
+///
+/// The typings mirror the location of the CSS modules in the project, e.g.
+/// `src/button.module.css` is declared by
+/// `<typings_root>/src/button.module.css.d.ts`. Listing the project and
+/// `typings_root` in the `rootDirs` of `tsconfig.json` makes them visible to
+/// TypeScript without writing to the source tree.
+#[turbo_tasks::function]
+pub async fn write_css_module_typings(
+    entries: Vc<Modules>,
+    project_path: Vc<FileSystemPath>,
+    typings_root: Vc<FileSystemPath>,
+) -> Result<Vc<()>> {
+    let project = project_path.await?;
+
+    let mut modules = HashSet::new();
+    for &entry in entries.await?.iter() {
+        modules.extend(
+            all_modules_and_affecting_sources(entry)
+                .await?
+                .iter()
+                .copied(),
+        );
+    }
+
+    for module in modules {
+        let Some(css_module) = Vc::try_resolve_downcast_type::<ModuleCssAsset>(module).await?
+        else {
+            continue;
+        };
+        let path = css_module.ident().path().await?;
+        let Some(relative) = project.get_path_to(&path) else {
+            continue;
+        };
+        // Packages are not part of the project
+        if is_in_node_modules(relative) {
+            continue;
+        }
+        typings_root
+            .join(format!("{relative}.d.ts").into())
+            .write(css_module.typings())
+            .await?;
+    }
+
+    Ok(Default::default())
+}
@@ -1,14 +1,17 @@
 use std::{
     collections::HashSet,
     env::current_dir,
-    path::{PathBuf, MAIN_SEPARATOR},
+    path::{Path, PathBuf, MAIN_SEPARATOR},
     sync::Arc,
 };
 
 use anyhow::{bail, Context, Result};
 use turbo_tasks::{RcStr, TransientInstance, TryJoinIterExt, TurboTasks, Value, Vc};
-use turbo_tasks_fs::FileSystem;
+use turbo_tasks_fs::{DiskFileSystem, File, FileContent, FileSystem};
 use turbo_tasks_memory::MemoryBackend;
+use turbopack::graph::circular_dependencies::{
+    detect_circular_dependencies, CircularDependencyOptions,
+};
 use turbopack_cli_utils::issue::{ConsoleUi, LogOptions};
 use turbopack_core::{
     asset::Asset,
@@ -18,7 +21,7 @@ use turbopack_core::{
     },
     environment::{BrowserEnvironment, Environment, ExecutionEnvironment},
     issue::{handle_issues, IssueReporter, IssueSeverity},
-    module::Module,
+    module::{Module, Modules},
     output::OutputAsset,
     reference::all_assets_from_entries,
     reference_type::{EntryReferenceSubType, ReferenceType},
@@ -27,20 +30,32 @@ use turbopack_core::{
         parse::Request,
     },
 };
+use turbopack_css::chunk::order::detect_css_order_conflicts;
 use turbopack_ecmascript_runtime::RuntimeType;
 use turbopack_env::dotenv::load_env;
 use turbopack_node::execution_context::ExecutionContext;
 use turbopack_nodejs::NodeJsChunkingContext;
+use turbopack_resolve::browser_import_map::browser_import_map_for_externals;
 
+use self::{
+    css_module_typings::write_css_module_typings,
+    unused::{emit_unused_issues, unused_report, UnusedOptions},
+};
 use crate::{
     arguments::BuildArguments,
-    contexts::{get_client_asset_context, get_client_compile_time_info, NodeEnv},
+    contexts::{
+        get_client_asset_context, get_client_browser_import_map, get_client_compile_time_info,
+        NodeEnv,
+    },
     util::{
         normalize_dirs, normalize_entries, output_fs, project_fs, EntryRequest, EntryRequests,
         NormalizedDirs,
     },
 };
 
+mod css_module_typings;
+mod unused;
+
 pub fn register() {
     turbopack::register();
     include!(concat!(env!("OUT_DIR"), "/register.rs"));
@@ -56,6 +71,13 @@ pub struct TurbopackBuildBuilder {
     show_all: bool,
     log_detail: bool,
     minify_type: MinifyType,
+    static_inline_limit: Option<usize>,
+    single_css_chunk: bool,
+    css_module_typings: Option<RcStr>,
+    unused_report: Option<RcStr>,
+    warn_unused: bool,
+    unused_options: UnusedOptions,
+    circular_dependencies: Option<CircularDependencyOptions>,
 }
 
 impl TurbopackBuildBuilder {
@@ -74,6 +96,13 @@ impl TurbopackBuildBuilder {
             show_all: false,
             log_detail: false,
             minify_type: MinifyType::Minify,
+            static_inline_limit: None,
+            single_css_chunk: false,
+            css_module_typings: None,
+            unused_report: None,
+            warn_unused: false,
+            unused_options: UnusedOptions::default(),
+            circular_dependencies: None,
         }
     }
 
@@ -107,6 +136,53 @@ impl TurbopackBuildBuilder {
         self
     }
 
+    /// Inlines static assets smaller than this many bytes as data URLs.
+    pub fn static_inline_limit(mut self, static_inline_limit: Option<usize>) -> Self {
+        self.static_inline_limit = static_inline_limit;
+        self
+    }
+
+    /// Puts all CSS of an entry into a single chunk.
+    pub fn single_css_chunk(mut self, single_css_chunk: bool) -> Self {
+        self.single_css_chunk = single_css_chunk;
+        self
+    }
+
+    /// Writes TypeScript declarations of every CSS module to the given
+    /// directory. Relative paths are resolved from the project directory.
+    pub fn css_module_typings(mut self, css_module_typings: Option<RcStr>) -> Self {
+        self.css_module_typings = css_module_typings;
+        self
+    }
+
+    /// Writes a JSON report of dead code to the given path. Relative paths are
+    /// resolved from the project directory.
+    pub fn unused_report(mut self, unused_report: Option<RcStr>) -> Self {
+        self.unused_report = unused_report;
+        self
+    }
+
+    /// Reports dead code as warnings.
+    pub fn warn_unused(mut self, warn_unused: bool) -> Self {
+        self.warn_unused = warn_unused;
+        self
+    }
+
+    /// Adds glob patterns of files which are never reported as unreachable.
+    pub fn unused_ignore(mut self, ignore: impl IntoIterator<Item = RcStr>) -> Self {
+        self.unused_options.ignore.extend(ignore);
+        self
+    }
+
+    /// Reports cycles in the module graph as issues.
+    pub fn circular_dependencies(
+        mut self,
+        circular_dependencies: Option<CircularDependencyOptions>,
+    ) -> Self {
+        self.circular_dependencies = circular_dependencies;
+        self
+    }
+
     pub async fn build(self) -> Result<()> {
         let task = self.turbo_tasks.spawn_once_task::<(), _>(async move {
             let build_result = build_internal(
@@ -122,6 +198,14 @@ impl TurbopackBuildBuilder {
                 .cell(),
                 self.browserslist_query,
                 self.minify_type,
+                self.static_inline_limit,
+                self.single_css_chunk,
+                self.css_module_typings,
+                self.unused_report,
+                self.warn_unused,
+                self.unused_options.cell(),
+                self.circular_dependencies
+                    .map(CircularDependencyOptions::cell),
             );
 
             // Await the result to propagate any errors.
@@ -161,6 +245,13 @@ async fn build_internal(
     entry_requests: Vc<EntryRequests>,
     browserslist_query: RcStr,
     minify_type: MinifyType,
+    static_inline_limit: Option<usize>,
+    single_css_chunk: bool,
+    css_module_typings_dir: Option<RcStr>,
+    unused_report_path: Option<RcStr>,
+    warn_unused: bool,
+    unused_options: Vc<UnusedOptions>,
+    circular_dependencies: Option<Vc<CircularDependencyOptions>>,
 ) -> Result<Vc<()>> {
     let env = Environment::new(Value::new(ExecutionEnvironment::Browser(
         BrowserEnvironment {
@@ -198,14 +289,20 @@ async fn build_internal(
             },
         )
         .minify_type(minify_type)
+        .single_ordered_chunk(single_css_chunk)
         .build(),
     );
 
     let compile_time_info = get_client_compile_time_info(browserslist_query, node_env);
     let execution_context =
         ExecutionContext::new(project_path, chunking_context, load_env(project_path));
-    let asset_context =
-        get_client_asset_context(project_path, execution_context, compile_time_info, node_env);
+    let asset_context = get_client_asset_context(
+        project_path,
+        execution_context,
+        compile_time_info,
+        node_env,
+        static_inline_limit,
+    );
 
     let entry_requests = (*entry_requests
         .await?
@@ -253,6 +350,8 @@ async fn build_internal(
         .try_join()
         .await?;
 
+    let entry_modules: Vc<Modules> = Vc::cell(entries.clone());
+
     let entry_chunk_groups = entries
         .into_iter()
         .map(|entry_module| async move {
@@ -301,16 +400,68 @@ async fn build_internal(
         .await?;
 
     let mut chunks: HashSet<Vc<Box<dyn OutputAsset>>> = HashSet::new();
-    for chunk_group in entry_chunk_groups {
+    for &chunk_group in &entry_chunk_groups {
         chunks.extend(&*all_assets_from_entries(chunk_group).await?);
     }
 
+    // Requests mapped to URLs by the import map are kept as externals, so the page needs an
+    // import map for them.
+    if let Some(import_map_path) = *get_client_browser_import_map(project_path).await? {
+        chunks.insert(browser_import_map_for_externals(
+            import_map_path,
+            build_output_root.join("importmap.json".into()),
+        ));
+    }
+
     chunks
         .iter()
         .map(|c| c.content().write(c.ident().path()))
         .try_join()
         .await?;
 
+    detect_css_order_conflicts(entry_chunk_groups).await?;
+
+    if let Some(css_module_typings_dir) = css_module_typings_dir {
+        let css_module_typings_dir =
+            Path::new(project_dir.as_str()).join(css_module_typings_dir.as_str());
+        let typings_root = DiskFileSystem::new(
+            "css-module-typings".into(),
+            css_module_typings_dir.to_string_lossy().into(),
+            vec![],
+        )
+        .root();
+        write_css_module_typings(entry_modules, project_path, typings_root).await?;
+    }
+
+    if let Some(circular_dependencies) = circular_dependencies {
+        detect_circular_dependencies(entry_modules, project_path, circular_dependencies).await?;
+    }
+
+    if unused_report_path.is_some() || warn_unused {
+        let report = unused_report(entry_modules, project_path, unused_options);
+        if let Some(unused_report_path) = unused_report_path {
+            // The report may be written anywhere, not only inside of the project.
+            let unused_report_path =
+                Path::new(project_dir.as_str()).join(unused_report_path.as_str());
+            let (Some(dir), Some(file_name)) =
+                (unused_report_path.parent(), unused_report_path.file_name())
+            else {
+                bail!(
+                    "invalid unused report path {}",
+                    unused_report_path.display()
+                );
+            };
+            DiskFileSystem::new("unused-report".into(), dir.to_string_lossy().into(), vec![])
+                .root()
+                .join(file_name.to_string_lossy().into())
+                .write(FileContent::Content(File::from(report.await?.to_json()?)).cell())
+                .await?;
+        }
+        if warn_unused {
+            emit_unused_issues(report, project_path).await?;
+        }
+    }
+
     Ok(Default::default())
 }
 
@@ -338,7 +489,33 @@ pub async fn build(args: &BuildArguments) -> Result<()> {
         } else {
             MinifyType::Minify
         })
-        .show_all(args.common.show_all);
+        .static_inline_limit(args.common.static_inline_limit)
+        .single_css_chunk(args.single_css_chunk)
+        .css_module_typings(
+            args.css_module_typings
+                .as_ref()
+                .map(|path| path.to_string_lossy().into()),
+        )
+        .show_all(args.common.show_all)
+        .unused_report(
+            args.unused_report
+                .as_ref()
+                .map(|path| path.to_string_lossy().into()),
+        )
+        .warn_unused(args.warn_unused)
+        .unused_ignore(args.unused_ignore.iter().map(|glob| glob.as_str().into()))
+        .circular_dependencies(args.circular_dependencies.then(|| {
+            CircularDependencyOptions {
+                allow: args
+                    .allow_circular_dependencies
+                    .iter()
+                    .map(|glob| glob.as_str().into())
+                    .collect(),
+                severity: args
+                    .circular_dependencies_severity
+                    .map_or(IssueSeverity::Warning, |severity| severity.0),
+            }
+        }));
 
     for entry in normalize_entries(&args.common.entries) {
         builder = builder.entry_request(EntryRequest::Relative(entry));
@@ -0,0 +1,701 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+use anyhow::Result;
+use serde_json::json;
+use turbo_tasks::{RcStr, ReadRef, TryJoinIterExt, ValueToString, Vc};
+use turbo_tasks_fs::{
+    glob::Glob, DirectoryContent, DirectoryEntry, FileJsonContent, FileSystemPath,
+};
+use turbopack::{
+    ecmascript::{
+        chunk::{EcmascriptChunkPlaceable, EcmascriptExports},
+        references::{
+            esm::{EsmAssetReference, EsmExport, EsmExports, ImportUsage},
+            external_module::CachedExternalModule,
+        },
+        side_effect_optimization::facade::module::EcmascriptModuleFacadeModule,
+        EcmascriptAnalyzable, EcmascriptModuleAsset,
+    },
+    graph::is_in_node_modules,
+};
+use turbopack_core::{
+    issue::{Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString},
+    module::{Module, Modules},
+    reference::{all_modules_and_affecting_sources, ModuleReference},
+    resolve::{ModulePart, ModuleResolveResultItem},
+};
+use turbopack_css::ModuleCssAsset;
+
+/// Source file extensions which are expected to be reachable from an entry.
+const SOURCE_EXTENSIONS: &[&str] = &[
+    "js", "jsx", "mjs", "cjs", "ts", "tsx", "mts", "cts", "css", "scss", "sass",
+];
+
+/// Files which are not expected to be reachable from an entry: type
+/// declarations, tests and tool configurations.
+const DEFAULT_IGNORED_FILES: &[&str] = &[
+    "**/*.d.{ts,mts,cts}",
+    "**/*.{test,spec}.*",
+    "**/{__tests__,__mocks__}/**",
+    "**/*.config.*",
+    "dist/**",
+];
+
+/// Configures the dead code report.
+#[turbo_tasks::value(shared)]
+#[derive(Clone, Debug)]
+pub struct UnusedOptions {
+    /// Glob patterns of paths, relative to the project directory, which are
+    /// never reported as unreachable files. `node_modules` and hidden
+    /// directories are always skipped.
+    pub ignore: Vec<RcStr>,
+}
+
+impl Default for UnusedOptions {
+    fn default() -> Self {
+        Self {
+            ignore: DEFAULT_IGNORED_FILES
+                .iter()
+                .map(|&glob| glob.into())
+                .collect(),
+        }
+    }
+}
+
+/// Dead code found in the module graph of a build.
+///
+/// All paths are relative to the project directory.
+#[turbo_tasks::value(shared)]
+#[derive(Debug, Default)]
+pub struct UnusedReport {
+    /// Exports which are never imported, by the file declaring them.
+    pub unused_exports: BTreeMap<RcStr, Vec<RcStr>>,
+    /// Classes of CSS modules which are never imported, by the CSS module
+    /// declaring them.
+    pub unused_css_classes: BTreeMap<RcStr, Vec<RcStr>>,
+    /// Source files which are not reachable from any entry.
+    pub unreachable_files: Vec<RcStr>,
+    /// Dependencies in the project's package.json which are never resolved.
+    pub unused_dependencies: Vec<RcStr>,
+}
+
+impl UnusedReport {
+    pub fn to_json(&self) -> Result<String> {
+        let unused_exports = self
+            .unused_exports
+            .iter()
+            .map(|(path, exports)| json!({ "path": path, "exports": exports }))
+            .collect::<Vec<_>>();
+        let unused_css_classes = self
+            .unused_css_classes
+            .iter()
+            .map(|(path, classes)| json!({ "path": path, "classes": classes }))
+            .collect::<Vec<_>>();
+        Ok(serde_json::to_string_pretty(&json!({
+            "unusedExports": unused_exports,
+            "unusedCssClasses": unused_css_classes,
+            "unreachableFiles": self.unreachable_files,
+            "unusedDependencies": self.unused_dependencies,
+        }))?)
+    }
+}
+
+/// Which exports of a file are used.
+#[derive(Default)]
+enum UsedExports {
+    #[default]
+    None,
+    Some(HashSet<RcStr>),
+    All,
+}
+
+impl UsedExports {
+    /// Marks the usage as used. Returns `false` when it was already used.
+    fn add(&mut self, usage: &ImportUsage) -> bool {
+        match usage {
+            ImportUsage::Namespace => {
+                !matches!(std::mem::replace(self, UsedExports::All), UsedExports::All)
+            }
+            ImportUsage::Export(name) | ImportUsage::DefaultExportMember(name) => match self {
+                UsedExports::None => {
+                    *self = UsedExports::Some(HashSet::from([name.clone()]));
+                    true
+                }
+                UsedExports::Some(names) => names.insert(name.clone()),
+                UsedExports::All => false,
+            },
+        }
+    }
+
+    fn is_used(&self, name: &str) -> bool {
+        match self {
+            UsedExports::None => false,
+            UsedExports::Some(names) => names.contains(name),
+            UsedExports::All => true,
+        }
+    }
+}
+
+/// A module a reference resolves to, identified by its file.
+struct Target {
+    path: RcStr,
+    /// The module only exposes a single export of the file, under a new name.
+    renamed: Option<Renamed>,
+}
+
+enum Renamed {
+    Export { original_export: RcStr },
+    Namespace,
+}
+
+impl Target {
+    /// Maps the usage of the referenced module to the usage of the file.
+    fn map_usage(&self, usage: &ImportUsage) -> ImportUsage {
+        match &self.renamed {
+            None => usage.clone(),
+            Some(Renamed::Export { original_export }) => {
+                ImportUsage::Export(original_export.clone())
+            }
+            Some(Renamed::Namespace) => ImportUsage::Namespace,
+        }
+    }
+}
+
+async fn reference_targets(
+    reference: Vc<Box<dyn ModuleReference>>,
+    project_path: &FileSystemPath,
+) -> Result<Vec<Target>> {
+    let modules = reference.resolve_reference().primary_modules().await?;
+    let mut targets = Vec::new();
+    for &module in modules.iter() {
+        let Some(path) = module_path(module, project_path).await? else {
+            continue;
+        };
+        let renamed = if let Some(facade) =
+            Vc::try_resolve_downcast_type::<EcmascriptModuleFacadeModule>(module).await?
+        {
+            match &*facade.await?.ty.await? {
+                ModulePart::RenamedExport {
+                    original_export, ..
+                } => Some(Renamed::Export {
+                    original_export: original_export.await?.clone_value(),
+                }),
+                ModulePart::RenamedNamespace { .. } => Some(Renamed::Namespace),
+                _ => None,
+            }
+        } else {
+            None
+        };
+        targets.push(Target { path, renamed });
+    }
+    Ok(targets)
+}
+
+/// The path of the module relative to the project directory, or the path in
+/// the file system for modules outside of it.
+async fn module_path(
+    module: Vc<Box<dyn Module>>,
+    project_path: &FileSystemPath,
+) -> Result<Option<RcStr>> {
+    let path = module.ident().path().await?;
+    if path.fs != project_path.fs {
+        return Ok(None);
+    }
+    Ok(Some(match project_path.get_path_to(&path) {
+        Some(relative) => relative.into(),
+        None => path.path.clone(),
+    }))
+}
+
+/// The name of the package a path is part of, if it's inside of a
+/// `node_modules` directory.
+fn package_name(path: &str) -> Option<&str> {
+    let (_, rest) = path.split_once("node_modules/")?;
+    Some(leading_package_name(rest))
+}
+
+/// The name of the package an external request refers to, e.g. `react` for
+/// `react/jsx-runtime`. Relative, absolute and URL requests don't refer to a
+/// package.
+fn request_package_name(request: &str) -> Option<&str> {
+    if request.starts_with(['.', '/']) || request.contains(':') {
+        return None;
+    }
+    Some(leading_package_name(request))
+}
+
+/// The package name at the start of `path`, which includes the scope for
+/// scoped packages.
+fn leading_package_name(path: &str) -> &str {
+    let end = if path.starts_with('@') {
+        path.match_indices('/').nth(1).map(|(i, _)| i)
+    } else {
+        path.find('/')
+    };
+    end.map_or(path, |end| &path[..end])
+}
+
+/// Analyzes the module graph starting at `entries` for dead code.
+#[turbo_tasks::function]
+pub async fn unused_report(
+    entries: Vc<Modules>,
+    project_path: Vc<FileSystemPath>,
+    options: Vc<UnusedOptions>,
+) -> Result<Vc<UnusedReport>> {
+    let project = project_path.await?;
+    let ignore = options
+        .await?
+        .ignore
+        .iter()
+        .map(|pattern| Glob::new(pattern.clone()))
+        .try_join()
+        .await?;
+
+    let entries = entries.await?;
+    let mut modules = HashSet::new();
+    for &entry in entries.iter() {
+        modules.extend(
+            all_modules_and_affecting_sources(entry)
+                .await?
+                .iter()
+                .copied(),
+        );
+    }
+
+    let mut reachable_files = HashSet::new();
+    let mut exports_by_file = HashMap::<RcStr, ReadRef<EsmExports>>::new();
+    let mut css_classes_by_file = HashMap::<RcStr, Vec<RcStr>>::new();
+    // Packages which are not bundled, but still used at runtime
+    let mut external_packages = HashSet::<RcStr>::new();
+    let mut queue = VecDeque::new();
+
+    for &module in entries.iter() {
+        if let Some(path) = module_path(module, &project).await? {
+            // Entries might be consumed by anything outside of the module graph
+            queue.push_back((path, ImportUsage::Namespace));
+        }
+    }
+
+    for &module in modules.iter() {
+        if let Some(external) =
+            Vc::try_resolve_downcast_type::<CachedExternalModule>(module).await?
+        {
+            external_packages
+                .extend(request_package_name(&external.await?.request).map(RcStr::from));
+            continue;
+        }
+        let Some(path) = module_path(module, &project).await? else {
+            continue;
+        };
+        reachable_files.insert(path.clone());
+
+        if let Some(css_module) = Vc::try_resolve_downcast_type::<ModuleCssAsset>(module).await? {
+            let exports = css_module.exports().await?;
+            css_classes_by_file.insert(path.clone(), exports.classes.clone());
+            // Composed classes are used by the composing class
+            for (reference, class_name) in exports.composes.iter() {
+                for target in reference_targets(*reference, &project).await? {
+                    queue.push_back((target.path, ImportUsage::Export(class_name.clone())));
+                }
+            }
+            continue;
+        }
+
+        let Some(asset) = Vc::try_resolve_downcast_type::<EcmascriptModuleAsset>(module).await?
+        else {
+            continue;
+        };
+
+        if let EcmascriptExports::EsmExports(exports) = &*asset.get_exports().await? {
+            exports_by_file
+                .entry(path.clone())
+                .or_insert((*exports).await?);
+        }
+
+        for (reference, usage) in asset.analyze().await?.import_usages.await?.iter() {
+            for target in reference_targets(*reference, &project).await? {
+                let usage = target.map_usage(usage);
+                queue.push_back((target.path, usage));
+            }
+        }
+
+        // Modules referenced in other ways than ESM imports, e.g. by `require()` or
+        // `import()`, might access any of their exports.
+        for &reference in asset.references().await?.iter() {
+            // Externals which aren't replaced by a module, e.g. URL externals
+            for item in reference.resolve_reference().await?.primary.values() {
+                if let ModuleResolveResultItem::External(request, _) = item {
+                    external_packages.extend(request_package_name(request).map(RcStr::from));
+                }
+            }
+            if Vc::try_resolve_downcast_type::<EsmAssetReference>(reference)
+                .await?
+                .is_some()
+            {
+                continue;
+            }
+            for target in reference_targets(reference, &project).await? {
+                if target.path != path {
+                    queue.push_back((target.path, ImportUsage::Namespace));
+                }
+            }
+        }
+    }
+
+    let mut used = HashMap::<RcStr, UsedExports>::new();
+    while let Some((path, usage)) = queue.pop_front() {
+        let usage = if css_classes_by_file.contains_key(&path) {
+            // The classes of a CSS module are the properties of its default export,
+            // which can also be imported by name
+            match usage {
+                ImportUsage::Export(name) if name == "default" => ImportUsage::Namespace,
+                ImportUsage::DefaultExportMember(name) => ImportUsage::Export(name),
+                usage => usage,
+            }
+        } else {
+            match usage {
+                ImportUsage::DefaultExportMember(_) => ImportUsage::Export("default".into()),
+                usage => usage,
+            }
+        };
+        if !used.entry(path.clone()).or_default().add(&usage) {
+            continue;
+        }
+        let Some(exports) = exports_by_file.get(&path) else {
+            continue;
+        };
+
+        // Follow re-exports to the files declaring the exports
+        let reexports = match &usage {
+            ImportUsage::Export(name) => match exports.exports.get(name) {
+                Some(export) => vec![(name, export)],
+                None => {
+                    for &reference in exports.star_exports.iter() {
+                        for target in reference_targets(reference, &project).await? {
+                            queue.push_back((target.path.clone(), target.map_usage(&usage)));
+                        }
+                    }
+                    vec![]
+                }
+            },
+            ImportUsage::Namespace => {
+                for &reference in exports.star_exports.iter() {
+                    for target in reference_targets(reference, &project).await? {
+                        queue.push_back((target.path.clone(), target.map_usage(&usage)));
+                    }
+                }
+                exports.exports.iter().collect()
+            }
+            // Already mapped to the default export above
+            ImportUsage::DefaultExportMember(_) => vec![],
+        };
+        for (_, export) in reexports {
+            let (reference, usage) = match export {
+                EsmExport::ImportedBinding(reference, name, _) => {
+                    (*reference, ImportUsage::Export(name.clone()))
+                }
+                EsmExport::ImportedNamespace(reference) => (*reference, ImportUsage::Namespace),
+                EsmExport::LocalBinding(..) | EsmExport::Error => continue,
+            };
+            for target in reference_targets(reference, &project).await? {
+                queue.push_back((target.path.clone(), target.map_usage(&usage)));
+            }
+        }
+    }
+
+    let mut unused_exports = BTreeMap::new();
+    for (path, exports) in exports_by_file.iter() {
+        if is_in_node_modules(path) {
+            continue;
+        }
+        let used = used.get(path);
+        let unused = exports
+            .exports
+            .keys()
+            .filter(|name| !used.is_some_and(|used| used.is_used(name)))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !unused.is_empty() {
+            unused_exports.insert(path.clone(), unused);
+        }
+    }
+
+    let mut unused_css_classes = BTreeMap::new();
+    for (path, classes) in css_classes_by_file.iter() {
+        if is_in_node_modules(path) {
+            continue;
+        }
+        let used = used.get(path);
+        let unused = classes
+            .iter()
+            .filter(|name| !used.is_some_and(|used| used.is_used(name)))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !unused.is_empty() {
+            unused_css_classes.insert(path.clone(), unused);
+        }
+    }
+
+    let mut unreachable_files = BTreeSet::new();
+    collect_unreachable_files(
+        project_path,
+        &project,
+        &ignore,
+        &reachable_files,
+        &mut unreachable_files,
+    )
+    .await?;
+
+    let resolved_packages = modules
+        .iter()
+        .map(|module| async move { Ok(module.ident().path().await?.path.clone()) })
+        .try_join()
+        .await?
+        .iter()
+        .filter_map(|path| package_name(path).map(RcStr::from))
+        .collect::<HashSet<_>>();
+    let mut unused_dependencies = Vec::new();
+    if let FileJsonContent::Content(package_json) =
+        &*project_path.join("package.json".into()).read_json().await?
+    {
+        if let Some(dependencies) = package_json["dependencies"].as_object() {
+            unused_dependencies.extend(
+                dependencies
+                    .keys()
+                    .filter(|name| {
+                        !resolved_packages.contains(name.as_str())
+                            && !external_packages.contains(name.as_str())
+                    })
+                    .map(|name| RcStr::from(name.as_str())),
+            );
+        }
+    }
+    unused_dependencies.sort();
+
+    Ok(UnusedReport {
+        unused_exports,
+        unused_css_classes,
+        unreachable_files: unreachable_files.into_iter().collect(),
+        unused_dependencies,
+    }
+    .cell())
+}
+
+async fn collect_unreachable_files(
+    dir: Vc<FileSystemPath>,
+    project: &FileSystemPath,
+    ignore: &[ReadRef<Glob>],
+    reachable_files: &HashSet<RcStr>,
+    unreachable_files: &mut BTreeSet<RcStr>,
+) -> Result<()> {
+    let DirectoryContent::Entries(entries) = &*dir.read_dir().await? else {
+        return Ok(());
+    };
+    for (name, entry) in entries.iter() {
+        match *entry {
+            DirectoryEntry::Directory(path) => {
+                if name.starts_with('.') || name == "node_modules" {
+                    continue;
+                }
+                Box::pin(collect_unreachable_files(
+                    path,
+                    project,
+                    ignore,
+                    reachable_files,
+                    unreachable_files,
+                ))
+                .await?;
+            }
+            DirectoryEntry::File(path) => {
+                let path = path.await?;
+                // Hidden files are configurations of tools, e.g. `.eslintrc.js`
+                if name.starts_with('.')
+                    || !path
+                        .extension_ref()
+                        .is_some_and(|ext| SOURCE_EXTENSIONS.contains(&ext))
+                {
+                    continue;
+                }
+                if let Some(relative) = project.get_path_to(&path) {
+                    if !reachable_files.contains(relative)
+                        && !ignore.iter().any(|glob| glob.execute(relative))
+                    {
+                        unreachable_files.insert(relative.into());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Emits warnings for all dead code in the report.
+#[turbo_tasks::function]
+pub async fn emit_unused_issues(
+    report: Vc<UnusedReport>,
+    project_path: Vc<FileSystemPath>,
+) -> Result<Vc<()>> {
+    let report = report.await?;
+    for (path, exports) in report.unused_exports.iter() {
+        UnusedCodeIssue {
+            path: project_path.join(path.clone()),
+            kind: UnusedCodeKind::Exports(exports.clone()),
+        }
+        .cell()
+        .emit();
+    }
+    for (path, classes) in report.unused_css_classes.iter() {
+        UnusedCodeIssue {
+            path: project_path.join(path.clone()),
+            kind: UnusedCodeKind::CssClasses(classes.clone()),
+        }
+        .cell()
+        .emit();
+    }
+    for path in report.unreachable_files.iter() {
+        UnusedCodeIssue {
+            path: project_path.join(path.clone()),
+            kind: UnusedCodeKind::UnreachableFile,
+        }
+        .cell()
+        .emit();
+    }
+    for name in report.unused_dependencies.iter() {
+        UnusedCodeIssue {
+            path: project_path.join("package.json".into()),
+            kind: UnusedCodeKind::Dependency(name.clone()),
+        }
+        .cell()
+        .emit();
+    }
+    Ok(Default::default())
+}
+
+#[turbo_tasks::value(shared)]
+#[derive(Debug)]
+enum UnusedCodeKind {
+    Exports(Vec<RcStr>),
+    CssClasses(Vec<RcStr>),
+    UnreachableFile,
+    Dependency(RcStr),
+}
+
+#[turbo_tasks::value(shared)]
+struct UnusedCodeIssue {
+    path: Vc<FileSystemPath>,
+    kind: UnusedCodeKind,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for UnusedCodeIssue {
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        self.path
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Text(match &self.kind {
+            UnusedCodeKind::Exports(_) => "Unused exports".into(),
+            UnusedCodeKind::CssClasses(_) => "Unused CSS module classes".into(),
+            UnusedCodeKind::UnreachableFile => "Unreachable file".into(),
+            UnusedCodeKind::Dependency(_) => "Unused dependency".into(),
+        })
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    async fn description(&self) -> Result<Vc<OptionStyledString>> {
+        let text: RcStr = match &self.kind {
+            UnusedCodeKind::Exports(exports) => format!(
+                "The following exports are not imported anywhere in the module graph: {}",
+                exports.join(", ")
+            )
+            .into(),
+            UnusedCodeKind::CssClasses(classes) => format!(
+                "The following classes are not used by any module in the module graph: {}",
+                classes.join(", ")
+            )
+            .into(),
+            UnusedCodeKind::UnreachableFile => format!(
+                "{} is not reachable from any entry.",
+                self.path.to_string().await?
+            )
+            .into(),
+            UnusedCodeKind::Dependency(name) => format!(
+                "The dependency \"{name}\" is never resolved by any module reachable from an \
+                 entry."
+            )
+            .into(),
+        };
+        Ok(Vc::cell(Some(StyledString::Text(text).cell())))
+    }
+
+    #[turbo_tasks::function]
+    fn severity(&self) -> Vc<IssueSeverity> {
+        IssueSeverity::Warning.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Analysis.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use turbo_tasks_fs::glob::Glob;
+
+    use super::{package_name, request_package_name, DEFAULT_IGNORED_FILES};
+
+    #[test]
+    fn package_names_of_paths() {
+        assert_eq!(package_name("node_modules/react/index.js"), Some("react"));
+        assert_eq!(
+            package_name("node_modules/@scope/pkg/dist/index.js"),
+            Some("@scope/pkg")
+        );
+        assert_eq!(
+            package_name("node_modules/a/node_modules/b/index.js"),
+            Some("a")
+        );
+        assert_eq!(package_name("src/index.js"), None);
+    }
+
+    #[test]
+    fn package_names_of_external_requests() {
+        assert_eq!(request_package_name("react"), Some("react"));
+        assert_eq!(request_package_name("react/jsx-runtime"), Some("react"));
+        assert_eq!(request_package_name("@scope/pkg/sub"), Some("@scope/pkg"));
+        assert_eq!(request_package_name("./local.js"), None);
+        assert_eq!(request_package_name("/absolute.js"), None);
+        assert_eq!(
+            request_package_name("https://cdn.example.com/react.js"),
+            None
+        );
+        assert_eq!(request_package_name("node:fs"), None);
+    }
+
+    #[test]
+    fn ignores_files_which_are_not_imported() {
+        let globs = DEFAULT_IGNORED_FILES
+            .iter()
+            .map(|glob| Glob::parse(glob).unwrap())
+            .collect::<Vec<_>>();
+        let is_ignored = |path: &str| globs.iter().any(|glob| glob.execute(path));
+
+        assert!(is_ignored("types.d.ts"));
+        assert!(is_ignored("src/types.d.mts"));
+        assert!(is_ignored("src/button.test.tsx"));
+        assert!(is_ignored("src/button.spec.js"));
+        assert!(is_ignored("src/__tests__/button.js"));
+        assert!(is_ignored("src/__mocks__/fs.js"));
+        assert!(is_ignored("vite.config.ts"));
+        assert!(is_ignored("dist/index.js"));
+
+        assert!(!is_ignored("src/index.ts"));
+        assert!(!is_ignored("src/config.ts"));
+        assert!(!is_ignored("src/testing.js"));
+    }
+}
@@ -2,12 +2,12 @@ use std::{collections::HashMap, fmt};
 
 use anyhow::Result;
 use turbo_tasks::{RcStr, Value, Vc};
-use turbo_tasks_fs::{FileSystem, FileSystemPath};
+use turbo_tasks_fs::{FileSystem, FileSystemEntryType, FileSystemPath, FileSystemPathOption};
 use turbopack::{
     ecmascript::{EcmascriptInputTransform, TreeShakingMode},
     module_options::{
-        EcmascriptOptionsContext, JsxTransformOptions, ModuleOptionsContext, ModuleRule,
-        ModuleRuleCondition, ModuleRuleEffect,
+        CssOptionsContext, EcmascriptOptionsContext, JsxTransformOptions, ModuleOptionsContext,
+        ModuleRule, ModuleRuleCondition, ModuleRuleEffect,
     },
     ModuleAssetContext,
 };
@@ -25,6 +25,8 @@ use turbopack_ecmascript_plugins::transform::{
     styled_components::{StyledComponentsTransformConfig, StyledComponentsTransformer},
     styled_jsx::StyledJsxTransformer,
 };
+use turbopack_image::svg::SvgMinifyOptions;
+use turbopack_json::DEFAULT_OBJECT_LITERAL_THRESHOLD;
 use turbopack_node::{
     execution_context::ExecutionContext, transforms::postcss::PostCssTransformOptions,
 };
@@ -70,6 +72,22 @@ pub async fn get_client_import_map(project_path: Vc<FileSystemPath>) -> Result<V
     Ok(import_map.cell())
 }
 
+/// The [W3C import map](https://html.spec.whatwg.org/multipage/webappapis.html#import-maps)
+/// of the project, an `importmap.json` in the project directory.
+#[turbo_tasks::function]
+pub async fn get_client_browser_import_map(
+    project_path: Vc<FileSystemPath>,
+) -> Result<Vc<FileSystemPathOption>> {
+    let import_map_path = project_path.join("importmap.json".into());
+    Ok(Vc::cell(
+        matches!(
+            &*import_map_path.get_type().await?,
+            FileSystemEntryType::File
+        )
+        .then_some(import_map_path),
+    ))
+}
+
 #[turbo_tasks::function]
 pub async fn get_client_resolve_options_context(
     project_path: Vc<FileSystemPath>,
@@ -79,6 +97,7 @@ pub async fn get_client_resolve_options_context(
         enable_node_modules: Some(project_path.root().resolve().await?),
         custom_conditions: vec!["development".into()],
         import_map: Some(next_client_import_map),
+        browser_import_map: *get_client_browser_import_map(project_path).await?,
         browser: true,
         module: true,
         ..Default::default()
@@ -101,8 +120,24 @@ async fn get_client_module_options_context(
     execution_context: Vc<ExecutionContext>,
     env: Vc<Environment>,
     node_env: Vc<NodeEnv>,
+    static_inline_limit: Option<usize>,
 ) -> Result<Vc<ModuleOptionsContext>> {
+    let enable_hot_module_replacement = matches!(*node_env.await?, NodeEnv::Development);
+
     let module_options_context = ModuleOptionsContext {
+        ecmascript: EcmascriptOptionsContext {
+            enable_hot_module_replacement,
+            ..Default::default()
+        },
+        css: CssOptionsContext {
+            enable_sass: true,
+            ..Default::default()
+        },
+        enable_svg_minification: matches!(*node_env.await?, NodeEnv::Production)
+            .then(|| SvgMinifyOptions::default().cell()),
+        enable_svg_components: true,
+        json_object_literal_threshold: Some(DEFAULT_OBJECT_LITERAL_THRESHOLD),
+        static_inline_limit,
         preset_env_versions: Some(env),
         execution_context: Some(execution_context),
         tree_shaking_mode: Some(TreeShakingMode::ReexportsOnly),
@@ -157,6 +192,7 @@ async fn get_client_module_options_context(
         ecmascript: EcmascriptOptionsContext {
             enable_jsx,
             enable_typescript_transform: Some(Default::default()),
+            enable_hot_module_replacement,
             ..Default::default()
         },
         enable_postcss_transform: Some(PostCssTransformOptions::default().cell()),
@@ -178,6 +214,7 @@ pub fn get_client_asset_context(
     execution_context: Vc<ExecutionContext>,
     compile_time_info: Vc<CompileTimeInfo>,
     node_env: Vc<NodeEnv>,
+    static_inline_limit: Option<usize>,
 ) -> Vc<Box<dyn AssetContext>> {
     let resolve_options_context = get_client_resolve_options_context(project_path);
     let module_options_context = get_client_module_options_context(
@@ -185,6 +222,7 @@ pub fn get_client_asset_context(
         execution_context,
         compile_time_info.environment(),
         node_env,
+        static_inline_limit,
     );
 
     let asset_context: Vc<Box<dyn AssetContext>> = Vc::upcast(ModuleAssetContext::new(
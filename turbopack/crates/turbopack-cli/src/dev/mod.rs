@@ -59,6 +59,7 @@ pub struct TurbopackDevServerBuilder {
     issue_reporter: Option<Box<dyn IssueReporterProvider>>,
     port: Option<u16>,
     browserslist_query: RcStr,
+    static_inline_limit: Option<usize>,
     log_level: IssueSeverity,
     show_all: bool,
     log_detail: bool,
@@ -83,6 +84,7 @@ impl TurbopackDevServerBuilder {
             browserslist_query: "last 1 Chrome versions, last 1 Firefox versions, last 1 Safari \
                                  versions, last 1 Edge versions"
                 .into(),
+            static_inline_limit: None,
             log_level: IssueSeverity::Warning,
             show_all: false,
             log_detail: false,
@@ -115,6 +117,15 @@ impl TurbopackDevServerBuilder {
         self
     }
 
+    /// Inlines static assets smaller than this many bytes as data URLs.
+    pub fn static_inline_limit(
+        mut self,
+        static_inline_limit: Option<usize>,
+    ) -> TurbopackDevServerBuilder {
+        self.static_inline_limit = static_inline_limit;
+        self
+    }
+
     pub fn log_level(mut self, log_level: IssueSeverity) -> TurbopackDevServerBuilder {
         self.log_level = log_level;
         self
@@ -196,6 +207,7 @@ impl TurbopackDevServerBuilder {
         let show_all = self.show_all;
         let log_detail: bool = self.log_detail;
         let browserslist_query: RcStr = self.browserslist_query;
+        let static_inline_limit = self.static_inline_limit;
         let log_args = TransientInstance::new(LogOptions {
             current_dir: current_dir().unwrap(),
             project_dir: PathBuf::from(project_dir.clone()),
@@ -217,6 +229,7 @@ impl TurbopackDevServerBuilder {
                 entry_requests.clone(),
                 eager_compile,
                 browserslist_query.clone(),
+                static_inline_limit,
             )
         };
 
@@ -232,6 +245,7 @@ async fn source(
     entry_requests: TransientInstance<Vec<EntryRequest>>,
     eager_compile: bool,
     browserslist_query: RcStr,
+    static_inline_limit: Option<usize>,
 ) -> Result<Vc<Box<dyn ContentSource>>> {
     let project_relative = project_dir.strip_prefix(&*root_dir).unwrap();
     let project_relative: RcStr = project_relative
@@ -290,6 +304,7 @@ async fn source(
         eager_compile,
         NodeEnv::Development.cell(),
         browserslist_query,
+        static_inline_limit,
     );
     let static_source = Vc::upcast(StaticAssetsContentSource::new(
         Default::default(),
@@ -342,6 +357,7 @@ pub async fn start_server(args: &DevArguments) -> Result<()> {
         .eager_compile(args.eager_compile)
         .hostname(args.hostname)
         .port(args.port)
+        .static_inline_limit(args.common.static_inline_limit)
         .log_detail(args.common.log_detail)
         .show_all(args.common.show_all)
         .log_level(
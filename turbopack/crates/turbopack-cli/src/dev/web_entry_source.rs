@@ -89,10 +89,16 @@ pub async fn create_web_entry_source(
     eager_compile: bool,
     node_env: Vc<NodeEnv>,
     browserslist_query: RcStr,
+    static_inline_limit: Option<usize>,
 ) -> Result<Vc<Box<dyn ContentSource>>> {
     let compile_time_info = get_client_compile_time_info(browserslist_query, node_env);
-    let asset_context =
-        get_client_asset_context(project_path, execution_context, compile_time_info, node_env);
+    let asset_context = get_client_asset_context(
+        project_path,
+        execution_context,
+        compile_time_info,
+        node_env,
+        static_inline_limit,
+    );
     let chunking_context =
         get_client_chunking_context(project_path, server_root, compile_time_info.environment());
     let entries = get_client_runtime_entries(project_path);
@@ -0,0 +1,134 @@
+use std::{
+    env::current_dir,
+    path::{PathBuf, MAIN_SEPARATOR},
+};
+
+use anyhow::Result;
+use turbo_tasks::{RcStr, TransientInstance, TurboTasks, Value, ValueToString, Vc};
+use turbo_tasks_fs::FileSystem;
+use turbo_tasks_memory::MemoryBackend;
+use turbopack_cli_utils::issue::{ConsoleUi, LogOptions};
+use turbopack_core::{
+    issue::{handle_issues, resolve::ResolvingIssue, IssueExt, IssueReporter, IssueSeverity},
+    reference_type::{CommonJsReferenceSubType, EcmaScriptModulesReferenceSubType, ReferenceType},
+    resolve::{explain::explain_resolve, parse::Request},
+};
+use turbopack_resolve::resolve::resolve_options;
+
+use crate::{
+    arguments::ResolveArguments,
+    contexts::get_client_resolve_options_context,
+    util::{normalize_dirs, project_fs, NormalizedDirs},
+};
+
+#[turbo_tasks::function]
+async fn resolve_request(
+    project_dir: RcStr,
+    root_dir: RcStr,
+    from: RcStr,
+    request: RcStr,
+    commonjs: bool,
+    explain: bool,
+) -> Result<Vc<RcStr>> {
+    let project_fs = project_fs(root_dir.clone());
+    let project_relative = project_dir.strip_prefix(&*root_dir).unwrap();
+    let project_relative: RcStr = project_relative
+        .strip_prefix(MAIN_SEPARATOR)
+        .unwrap_or(project_relative)
+        .replace(MAIN_SEPARATOR, "/")
+        .into();
+    let project_path = project_fs.root().join(project_relative);
+    let origin_path = project_path.join(from);
+    let lookup_path = origin_path.parent();
+
+    let reference_type = if commonjs {
+        ReferenceType::CommonJs(CommonJsReferenceSubType::Undefined)
+    } else {
+        ReferenceType::EcmaScriptModules(EcmaScriptModulesReferenceSubType::Undefined)
+    };
+    let options = resolve_options(
+        lookup_path,
+        get_client_resolve_options_context(project_path),
+    );
+    let request = Request::parse_string(request);
+
+    let result = turbopack_core::resolve::resolve(
+        lookup_path,
+        Value::new(reference_type.clone()),
+        request,
+        options,
+    );
+
+    if *result.is_unresolveable().await? {
+        ResolvingIssue {
+            severity: IssueSeverity::Error.cell(),
+            request_type: format!("{} request", reference_type),
+            request,
+            file_path: origin_path,
+            resolve_options: options,
+            error_message: None,
+            source: None,
+            // Only traced with `--explain`, which prints the trace as the output
+            resolve_trace: None,
+        }
+        .cell()
+        .emit();
+    }
+
+    Ok(if explain {
+        explain_resolve(lookup_path, Value::new(reference_type), request, options).to_string()
+    } else {
+        result.to_string()
+    })
+}
+
+pub async fn resolve(args: &ResolveArguments) -> Result<()> {
+    let NormalizedDirs {
+        project_dir,
+        root_dir,
+    } = normalize_dirs(&args.dir, &args.root)?;
+
+    let tt = TurboTasks::new(MemoryBackend::new(usize::MAX));
+
+    let from: RcStr = args.from.as_str().into();
+    let request: RcStr = args.request.as_str().into();
+    let commonjs = args.commonjs;
+    let explain = args.explain;
+
+    let task = tt.spawn_once_task::<(), _>(async move {
+        let output = resolve_request(
+            project_dir.clone(),
+            root_dir,
+            from,
+            request,
+            commonjs,
+            explain,
+        );
+
+        println!("{}", output.await?);
+
+        let issue_reporter: Vc<Box<dyn IssueReporter>> =
+            Vc::upcast(ConsoleUi::new(TransientInstance::new(LogOptions {
+                project_dir: PathBuf::from(project_dir),
+                current_dir: current_dir().unwrap(),
+                show_all: true,
+                log_detail: true,
+                log_level: IssueSeverity::Warning,
+            })));
+
+        handle_issues(
+            output,
+            issue_reporter,
+            IssueSeverity::Error.into(),
+            None,
+            None,
+        )
+        .await?;
+
+        Ok(Default::default())
+    });
+
+    tt.wait_task_completion(task, true).await?;
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use std::{
     borrow::Cow,
     mem::{replace, take},
+    ops::Range,
     pin::Pin,
 };
 
@@ -92,12 +93,16 @@ pub async fn make_chunks(
             empty_referenced_output_assets: OutputAssets::empty().resolve().await?,
         };
 
-        app_vendors_split(
-            chunk_items,
-            format!("{key_prefix}{ty_name}"),
-            &mut split_context,
-        )
-        .await?;
+        let name = format!("{key_prefix}{ty_name}");
+        if *ty.must_keep_item_order().await? {
+            if *chunking_context.single_ordered_chunk().await? {
+                make_chunk(chunk_items, &mut name.clone(), &mut split_context).await?;
+            } else {
+                ordered_split(chunk_items, name, &mut split_context).await?;
+            }
+        } else {
+            app_vendors_split(chunk_items, name, &mut split_context).await?;
+        }
     }
 
     Ok(Vc::cell(chunks))
@@ -213,6 +218,68 @@ async fn app_vendors_split(
     Ok(())
 }
 
+/// Split chunk items into chunks without changing their order. Consecutive
+/// chunk items of the app or of the same package are kept together, as long
+/// as the chunk size permits.
+#[tracing::instrument(level = Level::TRACE, skip_all, fields(name = display(&name)))]
+async fn ordered_split(
+    chunk_items: Vec<ChunkItemWithInfo>,
+    name: String,
+    split_context: &mut SplitContext<'_>,
+) -> Result<()> {
+    let groups = chunk_items
+        .iter()
+        .map(|(_, _, size, asset_ident)| {
+            let group = if is_app_code(asset_ident) {
+                "app".to_string()
+            } else {
+                package_name(asset_ident).to_string()
+            };
+            (group, *size)
+        })
+        .collect::<Vec<_>>();
+    let mut chunk_items = chunk_items.into_iter();
+    for (range, group) in ordered_split_ranges(&groups) {
+        let mut key = format!("{}-{}", name, group);
+        make_chunk(
+            chunk_items.by_ref().take(range.len()).collect(),
+            &mut key,
+            split_context,
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+/// Returns the ranges of consecutive chunk items, given as their group and
+/// size, which [ordered_split] puts into the same chunk, together with the
+/// group of the last chunk item of each range.
+///
+/// A new chunk is started when the group changes and the current chunk isn't
+/// too small, or when the current chunk would become too large.
+fn ordered_split_ranges(items: &[(String, usize)]) -> Vec<(Range<usize>, &str)> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    let mut current_size = 0;
+    for (index, (group, size)) in items.iter().enumerate() {
+        if index > start {
+            let current_group = &items[index - 1].0;
+            if (current_group != group && current_size > SMALL_CHUNK)
+                || current_size + size >= LARGE_CHUNK
+            {
+                ranges.push((start..index, current_group.as_str()));
+                start = index;
+                current_size = 0;
+            }
+        }
+        current_size += size;
+    }
+    if let Some((group, _)) = items.last() {
+        ranges.push((start..items.len(), group.as_str()));
+    }
+    ranges
+}
+
 /// Split chunk items by node_modules package name. Continues splitting with
 /// [folder_split] if necessary.
 #[tracing::instrument(level = Level::TRACE, skip_all, fields(name = display(&name)))]
@@ -364,3 +431,63 @@ fn chunk_size(chunk_items: &[ChunkItemWithInfo]) -> ChunkSize {
         ChunkSize::Small
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ordered_split_ranges, LARGE_CHUNK, SMALL_CHUNK};
+
+    fn items(items: &[(&str, usize)]) -> Vec<(String, usize)> {
+        items
+            .iter()
+            .map(|&(group, size)| (group.to_string(), size))
+            .collect()
+    }
+
+    #[test]
+    fn keeps_small_groups_together() {
+        let items = items(&[("app", 10), ("react", 10), ("app", 10)]);
+        assert_eq!(ordered_split_ranges(&items), vec![(0..3, "app")]);
+    }
+
+    #[test]
+    fn splits_when_the_group_changes() {
+        let items = items(&[
+            ("app", SMALL_CHUNK),
+            ("app", 1),
+            ("react", 10),
+            ("react", 10),
+            ("app", 10),
+        ]);
+        assert_eq!(
+            ordered_split_ranges(&items),
+            vec![(0..2, "app"), (2..5, "app")]
+        );
+    }
+
+    #[test]
+    fn splits_large_chunks() {
+        let items = items(&[("app", LARGE_CHUNK - 1), ("app", 1), ("app", 1)]);
+        assert_eq!(
+            ordered_split_ranges(&items),
+            vec![(0..1, "app"), (1..3, "app")]
+        );
+    }
+
+    #[test]
+    fn keeps_the_order() {
+        let items = items(&[
+            ("a", SMALL_CHUNK + 1),
+            ("b", SMALL_CHUNK + 1),
+            ("a", SMALL_CHUNK + 1),
+        ]);
+        assert_eq!(
+            ordered_split_ranges(&items),
+            vec![(0..1, "a"), (1..2, "b"), (2..3, "a")]
+        );
+    }
+
+    #[test]
+    fn handles_no_items() {
+        assert_eq!(ordered_split_ranges(&[]), vec![]);
+    }
+}
@@ -72,6 +72,12 @@ pub trait ChunkingContext {
     /// the static asset based on its `ident`.
     fn asset_url(self: Vc<Self>, ident: Vc<AssetIdent>) -> Result<Vc<RcStr>>;
 
+    /// Returns a URL (relative or absolute, depending on the chunk base path)
+    /// from which the runtime loads the chunk with the given `ident`.
+    fn chunk_url(self: Vc<Self>, ident: Vc<AssetIdent>) -> Vc<RcStr> {
+        self.asset_url(ident)
+    }
+
     fn asset_path(
         self: Vc<Self>,
         content_hash: RcStr,
@@ -82,6 +88,13 @@ pub trait ChunkingContext {
         Vc::cell(false)
     }
 
+    /// Whether all chunk items of a chunk type which must keep its item order,
+    /// e.g. CSS, are put into a single chunk per chunk group instead of being
+    /// split into multiple chunks.
+    fn single_ordered_chunk(self: Vc<Self>) -> Vc<bool> {
+        Vc::cell(false)
+    }
+
     fn async_loader_chunk_item(
         &self,
         module: Vc<Box<dyn ChunkableModule>>,
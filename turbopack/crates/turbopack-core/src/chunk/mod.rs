@@ -707,6 +707,13 @@ pub trait ChunkType: ValueToString {
         chunk_item: Vc<Box<dyn ChunkItem>>,
         async_module_info: Option<Vc<AsyncModuleInfo>>,
     ) -> Vc<usize>;
+
+    /// Whether the order of chunk items is significant, e.g. for the cascade
+    /// of CSS. Chunk items of such a type are never reordered when they are
+    /// split into chunks.
+    fn must_keep_item_order(self: Vc<Self>) -> Vc<bool> {
+        Vc::cell(false)
+    }
 }
 
 #[turbo_tasks::value(transparent)]
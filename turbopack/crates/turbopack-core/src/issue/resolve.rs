@@ -9,6 +9,7 @@ use crate::{
     error::PrettyPrintError,
     issue::IssueSeverity,
     resolve::{
+        explain::LazyResolveTrace,
         options::{ImportMap, ImportMapResult, ResolveOptions},
         parse::Request,
     },
@@ -23,6 +24,9 @@ pub struct ResolvingIssue {
     pub resolve_options: Vc<ResolveOptions>,
     pub error_message: Option<String>,
     pub source: Option<Vc<IssueSource>>,
+    /// The request to trace, see [crate::resolve::explain::explain_resolve].
+    /// The trace is computed for the detail when present.
+    pub resolve_trace: Option<Vc<LazyResolveTrace>>,
 }
 
 #[turbo_tasks::value_impl]
@@ -106,6 +110,16 @@ impl Issue for ResolvingIssue {
             "Type of request: {request_type}",
             request_type = self.request_type,
         )?;
+        if let Some(resolve_trace) = self.resolve_trace {
+            // Tracing resolves the request again, which fails for the same
+            // requests that failed with an error
+            if let Ok(resolve_trace) = resolve_trace.trace().to_string().await {
+                writeln!(detail, "Resolve trace:")?;
+                for line in resolve_trace.lines() {
+                    writeln!(detail, "  {line}")?;
+                }
+            }
+        }
         Ok(Vc::cell(Some(StyledString::Text(detail.into()).cell())))
     }
 
@@ -114,7 +128,6 @@ impl Issue for ResolvingIssue {
         Vc::cell(self.source)
     }
 
-    // TODO add sub_issue for a description of resolve_options
     // TODO add source link
 }
 
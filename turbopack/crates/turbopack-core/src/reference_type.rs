@@ -36,7 +36,12 @@ pub enum CommonJsReferenceSubType {
 #[turbo_tasks::value(serialization = "auto_for_input")]
 #[derive(Debug, Clone, Hash)]
 pub enum ImportWithType {
+    /// `with { type: "json" }`, which is always parsed as strict JSON.
     Json,
+    /// `with { type: "text" }`, which exports the content as a string.
+    Text,
+    /// `with { type: "bytes" }`, which exports the content as a `Uint8Array`.
+    Bytes,
 }
 
 #[turbo_tasks::value(serialization = "auto_for_input")]
@@ -46,6 +51,7 @@ pub enum EcmaScriptModulesReferenceSubType {
     Import,
     ImportWithType(ImportWithType),
     DynamicImport,
+    Worker,
     Custom(u8),
     #[default]
     Undefined,
@@ -93,10 +99,10 @@ impl ImportContext {
 
         let layers = {
             let mut layers = this.layers.clone();
+            // A layer nested in a layer of the same name is a different layer, e.g.
+            // `base.base`
             if let Some(attr_layer) = attr_layer {
-                if !layers.contains(&attr_layer) {
-                    layers.push(attr_layer);
-                }
+                layers.push(attr_layer);
             }
             layers
         };
@@ -0,0 +1,346 @@
+use std::{
+    fmt::{Display, Formatter, Write},
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use turbo_tasks::{emit, trace::TraceRawVcs, CollectiblesSource, RcStr, Value, ValueToString, Vc};
+use turbo_tasks_fs::FileSystemPath;
+
+use super::{
+    options::{ConditionValue, ResolveOptions},
+    parse::Request,
+    resolve_internal, ResolveResult, ResolveResultItem,
+};
+use crate::{issue::Issue, reference_type::ReferenceType};
+
+/// The category of a [ResolveTraceStep].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, TraceRawVcs)]
+pub enum ResolveTraceStepKind {
+    Request,
+    BeforeResolvePlugin,
+    /// A lookup in the import map. This includes aliases and tsconfig.json
+    /// `compilerOptions.paths`.
+    ImportMap,
+    Modules,
+    PackageJson,
+    Extensions,
+    FallbackImportMap,
+    AfterResolvePlugin,
+    Result,
+}
+
+impl Display for ResolveTraceStepKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ResolveTraceStepKind::Request => "request",
+            ResolveTraceStepKind::BeforeResolvePlugin => "before resolve plugin",
+            ResolveTraceStepKind::ImportMap => "import map",
+            ResolveTraceStepKind::Modules => "modules",
+            ResolveTraceStepKind::PackageJson => "package.json",
+            ResolveTraceStepKind::Extensions => "extensions",
+            ResolveTraceStepKind::FallbackImportMap => "fallback import map",
+            ResolveTraceStepKind::AfterResolvePlugin => "after resolve plugin",
+            ResolveTraceStepKind::Result => "result",
+        })
+    }
+}
+
+/// A step the resolver took, see [ResolveTracer].
+#[turbo_tasks::value(shared)]
+#[derive(Clone, Debug)]
+pub struct ResolveTraceStep {
+    pub kind: ResolveTraceStepKind,
+    pub message: RcStr,
+}
+
+/// The steps a resolve task took, in order. Collectibles are unordered, so
+/// each task emits its steps as a single collectible.
+#[turbo_tasks::value(shared)]
+#[derive(Clone, Debug)]
+pub struct ResolveTraceSteps {
+    pub steps: Vec<ResolveTraceStep>,
+}
+
+#[turbo_tasks::value_trait]
+pub trait ResolveTraceEntry {
+    fn steps(self: Vc<Self>) -> Vc<ResolveTraceSteps>;
+}
+
+#[turbo_tasks::value_impl]
+impl ResolveTraceEntry for ResolveTraceSteps {
+    #[turbo_tasks::function]
+    fn steps(self: Vc<Self>) -> Vc<ResolveTraceSteps> {
+        self
+    }
+}
+
+enum TracedEntry {
+    Step(ResolveTraceStep),
+    /// A part of the resolve which runs concurrently with other parts, e.g.
+    /// one of multiple alternatives.
+    Branch(ResolveTracer),
+    /// A resolve which runs in its own task.
+    Task(Vc<ResolveResult>),
+}
+
+/// Records the steps of a resolve when [ResolveOptions::trace] is set, and
+/// does nothing otherwise. The order of the steps follows the call structure:
+/// concurrent parts of a resolve record into their own [ResolveTracer::branch],
+/// and resolve tasks are recorded with [ResolveTracer::task], so their steps
+/// are inlined where they were started.
+#[derive(Clone, Default)]
+pub(super) struct ResolveTracer(Option<Arc<Mutex<Vec<TracedEntry>>>>);
+
+impl ResolveTracer {
+    pub(super) fn new(options: &ResolveOptions) -> Self {
+        ResolveTracer(options.trace.then(Default::default))
+    }
+
+    fn push(&self, entry: TracedEntry) {
+        if let Some(entries) = &self.0 {
+            entries.lock().unwrap().push(entry);
+        }
+    }
+
+    pub(super) fn step(&self, kind: ResolveTraceStepKind, message: impl Into<RcStr>) {
+        self.push(TracedEntry::Step(ResolveTraceStep {
+            kind,
+            message: message.into(),
+        }));
+    }
+
+    /// Returns a tracer for a concurrent part of the resolve. Its steps are
+    /// placed at the current position.
+    pub(super) fn branch(&self) -> Self {
+        if self.0.is_none() {
+            return ResolveTracer(None);
+        }
+        let branch = ResolveTracer(Some(Default::default()));
+        self.push(TracedEntry::Branch(branch.clone()));
+        branch
+    }
+
+    /// Places the steps of the resolve task which computes `result` at the
+    /// current position.
+    pub(super) fn task(&self, result: Vc<ResolveResult>) -> Vc<ResolveResult> {
+        self.push(TracedEntry::Task(result));
+        result
+    }
+
+    fn steps(self) -> Pin<Box<dyn Future<Output = Result<Vec<ResolveTraceStep>>> + Send>> {
+        Box::pin(async move {
+            let entries = match &self.0 {
+                Some(entries) => std::mem::take(&mut *entries.lock().unwrap()),
+                None => Vec::new(),
+            };
+            let mut steps = Vec::new();
+            for entry in entries {
+                match entry {
+                    TracedEntry::Step(step) => steps.push(step),
+                    TracedEntry::Branch(branch) => steps.extend(branch.steps().await?),
+                    TracedEntry::Task(result) => steps.extend(task_steps(result).await?),
+                }
+            }
+            Ok(steps)
+        })
+    }
+
+    /// Emits the recorded steps. Called by each resolve task when it's done.
+    pub(super) async fn emit(self) -> Result<()> {
+        if self.0.is_none() {
+            return Ok(());
+        }
+        let steps = self.steps().await?;
+        emit(Vc::upcast::<Box<dyn ResolveTraceEntry>>(
+            ResolveTraceSteps { steps }.cell(),
+        ));
+        Ok(())
+    }
+}
+
+/// Takes the steps emitted while computing `result`.
+async fn task_steps(result: Vc<ResolveResult>) -> Result<Vec<ResolveTraceStep>> {
+    let result = result.resolve_strongly_consistent().await?;
+    let mut entries = Vec::new();
+    for entry in result.take_collectibles::<Box<dyn ResolveTraceEntry>>() {
+        entries.push(entry.steps().await?.steps.clone());
+    }
+    // Each resolve task emits a single entry, so there is only more than one
+    // when a task didn't record the tasks it started. Sort them to stay
+    // deterministic anyway.
+    entries.sort_by(|a, b| {
+        a.iter()
+            .map(|step| &step.message)
+            .cmp(b.iter().map(|step| &step.message))
+    });
+    Ok(entries.into_iter().flatten().collect())
+}
+
+/// A step by step description of how a request is resolved, used to diagnose
+/// unresolveable requests or unexpected resolve results.
+#[turbo_tasks::value(shared)]
+#[derive(Clone, Debug, Default)]
+pub struct ResolveTrace {
+    pub steps: Vec<ResolveTraceStep>,
+}
+
+impl ResolveTrace {
+    /// Collects the steps recorded while computing `result`, which must have
+    /// been resolved with [ResolveOptions::trace] set.
+    async fn collect(result: Vc<ResolveResult>) -> Result<Self> {
+        let result = result.resolve_strongly_consistent().await?;
+        // The traced resolve runs the same code, so it reports the same issues
+        // again. They are already reported by the regular resolve.
+        let _ = result.take_collectibles::<Box<dyn Issue>>();
+
+        let mut steps = task_steps(result).await?;
+
+        let result = result.await?;
+        if result.primary.is_empty() {
+            steps.push(ResolveTraceStep {
+                kind: ResolveTraceStepKind::Result,
+                message: "unresolveable".into(),
+            });
+        }
+        for (key, item) in result.primary.iter() {
+            steps.push(ResolveTraceStep {
+                kind: ResolveTraceStepKind::Result,
+                message: format!("{key} -> {}", describe_item(item).await?).into(),
+            });
+        }
+
+        Ok(ResolveTrace { steps })
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ValueToString for ResolveTrace {
+    #[turbo_tasks::function]
+    fn to_string(&self) -> Result<Vc<RcStr>> {
+        let mut result = String::new();
+        for step in &self.steps {
+            writeln!(result, "[{}] {}", step.kind, step.message)?;
+        }
+        Ok(Vc::cell(result.into()))
+    }
+}
+
+/// A request to explain, attached to issues instead of the [ResolveTrace]
+/// itself. Tracing resolves the request again, so this is only done when the
+/// trace is read, e.g. for the detail of a
+/// [crate::issue::resolve::ResolvingIssue].
+#[turbo_tasks::value(shared)]
+pub struct LazyResolveTrace {
+    pub lookup_path: Vc<FileSystemPath>,
+    /// The reference type to resolve the request with. The resolve plugins
+    /// are skipped when this is `None`, which is used for issues reported by
+    /// the resolver itself.
+    pub reference_type: Option<ReferenceType>,
+    pub request: Vc<Request>,
+    pub options: Vc<ResolveOptions>,
+}
+
+#[turbo_tasks::value_impl]
+impl LazyResolveTrace {
+    #[turbo_tasks::function]
+    pub fn trace(&self) -> Vc<ResolveTrace> {
+        match &self.reference_type {
+            Some(reference_type) => explain_resolve(
+                self.lookup_path,
+                Value::new(reference_type.clone()),
+                self.request,
+                self.options,
+            ),
+            None => explain_resolve_internal(self.lookup_path, self.request, self.options),
+        }
+    }
+}
+
+/// Resolves `request` like [super::resolve] does, but with
+/// [ResolveOptions::trace] set, and returns the steps the resolver recorded
+/// along the way.
+#[turbo_tasks::function]
+pub async fn explain_resolve(
+    lookup_path: Vc<FileSystemPath>,
+    reference_type: Value<ReferenceType>,
+    request: Vc<Request>,
+    options: Vc<ResolveOptions>,
+) -> Result<Vc<ResolveTrace>> {
+    let result = super::resolve(lookup_path, reference_type, request, options.with_trace());
+    Ok(ResolveTrace::collect(result).await?.cell())
+}
+
+/// Like [explain_resolve], but without the resolve plugins. Used for issues
+/// reported by the resolver itself.
+#[turbo_tasks::function]
+async fn explain_resolve_internal(
+    lookup_path: Vc<FileSystemPath>,
+    request: Vc<Request>,
+    options: Vc<ResolveOptions>,
+) -> Result<Vc<ResolveTrace>> {
+    let result = resolve_internal(lookup_path, request, options.with_trace());
+    Ok(ResolveTrace::collect(result).await?.cell())
+}
+
+pub(super) fn describe_conditions(
+    conditions: &std::collections::BTreeMap<RcStr, ConditionValue>,
+    unspecified_conditions: &ConditionValue,
+) -> String {
+    let mut result = String::new();
+    for (name, value) in conditions {
+        if !result.is_empty() {
+            result.push_str(", ");
+        }
+        write!(result, "{name}={}", describe_condition(value)).unwrap();
+    }
+    if !result.is_empty() {
+        result.push_str(", ");
+    }
+    write!(
+        result,
+        "others={}",
+        describe_condition(unspecified_conditions)
+    )
+    .unwrap();
+    result
+}
+
+fn describe_condition(value: &ConditionValue) -> &'static str {
+    match value {
+        ConditionValue::Set => "set",
+        ConditionValue::Unset => "unset",
+        ConditionValue::Unknown => "unknown",
+    }
+}
+
+pub(super) fn request_kind(request: &Request) -> &'static str {
+    match request {
+        Request::Raw { .. } => "raw",
+        Request::Relative { .. } => "relative",
+        Request::Module { .. } => "module",
+        Request::ServerRelative { .. } => "server relative",
+        Request::Windows { .. } => "windows",
+        Request::Empty => "empty",
+        Request::PackageInternal { .. } => "package internal",
+        Request::Uri { .. } => "uri",
+        Request::Unknown { .. } => "unknown",
+        Request::Dynamic => "dynamic",
+        Request::Alternatives { .. } => "alternatives",
+    }
+}
+
+async fn describe_item(item: &ResolveResultItem) -> Result<RcStr> {
+    Ok(match item {
+        ResolveResultItem::Source(source) => source.ident().to_string().await?.as_str().into(),
+        ResolveResultItem::External(name, ty) => format!("external {name} ({ty})").into(),
+        ResolveResultItem::Ignore => "ignore".into(),
+        ResolveResultItem::Error(message) => format!("error: {}", message.await?).into(),
+        ResolveResultItem::Empty => "empty".into(),
+        ResolveResultItem::Custom(_) => "custom".into(),
+        ResolveResultItem::Unresolveable => "unresolveable".into(),
+    })
+}
@@ -18,6 +18,9 @@ use turbo_tasks_fs::{
 };
 
 use self::{
+    explain::{
+        describe_conditions, request_kind, LazyResolveTrace, ResolveTraceStepKind, ResolveTracer,
+    },
     options::{
         resolve_modules_options, ConditionValue, ImportMapResult, ResolveInPackage,
         ResolveIntoPackage, ResolveModules, ResolveModulesOptions, ResolveOptions,
@@ -45,6 +48,7 @@ use crate::{
 };
 
 mod alias_map;
+pub mod explain;
 pub mod node;
 pub mod options;
 pub mod origin;
@@ -1149,9 +1153,19 @@ enum FindPackageItem {
     PackageFile(Vc<FileSystemPath>),
 }
 
+/// A modules directory (e.g. `node_modules`) [find_package] looked for.
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, Debug)]
+struct ProbedModulesDirectory {
+    path: Vc<FileSystemPath>,
+    exists: bool,
+}
+
 #[turbo_tasks::value]
 struct FindPackageResult {
     packages: Vec<FindPackageItem>,
+    /// The nested modules directories in the order they were looked for, for
+    /// each entry of [ResolveModulesOptions::modules]. Used for resolve traces.
+    probed_directories: Vec<Vec<ProbedModulesDirectory>>,
     affecting_sources: Vec<Vc<Box<dyn Source>>>,
 }
 
@@ -1162,9 +1176,11 @@ async fn find_package(
     options: Vc<ResolveModulesOptions>,
 ) -> Result<Vc<FindPackageResult>> {
     let mut packages = vec![];
+    let mut probed_directories = vec![];
     let mut affecting_sources = vec![];
     let options = options.await?;
     for resolve_modules in &options.modules {
+        let mut probed = vec![];
         match resolve_modules {
             ResolveModules::Nested(root_vc, names) => {
                 let mut lookup_path = lookup_path;
@@ -1174,7 +1190,12 @@ async fn find_package(
                 while lookup_path_value.is_inside_ref(root) {
                     for name in names.iter() {
                         let fs_path = lookup_path.join(name.clone());
-                        if let Some(fs_path) = dir_exists(fs_path, &mut affecting_sources).await? {
+                        let modules_dir = dir_exists(fs_path, &mut affecting_sources).await?;
+                        probed.push(ProbedModulesDirectory {
+                            path: fs_path,
+                            exists: modules_dir.is_some(),
+                        });
+                        if let Some(fs_path) = modules_dir {
                             let fs_path = fs_path.join(package_name.clone());
                             if let Some(fs_path) =
                                 dir_exists(fs_path, &mut affecting_sources).await?
@@ -1216,9 +1237,11 @@ async fn find_package(
             }
             ResolveModules::Registry(_, _) => todo!(),
         }
+        probed_directories.push(probed);
     }
     Ok(FindPackageResult::cell(FindPackageResult {
         packages,
+        probed_directories,
         affecting_sources,
     }))
 }
@@ -1344,23 +1367,50 @@ pub async fn resolve_inline(
         )
     };
     async {
+        let options_value = options.await?;
+        let trace = ResolveTracer::new(&options_value);
+        if options_value.trace {
+            trace.step(
+                ResolveTraceStepKind::Request,
+                format!(
+                    "{} request {} ({}) from {}",
+                    reference_type,
+                    request.to_string().await?,
+                    request_kind(&*request.await?),
+                    lookup_path.to_string().await?
+                ),
+            );
+        }
         let reference_type = Value::new(reference_type);
-        let before_plugins_result =
-            handle_before_resolve_plugins(lookup_path, reference_type.clone(), request, options)
-                .await?;
+        let before_plugins_result = handle_before_resolve_plugins(
+            lookup_path,
+            reference_type.clone(),
+            request,
+            options,
+            &trace,
+        )
+        .await?;
 
         let raw_result = match before_plugins_result {
             Some(result) => result,
             None => {
-                resolve_internal(lookup_path, request, options)
+                trace
+                    .task(resolve_internal(lookup_path, request, options))
                     .resolve()
                     .await?
             }
         };
 
-        let result =
-            handle_after_resolve_plugins(lookup_path, reference_type, request, options, raw_result)
-                .await?;
+        let result = handle_after_resolve_plugins(
+            lookup_path,
+            reference_type,
+            request,
+            options,
+            raw_result,
+            &trace,
+        )
+        .await?;
+        trace.emit().await?;
         Ok(result)
     }
     .instrument(span)
@@ -1415,8 +1465,10 @@ async fn handle_before_resolve_plugins(
     reference_type: Value<ReferenceType>,
     request: Vc<Request>,
     options: Vc<ResolveOptions>,
+    trace: &ResolveTracer,
 ) -> Result<Option<Vc<ResolveResult>>> {
-    for plugin in &options.await?.before_resolve_plugins {
+    let options_value = options.await?;
+    for (i, plugin) in options_value.before_resolve_plugins.iter().enumerate() {
         let condition = plugin.before_resolve_condition().resolve().await?;
         if !condition.await?.matches(request).await? {
             continue;
@@ -1426,8 +1478,20 @@ async fn handle_before_resolve_plugins(
             .before_resolve(lookup_path, reference_type.clone(), request)
             .await?
         {
+            if options_value.trace {
+                trace.step(
+                    ResolveTraceStepKind::BeforeResolvePlugin,
+                    format!("#{i} handled the request: {}", result.to_string().await?),
+                );
+            }
             return Ok(Some(result));
         }
+        if options_value.trace {
+            trace.step(
+                ResolveTraceStepKind::BeforeResolvePlugin,
+                format!("#{i} matches, but left the request unhandled"),
+            );
+        }
     }
     Ok(None)
 }
@@ -1438,6 +1502,7 @@ async fn handle_after_resolve_plugins(
     request: Vc<Request>,
     options: Vc<ResolveOptions>,
     result: Vc<ResolveResult>,
+    trace: &ResolveTracer,
 ) -> Result<Vc<ResolveResult>> {
     async fn apply_plugins_to_path(
         path: Vc<FileSystemPath>,
@@ -1445,16 +1510,34 @@ async fn handle_after_resolve_plugins(
         reference_type: Value<ReferenceType>,
         request: Vc<Request>,
         options: Vc<ResolveOptions>,
+        trace: &ResolveTracer,
     ) -> Result<Option<Vc<ResolveResult>>> {
-        for plugin in &options.await?.plugins {
+        let options_value = options.await?;
+        for (i, plugin) in options_value.plugins.iter().enumerate() {
             let after_resolve_condition = plugin.after_resolve_condition().resolve().await?;
             if *after_resolve_condition.matches(path).await? {
                 if let Some(result) = *plugin
                     .after_resolve(path, lookup_path, reference_type.clone(), request)
                     .await?
                 {
+                    if options_value.trace {
+                        trace.step(
+                            ResolveTraceStepKind::AfterResolvePlugin,
+                            format!(
+                                "#{i} replaced {} with {}",
+                                path.to_string().await?,
+                                result.to_string().await?
+                            ),
+                        );
+                    }
                     return Ok(Some(result));
                 }
+                if options_value.trace {
+                    trace.step(
+                        ResolveTraceStepKind::AfterResolvePlugin,
+                        format!("#{i} matches {}, but kept it", path.to_string().await?),
+                    );
+                }
             }
         }
         Ok(None)
@@ -1469,9 +1552,15 @@ async fn handle_after_resolve_plugins(
     for (key, primary) in result_value.primary.iter() {
         if let &ResolveResultItem::Source(source) = primary {
             let path = source.ident().path().resolve().await?;
-            if let Some(new_result) =
-                apply_plugins_to_path(path, lookup_path, reference_type.clone(), request, options)
-                    .await?
+            if let Some(new_result) = apply_plugins_to_path(
+                path,
+                lookup_path,
+                reference_type.clone(),
+                request,
+                options,
+                trace,
+            )
+            .await?
             {
                 let new_result = new_result.await?;
                 changed = true;
@@ -1511,21 +1600,26 @@ async fn resolve_internal(
     request: Vc<Request>,
     options: Vc<ResolveOptions>,
 ) -> Result<Vc<ResolveResult>> {
-    resolve_internal_inline(lookup_path, request, options).await
+    let trace = ResolveTracer::new(&*options.await?);
+    let result = resolve_internal_inline(lookup_path, request, options, &trace).await?;
+    trace.emit().await?;
+    Ok(result)
 }
 
 fn resolve_internal_boxed(
     lookup_path: Vc<FileSystemPath>,
     request: Vc<Request>,
     options: Vc<ResolveOptions>,
+    trace: ResolveTracer,
 ) -> Pin<Box<dyn Future<Output = Result<Vc<ResolveResult>>> + Send>> {
-    Box::pin(resolve_internal_inline(lookup_path, request, options))
+    Box::pin(async move { resolve_internal_inline(lookup_path, request, options, &trace).await })
 }
 
 async fn resolve_internal_inline(
     lookup_path: Vc<FileSystemPath>,
     request: Vc<Request>,
     options: Vc<ResolveOptions>,
+    trace: &ResolveTracer,
 ) -> Result<Vc<ResolveResult>> {
     let span = {
         let lookup_path = lookup_path.to_string().await?.to_string();
@@ -1546,6 +1640,17 @@ async fn resolve_internal_inline(
         // Apply import mappings if provided
         if let Some(import_map) = &options_value.import_map {
             let result = import_map.await?.lookup(lookup_path, request).await?;
+            if options_value.trace {
+                trace.step(
+                    ResolveTraceStepKind::ImportMap,
+                    format!(
+                        "{} from {}: {}",
+                        request.to_string().await?,
+                        lookup_path.to_string().await?,
+                        result.clone().cell().to_string().await?
+                    ),
+                );
+            }
             if !matches!(result, ImportMapResult::NoEntry) {
                 has_alias = true;
                 let resolved_result = resolve_import_map_result(
@@ -1555,6 +1660,7 @@ async fn resolve_internal_inline(
                     request,
                     options,
                     request.query(),
+                    trace,
                 )
                 .await?;
                 // We might have matched an alias in the import map, but there is no guarantee
@@ -1577,7 +1683,7 @@ async fn resolve_internal_inline(
             Request::Alternatives { requests } => {
                 let results = requests
                     .iter()
-                    .map(|req| resolve_internal_boxed(lookup_path, *req, options))
+                    .map(|req| resolve_internal_boxed(lookup_path, *req, options, trace.branch()))
                     .try_join()
                     .await?;
 
@@ -1611,13 +1717,15 @@ async fn resolve_internal_inline(
                                     options,
                                     *query,
                                     *fragment,
+                                    trace,
                                 )
                                 .await?,
                             );
                         }
                         PatternMatch::Directory(matched_pattern, path) => {
                             results.push(
-                                resolve_into_folder(*path, options)
+                                trace
+                                    .task(resolve_into_folder(*path, options))
                                     .with_request(matched_pattern.clone()),
                             );
                         }
@@ -1642,6 +1750,7 @@ async fn resolve_internal_inline(
                         *query,
                         *force_in_lookup_dir,
                         *fragment,
+                        trace,
                     )
                     .await
                     {
@@ -1658,6 +1767,7 @@ async fn resolve_internal_inline(
                     *query,
                     *force_in_lookup_dir,
                     Vc::cell(RcStr::default()),
+                    trace,
                 )
                 .await?
             }
@@ -1676,6 +1786,7 @@ async fn resolve_internal_inline(
                     path,
                     *query,
                     *fragment,
+                    trace,
                 )
                 .await?
             }
@@ -1701,6 +1812,12 @@ async fn resolve_internal_inline(
                                 .to_string(),
                         ),
                         source: None,
+                        resolve_trace: trace_for_issue(
+                            lookup_path,
+                            request,
+                            options_value,
+                            options,
+                        ),
                     }
                     .cell()
                     .emit();
@@ -1710,6 +1827,7 @@ async fn resolve_internal_inline(
                     lookup_path.root().resolve().await?,
                     relative.resolve().await?,
                     options,
+                    trace.clone(),
                 )
                 .await?
             }
@@ -1727,6 +1845,12 @@ async fn resolve_internal_inline(
                         resolve_options: options,
                         error_message: Some("windows imports are not implemented yet".to_string()),
                         source: None,
+                        resolve_trace: trace_for_issue(
+                            lookup_path,
+                            request,
+                            options_value,
+                            options,
+                        ),
                     }
                     .cell()
                     .emit();
@@ -1755,6 +1879,7 @@ async fn resolve_internal_inline(
                     path,
                     &conditions,
                     &unspecified_conditions,
+                    trace,
                 )
                 .await?
             }
@@ -1781,6 +1906,12 @@ async fn resolve_internal_inline(
                         resolve_options: options,
                         error_message: None,
                         source: None,
+                        resolve_trace: trace_for_issue(
+                            lookup_path,
+                            request,
+                            options_value,
+                            options,
+                        ),
                     }
                     .cell()
                     .emit();
@@ -1793,6 +1924,17 @@ async fn resolve_internal_inline(
         if let Some(import_map) = &options_value.fallback_import_map {
             if *result.is_unresolveable().await? {
                 let result = import_map.await?.lookup(lookup_path, request).await?;
+                if options_value.trace {
+                    trace.step(
+                        ResolveTraceStepKind::FallbackImportMap,
+                        format!(
+                            "{} from {}: {}",
+                            request.to_string().await?,
+                            lookup_path.to_string().await?,
+                            result.clone().cell().to_string().await?
+                        ),
+                    );
+                }
                 let resolved_result = resolve_import_map_result(
                     &result,
                     lookup_path,
@@ -1800,6 +1942,7 @@ async fn resolve_internal_inline(
                     request,
                     options,
                     request.query(),
+                    trace,
                 )
                 .await?;
                 if let Some(result) = resolved_result {
@@ -1816,10 +1959,40 @@ async fn resolve_internal_inline(
     .await
 }
 
+/// The trace to attach to issues reported while resolving. There is none when
+/// the issue is reported while tracing, since the trace is still recorded.
+fn trace_for_issue(
+    lookup_path: Vc<FileSystemPath>,
+    request: Vc<Request>,
+    options_value: &ResolveOptions,
+    options: Vc<ResolveOptions>,
+) -> Option<Vc<LazyResolveTrace>> {
+    (!options_value.trace).then(|| {
+        LazyResolveTrace {
+            lookup_path,
+            reference_type: None,
+            request,
+            options,
+        }
+        .cell()
+    })
+}
+
 #[turbo_tasks::function]
 async fn resolve_into_folder(
     package_path: Vc<FileSystemPath>,
     options: Vc<ResolveOptions>,
+) -> Result<Vc<ResolveResult>> {
+    let trace = ResolveTracer::new(&*options.await?);
+    let result = resolve_into_folder_inline(package_path, options, &trace).await?;
+    trace.emit().await?;
+    Ok(result)
+}
+
+async fn resolve_into_folder_inline(
+    package_path: Vc<FileSystemPath>,
+    options: Vc<ResolveOptions>,
+    trace: &ResolveTracer,
 ) -> Result<Vc<ResolveResult>> {
     let package_json_path = package_path.join("package.json".into());
     let options_value = options.await?;
@@ -1828,6 +2001,21 @@ async fn resolve_into_folder(
         match resolve_into_package {
             ResolveIntoPackage::MainField { field: name } => {
                 if let Some(package_json) = &*read_package_json(package_json_path).await? {
+                    if options_value.trace {
+                        trace.step(
+                            ResolveTraceStepKind::PackageJson,
+                            match package_json.get(name.as_str()) {
+                                Some(value) => format!(
+                                    "{}: \"{name}\" field: {value}",
+                                    package_json_path.to_string().await?
+                                ),
+                                None => format!(
+                                    "{}: \"{name}\" field not found",
+                                    package_json_path.to_string().await?
+                                ),
+                            },
+                        );
+                    }
                     if let Some(field_value) = package_json[name.as_str()].as_str() {
                         let normalized_request: RcStr = normalize_request(field_value).into();
                         if normalized_request.is_empty()
@@ -1844,9 +2032,10 @@ async fn resolve_into_folder(
                         } else {
                             options
                         };
-                        let result = &*resolve_internal_inline(package_path, request, options)
-                            .await?
-                            .await?;
+                        let result =
+                            &*resolve_internal_inline(package_path, request, options, trace)
+                                .await?
+                                .await?;
                         // we are not that strict when a main field fails to resolve
                         // we continue to try other alternatives
                         if !result.is_unresolveable_ref() {
@@ -1867,6 +2056,17 @@ async fn resolve_into_folder(
         return Ok(ResolveResult::unresolveable().into());
     }
 
+    if options_value.trace {
+        trace.step(
+            ResolveTraceStepKind::Extensions,
+            format!(
+                "default files in {}: [{}]",
+                package_path.to_string().await?,
+                options_value.default_files.join(", ")
+            ),
+        );
+    }
+
     // fall back to dir/index.[js,ts,...]
     let pattern = match &options_value.default_files[..] {
         [] => return Ok(ResolveResult::unresolveable().into()),
@@ -1882,7 +2082,7 @@ async fn resolve_into_folder(
     let request = Request::parse(Value::new(pattern));
 
     Ok(
-        resolve_internal_inline(package_path, request.resolve().await?, options)
+        resolve_internal_inline(package_path, request.resolve().await?, options, trace)
             .await?
             .with_request(".".into()),
     )
@@ -1898,6 +2098,7 @@ async fn resolve_relative_request(
     query: Vc<RcStr>,
     force_in_lookup_dir: bool,
     fragment: Vc<RcStr>,
+    trace: &ResolveTracer,
 ) -> Result<Vc<ResolveResult>> {
     // Check alias field for aliases first
     let lookup_path_ref = &*lookup_path.await?;
@@ -1913,6 +2114,7 @@ async fn resolve_relative_request(
         },
         query,
         fragment,
+        trace,
     )
     .await?
     {
@@ -1930,6 +2132,26 @@ async fn resolve_relative_request(
         ));
     }
 
+    if options_value.trace {
+        trace.step(
+            ResolveTraceStepKind::Extensions,
+            if options_value.fully_specified {
+                format!(
+                    "{} in {}: fully specified, no extensions are added",
+                    new_path,
+                    lookup_path.to_string().await?
+                )
+            } else {
+                format!(
+                    "{} in {}: extensions [{}]",
+                    new_path,
+                    lookup_path.to_string().await?,
+                    options_value.extensions.join(", ")
+                )
+            },
+        );
+    }
+
     if !options_value.fully_specified {
         // Add the extensions as alternatives to the path
         // read_matches keeps the order of alternatives intact
@@ -2022,6 +2244,7 @@ async fn resolve_relative_request(
                                     options,
                                     query,
                                     Vc::cell(RcStr::default()),
+                                    trace,
                                 )
                                 .await?,
                             );
@@ -2039,6 +2262,7 @@ async fn resolve_relative_request(
                                 options,
                                 query,
                                 fragment,
+                                trace,
                             )
                             .await?,
                         );
@@ -2062,6 +2286,7 @@ async fn resolve_relative_request(
                             options,
                             query,
                             Vc::cell(RcStr::default()),
+                            trace,
                         )
                         .await?,
                     );
@@ -2080,6 +2305,7 @@ async fn resolve_relative_request(
                         options,
                         query,
                         fragment,
+                        trace,
                     )
                     .await?,
                 );
@@ -2089,7 +2315,11 @@ async fn resolve_relative_request(
     // Directory matches must be resolved AFTER file matches
     for m in matches.iter() {
         if let PatternMatch::Directory(matched_pattern, path) = m {
-            results.push(resolve_into_folder(*path, options).with_request(matched_pattern.clone()));
+            results.push(
+                trace
+                    .task(resolve_into_folder(*path, options))
+                    .with_request(matched_pattern.clone()),
+            );
         }
     }
 
@@ -2104,6 +2334,7 @@ async fn apply_in_package(
     get_request: impl Fn(&FileSystemPath) -> Option<RcStr>,
     query: Vc<RcStr>,
     fragment: Vc<RcStr>,
+    trace: &ResolveTracer,
 ) -> Result<Option<Vc<ResolveResult>>> {
     // Check alias field for module aliases first
     for in_package in options_value.in_package.iter() {
@@ -2131,6 +2362,16 @@ async fn apply_in_package(
 
         let package_path = package_json_path.parent().resolve().await?;
 
+        if options_value.trace {
+            trace.step(
+                ResolveTraceStepKind::PackageJson,
+                format!(
+                    "{}: \"{field}\" alias field found",
+                    package_json_path.to_string().await?
+                ),
+            );
+        }
+
         let Some(request) = get_request(&*package_path.await?) else {
             continue;
         };
@@ -2166,26 +2407,29 @@ async fn apply_in_package(
                 return Ok(None);
             }
             return Ok(Some(
-                resolve_internal(
-                    package_path,
-                    Request::parse(Value::new(Pattern::Constant(value.into())))
-                        .with_query(query)
-                        .with_fragment(fragment),
-                    options,
-                )
-                .with_replaced_request_key(value.into(), Value::new(request_key))
-                .with_affecting_sources(refs),
+                trace
+                    .task(resolve_internal(
+                        package_path,
+                        Request::parse(Value::new(Pattern::Constant(value.into())))
+                            .with_query(query)
+                            .with_fragment(fragment),
+                        options,
+                    ))
+                    .with_replaced_request_key(value.into(), Value::new(request_key))
+                    .with_affecting_sources(refs),
             ));
         }
 
+        let request = Request::parse(Value::new(Pattern::Constant(request)));
         ResolvingIssue {
             severity: IssueSeverity::Error.cell(),
             file_path: *package_json_path,
             request_type: format!("alias field ({field})"),
-            request: Request::parse(Value::new(Pattern::Constant(request))),
+            request,
             resolve_options: options,
             error_message: Some(format!("invalid alias field value: {}", value)),
             source: None,
+            resolve_trace: trace_for_issue(package_path, request, options_value, options),
         }
         .cell()
         .emit();
@@ -2240,6 +2484,7 @@ async fn resolve_module_request(
     path: &Pattern,
     query: Vc<RcStr>,
     fragment: Vc<RcStr>,
+    trace: &ResolveTracer,
 ) -> Result<Vc<ResolveResult>> {
     // Check alias field for module aliases first
     if let Some(result) = apply_in_package(
@@ -2252,6 +2497,7 @@ async fn resolve_module_request(
         },
         query,
         fragment,
+        trace,
     )
     .await?
     {
@@ -2265,25 +2511,72 @@ async fn resolve_module_request(
         &*find_self_reference(lookup_path).await?
     {
         if name == module {
-            let result = resolve_into_package(
+            let result = trace.task(resolve_into_package(
                 Value::new(path.clone()),
                 *package_path,
                 query,
                 fragment,
                 options,
-            );
+            ));
             if !(*result.is_unresolveable().await?) {
                 return Ok(result);
             }
         }
     }
 
-    let result = find_package(
-        lookup_path,
-        module.into(),
-        resolve_modules_options(options).resolve().await?,
-    )
-    .await?;
+    let modules_options = resolve_modules_options(options).resolve().await?;
+    let result = find_package(lookup_path, module.into(), modules_options).await?;
+
+    if options_value.trace {
+        for (modules, probed_directories) in modules_options
+            .await?
+            .modules
+            .iter()
+            .zip(&result.probed_directories)
+        {
+            match modules {
+                ResolveModules::Nested(..) => {
+                    for probed in probed_directories {
+                        trace.step(
+                            ResolveTraceStepKind::Modules,
+                            format!(
+                                "looking into {}{}",
+                                probed.path.to_string().await?,
+                                if probed.exists { "" } else { ": not found" }
+                            ),
+                        );
+                    }
+                }
+                ResolveModules::Path(path) => trace.step(
+                    ResolveTraceStepKind::Modules,
+                    format!("looking into {}", path.to_string().await?),
+                ),
+                ResolveModules::Registry(path, _) => trace.step(
+                    ResolveTraceStepKind::Modules,
+                    format!("looking into registry {}", path.to_string().await?),
+                ),
+            }
+        }
+        if result.packages.is_empty() {
+            trace.step(
+                ResolveTraceStepKind::Modules,
+                format!("no package named {module} found"),
+            );
+        }
+        for item in &result.packages {
+            trace.step(
+                ResolveTraceStepKind::Modules,
+                match *item {
+                    FindPackageItem::PackageDirectory(path) => {
+                        format!("found package directory {}", path.to_string().await?)
+                    }
+                    FindPackageItem::PackageFile(path) => {
+                        format!("found file {}", path.to_string().await?)
+                    }
+                },
+            );
+        }
+    }
 
     if result.packages.is_empty() {
         return Ok(ResolveResult::unresolveable_with_affecting_sources(
@@ -2302,13 +2595,13 @@ async fn resolve_module_request(
     for item in &result.packages {
         match *item {
             FindPackageItem::PackageDirectory(package_path) => {
-                results.push(resolve_into_package(
+                results.push(trace.task(resolve_into_package(
                     Value::new(path.clone()),
                     package_path,
                     query,
                     fragment,
                     options,
-                ));
+                )));
             }
             FindPackageItem::PackageFile(package_path) => {
                 if path.is_match("") {
@@ -2321,6 +2614,7 @@ async fn resolve_module_request(
                         options,
                         query,
                         fragment,
+                        trace,
                     )
                     .await?;
                     results.push(resolved)
@@ -2341,8 +2635,13 @@ async fn resolve_module_request(
             path.clone(),
         ]);
         let relative = Request::relative(Value::new(pattern), query, fragment, true);
-        let relative_result =
-            resolve_internal_boxed(lookup_path, relative.resolve().await?, options).await?;
+        let relative_result = resolve_internal_boxed(
+            lookup_path,
+            relative.resolve().await?,
+            options,
+            trace.clone(),
+        )
+        .await?;
         let relative_result = relative_result
             .with_replaced_request_key(module_prefix, Value::new(RequestKey::new(module.into())));
 
@@ -2360,7 +2659,28 @@ async fn resolve_into_package(
     fragment: Vc<RcStr>,
     options: Vc<ResolveOptions>,
 ) -> Result<Vc<ResolveResult>> {
-    let path = path.into_value();
+    let trace = ResolveTracer::new(&*options.await?);
+    let result = resolve_into_package_inline(
+        path.into_value(),
+        package_path,
+        query,
+        fragment,
+        options,
+        &trace,
+    )
+    .await?;
+    trace.emit().await?;
+    Ok(result)
+}
+
+async fn resolve_into_package_inline(
+    path: Pattern,
+    package_path: Vc<FileSystemPath>,
+    query: Vc<RcStr>,
+    fragment: Vc<RcStr>,
+    options: Vc<ResolveOptions>,
+    trace: &ResolveTracer,
+) -> Result<Vc<ResolveResult>> {
     let options_value = options.await?;
     let mut results = Vec::new();
 
@@ -2376,14 +2696,46 @@ async fn resolve_into_package(
                 unspecified_conditions,
             } => {
                 let package_json_path = package_path.join("package.json".into());
-                let ExportsFieldResult::Some(exports_field) =
-                    &*exports_field(package_json_path).await?
-                else {
+                let exports_field_result = exports_field(package_json_path).await?;
+                if options_value.trace {
+                    trace.step(
+                        ResolveTraceStepKind::PackageJson,
+                        format!(
+                            "{}: \"exports\" field {}, conditions: {}",
+                            package_json_path.to_string().await?,
+                            if matches!(&*exports_field_result, ExportsFieldResult::Some(_)) {
+                                "found, other fields are ignored"
+                            } else {
+                                "not found"
+                            },
+                            describe_conditions(conditions, unspecified_conditions)
+                        ),
+                    );
+                }
+                let ExportsFieldResult::Some(exports_field) = &*exports_field_result else {
                     continue;
                 };
 
                 let Some(path) = path.clone().into_string() else {
-                    todo!("pattern into an exports field is not implemented yet");
+                    results.push(
+                        handle_exports_field_pattern(
+                            package_path,
+                            package_json_path,
+                            options,
+                            exports_field,
+                            &path,
+                            conditions,
+                            unspecified_conditions,
+                            query,
+                            fragment,
+                            trace,
+                        )
+                        .await?,
+                    );
+
+                    // other options do not apply anymore when an exports
+                    // field exist
+                    return Ok(merge_results(results));
                 };
 
                 let path = if &*path == "/" {
@@ -2402,6 +2754,7 @@ async fn resolve_into_package(
                         conditions,
                         unspecified_conditions,
                         query,
+                        trace,
                     )
                     .await?,
                 );
@@ -2415,7 +2768,7 @@ async fn resolve_into_package(
 
     // apply main field(s) or fallback to index.js if there's no subpath
     if is_root_match {
-        results.push(resolve_into_folder(package_path, options));
+        results.push(trace.task(resolve_into_folder(package_path, options)));
     }
 
     if could_match_others {
@@ -2423,8 +2776,10 @@ async fn resolve_into_package(
         new_pat.push_front(RcStr::from(".").into());
 
         let relative = Request::relative(Value::new(new_pat), query, fragment, true);
-        results
-            .push(resolve_internal_inline(package_path, relative.resolve().await?, options).await?);
+        results.push(
+            resolve_internal_inline(package_path, relative.resolve().await?, options, trace)
+                .await?,
+        );
     }
 
     Ok(merge_results(results))
@@ -2438,6 +2793,7 @@ async fn resolve_import_map_result(
     original_request: Vc<Request>,
     options: Vc<ResolveOptions>,
     query: Vc<RcStr>,
+    trace: &ResolveTracer,
 ) -> Result<Option<Vc<ResolveResult>>> {
     Ok(match result {
         ImportMapResult::Result(result) => Some(*result),
@@ -2450,7 +2806,7 @@ async fn resolve_import_map_result(
             {
                 None
             } else {
-                Some(resolve_internal(lookup_path, request, options))
+                Some(trace.task(resolve_internal(lookup_path, request, options)))
             }
         }
         ImportMapResult::Alternatives(list) => {
@@ -2464,6 +2820,7 @@ async fn resolve_import_map_result(
                         original_request,
                         options,
                         query,
+                        trace.branch(),
                     )
                 })
                 .try_join()
@@ -2486,15 +2843,20 @@ fn resolve_import_map_result_boxed<'a>(
     original_request: Vc<Request>,
     options: Vc<ResolveOptions>,
     query: Vc<RcStr>,
+    trace: ResolveTracer,
 ) -> Pin<Box<dyn Future<Output = ResolveImportMapResult> + Send + 'a>> {
-    Box::pin(resolve_import_map_result(
-        result,
-        lookup_path,
-        original_lookup_path,
-        original_request,
-        options,
-        query,
-    ))
+    Box::pin(async move {
+        resolve_import_map_result(
+            result,
+            lookup_path,
+            original_lookup_path,
+            original_request,
+            options,
+            query,
+            &trace,
+        )
+        .await
+    })
 }
 
 #[tracing::instrument(level = Level::TRACE, skip_all)]
@@ -2507,6 +2869,7 @@ async fn resolved(
     options: Vc<ResolveOptions>,
     query: Vc<RcStr>,
     fragment: Vc<RcStr>,
+    trace: &ResolveTracer,
 ) -> Result<Vc<ResolveResult>> {
     let RealPathResult { path, symlinks } = &*fs_path.realpath_with_links().await?;
 
@@ -2519,6 +2882,7 @@ async fn resolved(
         |package_path| package_path.get_relative_path_to(path_ref),
         query,
         fragment,
+        trace,
     )
     .await?
     {
@@ -2537,6 +2901,7 @@ async fn resolved(
             original_request,
             options,
             query,
+            trace,
         )
         .await?;
 
@@ -2567,6 +2932,7 @@ async fn handle_exports_imports_field(
     conditions: &BTreeMap<RcStr, ConditionValue>,
     unspecified_conditions: &ConditionValue,
     query: Vc<RcStr>,
+    trace: &ResolveTracer,
 ) -> Result<Vc<ResolveResult>> {
     let mut results = Vec::new();
     let mut conditions_state = HashMap::new();
@@ -2595,7 +2961,8 @@ async fn handle_exports_imports_field(
         if let Some(result_path) = normalize_path(result_path) {
             let request =
                 Request::parse(Value::new(RcStr::from(format!("./{}", result_path)).into()));
-            let resolve_result = resolve_internal_boxed(package_path, request, options).await?;
+            let resolve_result =
+                resolve_internal_boxed(package_path, request, options, trace.clone()).await?;
             if conditions.is_empty() {
                 resolved_results.push(resolve_result.with_request(path.into()));
             } else {
@@ -2613,6 +2980,134 @@ async fn handle_exports_imports_field(
     ))
 }
 
+/// Resolves a dynamic subpath [Pattern], e.g. from
+/// ``import(`pkg/locales/${lang}`)``, using the "exports" field. Every entry
+/// that could match the pattern contributes results. For wildcard entries the
+/// part of the pattern matched by `*` is substituted into the target, so that
+/// all files the target could refer to are resolved. The query and fragment of
+/// the request are kept on the substituted targets.
+async fn handle_exports_field_pattern(
+    package_path: Vc<FileSystemPath>,
+    package_json_path: Vc<FileSystemPath>,
+    options: Vc<ResolveOptions>,
+    exports_field: &AliasMap<SubpathValue>,
+    path: &Pattern,
+    conditions: &BTreeMap<RcStr, ConditionValue>,
+    unspecified_conditions: &ConditionValue,
+    query: Vc<RcStr>,
+    fragment: Vc<RcStr>,
+    trace: &ResolveTracer,
+) -> Result<Vc<ResolveResult>> {
+    let is_root_match = path.is_match("") || path.is_match("/");
+    let mut subpath = path.clone();
+    subpath.push_front(RcStr::from(".").into());
+    subpath.normalize();
+
+    let mut resolved_results = Vec::new();
+    for (alias, value) in exports_field {
+        let (capture, prefix, suffix) = match alias {
+            AliasPattern::Exact(key) => {
+                if subpath.is_match(&key) || (is_root_match && key == ".") {
+                    resolved_results.push(
+                        handle_exports_imports_field(
+                            package_path,
+                            package_json_path,
+                            options,
+                            exports_field,
+                            &key,
+                            conditions,
+                            unspecified_conditions,
+                            query,
+                            trace,
+                        )
+                        .await?,
+                    );
+                }
+                continue;
+            }
+            AliasPattern::Wildcard { prefix, suffix } => {
+                let Some(capture) = subpath
+                    .strip_prefix(&prefix)
+                    .and_then(|rest| rest.strip_suffix(&suffix))
+                else {
+                    continue;
+                };
+                (capture, prefix, suffix)
+            }
+        };
+
+        let mut conditions_state = HashMap::new();
+        let mut results = Vec::new();
+        value.add_results(
+            conditions,
+            unspecified_conditions,
+            &mut conditions_state,
+            &mut results,
+        );
+
+        for (result_path, result_conditions) in results {
+            let Some(result_path) = normalize_path(result_path) else {
+                continue;
+            };
+            let target_parts = format!("./{result_path}");
+            let target_parts = target_parts.split('*').collect::<Vec<_>>();
+            let mut target = Pattern::Constant(target_parts[0].into());
+            for part in &target_parts[1..] {
+                target.push(capture.clone());
+                target.push(Pattern::Constant((*part).into()));
+            }
+            target.normalize();
+            let request = Request::relative(Value::new(target), query, fragment, false);
+            let resolve_result =
+                resolve_internal_boxed(package_path, request, options, trace.clone()).await?;
+            // The results are keyed by the target paths, but the request refers to the
+            // exports subpath, so the part matched by `*` is substituted back into it.
+            let resolve_result = resolve_result.await?;
+            let mut resolve_result = ResolveResult {
+                primary: resolve_result
+                    .primary
+                    .iter()
+                    .filter_map(|(key, item)| {
+                        if target_parts.len() == 1 {
+                            return Some((key.clone(), item.clone()));
+                        }
+                        let request = key.request.as_deref()?;
+                        let capture = wildcard_capture(request, &target_parts)?;
+                        Some((
+                            RequestKey {
+                                request: Some(format!("{prefix}{capture}{suffix}").into()),
+                                conditions: key.conditions.clone(),
+                            },
+                            item.clone(),
+                        ))
+                    })
+                    .collect(),
+                affecting_sources: resolve_result.affecting_sources.clone(),
+            };
+            resolve_result.add_conditions(result_conditions);
+            resolved_results.push(resolve_result.cell());
+        }
+    }
+
+    Ok(merge_results_with_affecting_sources(
+        resolved_results,
+        vec![Vc::upcast(FileSource::new(package_json_path))],
+    ))
+}
+
+/// Returns the part of `path` which was matched by the `*`s of a target split
+/// into `target_parts`, e.g. `en` for `./dist/en.js` and `["./dist/", ".js"]`.
+fn wildcard_capture<'a>(path: &'a str, target_parts: &[&str]) -> Option<&'a str> {
+    let (first, rest) = target_parts.split_first()?;
+    let (last, middle) = rest.split_last()?;
+    let path = path.strip_prefix(first)?.strip_suffix(last)?;
+    // All `*`s are substituted with the same value, so the first one is enough.
+    match middle.first() {
+        Some(next) => path.split_once(next).map(|(capture, _)| capture),
+        None => Some(path),
+    }
+}
+
 /// Resolves a `#dep` import using the containing package.json's `imports`
 /// field. The dep may be a constant string or a pattern, and the values can be
 /// static strings or conditions like `import` or `require` to handle ESM/CJS
@@ -2624,6 +3119,7 @@ async fn resolve_package_internal_with_imports_field(
     pattern: &Pattern,
     conditions: &BTreeMap<RcStr, ConditionValue>,
     unspecified_conditions: &ConditionValue,
+    trace: &ResolveTracer,
 ) -> Result<Vc<ResolveResult>> {
     let Pattern::Constant(specifier) = pattern else {
         bail!("PackageInternal requests can only be Constant strings");
@@ -2638,6 +3134,12 @@ async fn resolve_package_internal_with_imports_field(
             resolve_options,
             error_message: None,
             source: None,
+            resolve_trace: trace_for_issue(
+                file_path,
+                request,
+                &*resolve_options.await?,
+                resolve_options,
+            ),
         }
         .cell()
         .emit();
@@ -2659,6 +3161,7 @@ async fn resolve_package_internal_with_imports_field(
         conditions,
         unspecified_conditions,
         Vc::<RcStr>::default(),
+        trace,
     )
     .await
 }
@@ -2676,6 +3179,18 @@ pub async fn handle_resolve_error(
     severity: Vc<IssueSeverity>,
     source: Option<Vc<IssueSource>>,
 ) -> Result<Vc<ModuleResolveResult>> {
+    let trace_reference_type = reference_type.clone().into_value();
+    let resolve_trace = move || {
+        Some(
+            LazyResolveTrace {
+                lookup_path: origin_path.parent(),
+                reference_type: Some(trace_reference_type.clone()),
+                request,
+                options: resolve_options,
+            }
+            .cell(),
+        )
+    };
     Ok(match is_unresolveable(result).await {
         Ok(unresolveable) => {
             if unresolveable {
@@ -2687,6 +3202,7 @@ pub async fn handle_resolve_error(
                     resolve_options,
                     error_message: None,
                     source,
+                    resolve_trace: resolve_trace(),
                 }
                 .cell()
                 .emit();
@@ -2703,6 +3219,7 @@ pub async fn handle_resolve_error(
                 resolve_options,
                 error_message: Some(format!("{}", PrettyPrintError(&err))),
                 source,
+                resolve_trace: resolve_trace(),
             }
             .cell()
             .emit();
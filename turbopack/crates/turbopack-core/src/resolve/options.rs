@@ -444,6 +444,9 @@ pub struct ResolveOptions {
     pub plugins: Vec<Vc<Box<dyn AfterResolvePlugin>>>,
     /// Support resolving *.js requests to *.ts files
     pub enable_typescript_with_output_extension: bool,
+    /// Record the steps taken while resolving, see
+    /// [super::explain::explain_resolve].
+    pub trace: bool,
 
     pub placeholder_for_future_extensions: (),
 }
@@ -492,6 +495,19 @@ impl ResolveOptions {
         Ok(resolve_options.into())
     }
 
+    /// Returns a new [Vc<ResolveOptions>] which records the steps taken while
+    /// resolving.
+    #[turbo_tasks::function]
+    pub async fn with_trace(self: Vc<Self>) -> Result<Vc<Self>> {
+        let resolve_options = self.await?;
+        if resolve_options.trace {
+            return Ok(self);
+        }
+        let mut resolve_options = resolve_options.clone_value();
+        resolve_options.trace = true;
+        Ok(resolve_options.cell())
+    }
+
     /// Overrides the fully_specified flag for resolving
     #[turbo_tasks::function]
     pub async fn with_fully_specified(self: Vc<Self>, fully_specified: bool) -> Result<Vc<Self>> {
@@ -620,6 +620,107 @@ impl Pattern {
         }
     }
 
+    /// Returns a pattern matching the remainder of all values of this pattern
+    /// that start with `prefix`, or `None` if no value can start with it.
+    ///
+    /// A leading dynamic part is assumed to absorb the prefix, so the result
+    /// might match more than the original pattern did.
+    pub fn strip_prefix(&self, prefix: &str) -> Option<Pattern> {
+        if prefix.is_empty() {
+            return Some(self.clone());
+        }
+        match self {
+            Pattern::Constant(c) => c
+                .strip_prefix(prefix)
+                .map(|rest| Pattern::Constant(rest.into())),
+            Pattern::Dynamic => Some(Pattern::Dynamic),
+            Pattern::Alternatives(list) => {
+                Self::alternatives_or_single(list.iter().filter_map(|alt| alt.strip_prefix(prefix)))
+            }
+            Pattern::Concatenation(list) => match list.split_first() {
+                None => None,
+                Some((Pattern::Constant(c), rest)) => {
+                    if let Some(remaining) = c.strip_prefix(prefix) {
+                        let mut parts = vec![Pattern::Constant(remaining.into())];
+                        parts.extend(rest.iter().cloned());
+                        let mut new = Pattern::Concatenation(parts);
+                        new.normalize();
+                        Some(new)
+                    } else if let Some(remaining_prefix) = prefix.strip_prefix(&**c) {
+                        Self::concatenation_or_empty(rest).strip_prefix(remaining_prefix)
+                    } else {
+                        None
+                    }
+                }
+                Some((Pattern::Dynamic, _)) => Some(self.clone()),
+                Some(_) => {
+                    let mut normalized = self.clone();
+                    normalized.normalize();
+                    normalized.strip_prefix(prefix)
+                }
+            },
+        }
+    }
+
+    /// Returns a pattern matching the beginning of all values of this pattern
+    /// that end with `suffix`, or `None` if no value can end with it.
+    ///
+    /// A trailing dynamic part is assumed to absorb the suffix, so the result
+    /// might match more than the original pattern did.
+    pub fn strip_suffix(&self, suffix: &str) -> Option<Pattern> {
+        if suffix.is_empty() {
+            return Some(self.clone());
+        }
+        match self {
+            Pattern::Constant(c) => c
+                .strip_suffix(suffix)
+                .map(|rest| Pattern::Constant(rest.into())),
+            Pattern::Dynamic => Some(Pattern::Dynamic),
+            Pattern::Alternatives(list) => {
+                Self::alternatives_or_single(list.iter().filter_map(|alt| alt.strip_suffix(suffix)))
+            }
+            Pattern::Concatenation(list) => match list.split_last() {
+                None => None,
+                Some((Pattern::Constant(c), rest)) => {
+                    if let Some(remaining) = c.strip_suffix(suffix) {
+                        let mut parts = rest.to_vec();
+                        parts.push(Pattern::Constant(remaining.into()));
+                        let mut new = Pattern::Concatenation(parts);
+                        new.normalize();
+                        Some(new)
+                    } else if let Some(remaining_suffix) = suffix.strip_suffix(&**c) {
+                        Self::concatenation_or_empty(rest).strip_suffix(remaining_suffix)
+                    } else {
+                        None
+                    }
+                }
+                Some((Pattern::Dynamic, _)) => Some(self.clone()),
+                Some(_) => {
+                    let mut normalized = self.clone();
+                    normalized.normalize();
+                    normalized.strip_suffix(suffix)
+                }
+            },
+        }
+    }
+
+    fn alternatives_or_single(alts: impl Iterator<Item = Pattern>) -> Option<Pattern> {
+        let mut list = alts.collect::<Vec<_>>();
+        match list.len() {
+            0 => None,
+            1 => list.pop(),
+            _ => Some(Pattern::Alternatives(list)),
+        }
+    }
+
+    fn concatenation_or_empty(parts: &[Pattern]) -> Pattern {
+        match parts {
+            [] => Pattern::Constant(RcStr::default()),
+            [single] => single.clone(),
+            parts => Pattern::Concatenation(parts.to_vec()),
+        }
+    }
+
     pub fn or_any_nested_file(&self) -> Self {
         let mut new = self.clone();
         new.push(Pattern::Constant("/".into()));
@@ -1387,6 +1488,55 @@ mod tests {
         assert_eq!(pat.next_constants(value), expected);
     }
 
+    #[rstest]
+    #[case::constant(Pattern::Constant("./a/b".into()), "./a/", Some(Pattern::Constant("b".into())))]
+    #[case::constant_mismatch(Pattern::Constant("./icons/x".into()), "./locales/", None)]
+    #[case::dynamic(Pattern::Dynamic, "./locales/", Some(Pattern::Dynamic))]
+    #[case::concat(
+        Pattern::Concatenation(vec![Pattern::Constant("./locales/".into()), Pattern::Dynamic]),
+        "./locales/", Some(Pattern::Dynamic)
+    )]
+    #[case::concat_partial_constant(
+        Pattern::Concatenation(vec![Pattern::Constant("./".into()), Pattern::Dynamic]),
+        "./locales/", Some(Pattern::Dynamic)
+    )]
+    #[case::alternatives(
+        Pattern::Alternatives(vec![
+            Pattern::Constant("./locales/en".into()),
+            Pattern::Constant("./icons/a".into()),
+        ]), "./locales/", Some(Pattern::Constant("en".into()))
+    )]
+    fn strip_prefix(#[case] pat: Pattern, #[case] prefix: &str, #[case] expected: Option<Pattern>) {
+        assert_eq!(pat.strip_prefix(prefix), expected);
+    }
+
+    #[rstest]
+    #[case::constant(Pattern::Constant("a.json".into()), ".json", Some(Pattern::Constant("a".into())))]
+    #[case::constant_mismatch(Pattern::Constant("a.js".into()), ".json", None)]
+    #[case::concat(
+        Pattern::Concatenation(vec![
+            Pattern::Constant("./locales/".into()),
+            Pattern::Dynamic,
+            Pattern::Constant(".json".into()),
+        ]),
+        ".json",
+        Some(Pattern::Concatenation(vec![
+            Pattern::Constant("./locales/".into()),
+            Pattern::Dynamic,
+        ]))
+    )]
+    #[case::concat_dynamic_end(
+        Pattern::Concatenation(vec![Pattern::Constant("./locales/".into()), Pattern::Dynamic]),
+        ".json",
+        Some(Pattern::Concatenation(vec![
+            Pattern::Constant("./locales/".into()),
+            Pattern::Dynamic,
+        ]))
+    )]
+    fn strip_suffix(#[case] pat: Pattern, #[case] suffix: &str, #[case] expected: Option<Pattern>) {
+        assert_eq!(pat.strip_suffix(suffix), expected);
+    }
+
     #[test]
     fn replace_final_constants() {
         fn f(mut p: Pattern, cb: &impl Fn(&RcStr) -> Option<Pattern>) -> Pattern {
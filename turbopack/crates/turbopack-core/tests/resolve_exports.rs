@@ -0,0 +1,114 @@
+#![feature(arbitrary_self_types)]
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use turbo_tasks::{RcStr, Value, Vc};
+use turbo_tasks_fs::{DiskFileSystem, FileSystem, FileSystemPath};
+use turbo_tasks_testing::{register, run, Registration};
+use turbopack_core::{
+    reference_type::{EcmaScriptModulesReferenceSubType, ReferenceType},
+    resolve::{
+        options::{ConditionValue, ResolveIntoPackage, ResolveModules, ResolveOptions},
+        parse::Request,
+        pattern::Pattern,
+        resolve, ResolveResultItem,
+    },
+    source::Source,
+};
+
+static REGISTRATION: Registration = register!(turbopack_core::register);
+
+fn fixture_root() -> Vc<FileSystemPath> {
+    let root: RcStr = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/resolve_exports").into();
+    DiskFileSystem::new("fixture".into(), root, vec![]).root()
+}
+
+fn resolve_options(root: Vc<FileSystemPath>) -> Vc<ResolveOptions> {
+    ResolveOptions {
+        extensions: vec![".js".into()],
+        modules: vec![ResolveModules::Nested(root, vec!["node_modules".into()])],
+        into_package: vec![ResolveIntoPackage::ExportsField {
+            conditions: BTreeMap::from([("import".into(), ConditionValue::Set)]),
+            unspecified_conditions: ConditionValue::Unset,
+        }],
+        default_files: vec!["index".into()],
+        ..Default::default()
+    }
+    .cell()
+}
+
+/// Resolves `pkg` followed by `path` with `query` from the `src` directory
+/// and returns the request keys with the resolved sources and their queries.
+async fn resolve_in_pkg(path: Pattern, query: &str) -> Result<Vec<String>> {
+    let root = fixture_root();
+    let request = Request::module(
+        "pkg".into(),
+        Value::new(path),
+        Vc::cell(query.into()),
+        Vc::<RcStr>::default(),
+    );
+    let result = resolve(
+        root.join("src".into()),
+        Value::new(ReferenceType::EcmaScriptModules(
+            EcmaScriptModulesReferenceSubType::Undefined,
+        )),
+        request,
+        resolve_options(root),
+    );
+    let mut sources = Vec::new();
+    for (key, item) in result.await?.primary.iter() {
+        let ResolveResultItem::Source(source) = item else {
+            continue;
+        };
+        let ident = source.ident().await?;
+        sources.push(format!(
+            "{} => {}{}",
+            key.request.as_deref().unwrap_or("-"),
+            ident.path.await?.path,
+            ident.query.await?
+        ));
+    }
+    sources.sort();
+    Ok(sources)
+}
+
+#[tokio::test]
+async fn substitutes_dynamic_parts_into_wildcard_targets() {
+    run(&REGISTRATION, || async {
+        let path = Pattern::Concatenation(vec![
+            Pattern::Constant("/locales/".into()),
+            Pattern::Dynamic,
+        ]);
+        assert_eq!(
+            resolve_in_pkg(path, "").await?,
+            vec![
+                "pkg/locales/de => node_modules/pkg/dist/locales/de.js",
+                "pkg/locales/en => node_modules/pkg/dist/locales/en.js",
+            ]
+        );
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn keeps_the_query_for_wildcard_targets() {
+    run(&REGISTRATION, || async {
+        let path = Pattern::Concatenation(vec![
+            Pattern::Constant("/locales/".into()),
+            Pattern::Dynamic,
+        ]);
+        assert_eq!(
+            resolve_in_pkg(path, "?raw").await?,
+            vec![
+                "pkg/locales/de => node_modules/pkg/dist/locales/de.js?raw",
+                "pkg/locales/en => node_modules/pkg/dist/locales/en.js?raw",
+            ]
+        );
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
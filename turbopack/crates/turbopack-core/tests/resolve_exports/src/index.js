@@ -0,0 +1 @@
+import(`pkg/locales/${lang}?raw`);
@@ -0,0 +1,202 @@
+#![feature(arbitrary_self_types)]
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use turbo_tasks::{CollectiblesSource, RcStr, Value, ValueToString, Vc};
+use turbo_tasks_fs::{DiskFileSystem, FileSystem, FileSystemPath};
+use turbo_tasks_testing::{register, run, Registration};
+use turbopack_core::{
+    issue::{resolve::ResolvingIssue, Issue, IssueSeverity},
+    reference_type::{CommonJsReferenceSubType, ReferenceType},
+    resolve::{
+        explain::explain_resolve,
+        handle_resolve_error,
+        options::{ConditionValue, ResolveIntoPackage, ResolveModules, ResolveOptions},
+        parse::Request,
+        ModuleResolveResult,
+    },
+};
+
+static REGISTRATION: Registration = register!(turbopack_core::register);
+
+fn fixture_root() -> Vc<FileSystemPath> {
+    let root: RcStr = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/resolve_trace").into();
+    DiskFileSystem::new("fixture".into(), root, vec![]).root()
+}
+
+fn resolve_options(root: Vc<FileSystemPath>) -> Vc<ResolveOptions> {
+    ResolveOptions {
+        extensions: vec![".js".into()],
+        modules: vec![ResolveModules::Nested(root, vec!["node_modules".into()])],
+        into_package: vec![ResolveIntoPackage::ExportsField {
+            conditions: BTreeMap::from([("require".into(), ConditionValue::Set)]),
+            unspecified_conditions: ConditionValue::Unset,
+        }],
+        default_files: vec!["index".into()],
+        ..Default::default()
+    }
+    .cell()
+}
+
+fn reference_type() -> Value<ReferenceType> {
+    Value::new(ReferenceType::CommonJs(CommonJsReferenceSubType::Undefined))
+}
+
+async fn trace(request: &str) -> Result<String> {
+    let root = fixture_root();
+    let lookup_path = root.join("src".into());
+    let trace = explain_resolve(
+        lookup_path,
+        reference_type(),
+        Request::parse_string(request.into()),
+        resolve_options(root),
+    );
+    Ok(trace.to_string().await?.to_string())
+}
+
+#[turbo_tasks::function]
+async fn report_unresolveable(request: RcStr) -> Result<Vc<ModuleResolveResult>> {
+    let root = fixture_root();
+    handle_resolve_error(
+        ModuleResolveResult::unresolveable().cell(),
+        reference_type(),
+        root.join("src/index.js".into()),
+        Request::parse_string(request),
+        resolve_options(root),
+        IssueSeverity::Error.cell(),
+        None,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn traces_a_package_request() {
+    run(&REGISTRATION, || async {
+        let trace = trace("pkg").await?;
+
+        assert!(trace.starts_with("[request] commonjs request"), "{trace}");
+        assert!(
+            trace.contains("[modules] found package directory"),
+            "{trace}"
+        );
+        assert!(
+            trace.contains(
+                "\"exports\" field found, other fields are ignored, conditions: require=set, \
+                 others=unset"
+            ),
+            "{trace}"
+        );
+        assert!(trace.contains("node_modules/pkg/main.js"), "{trace}");
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn traces_an_unresolveable_request() {
+    run(&REGISTRATION, || async {
+        let trace = trace("missing").await?;
+
+        assert!(
+            trace.contains("[modules] no package named missing found"),
+            "{trace}"
+        );
+        assert!(trace.ends_with("[result] unresolveable\n"), "{trace}");
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn traces_each_modules_directory_in_order() {
+    run(&REGISTRATION, || async {
+        let trace = trace("missing").await?;
+
+        let position = |step: &str| {
+            trace
+                .find(step)
+                .unwrap_or_else(|| panic!("{step} not found in {trace}"))
+        };
+        let src = position("[modules] looking into [fixture]/src/node_modules: not found\n");
+        let root = position("[modules] looking into [fixture]/node_modules\n");
+        let not_found = position("[modules] no package named missing found");
+        assert!(position("[request]") < src, "{trace}");
+        assert!(src < root, "{trace}");
+        assert!(root < not_found, "{trace}");
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn traces_nested_resolves_in_call_order() {
+    run(&REGISTRATION, || async {
+        let trace = trace("pkg").await?;
+
+        let position = |step: &str| {
+            trace
+                .find(step)
+                .unwrap_or_else(|| panic!("{step} not found in {trace}"))
+        };
+        // The package and the exports target are resolved in their own tasks
+        let package = position("[modules] found package directory");
+        let exports = position("\"exports\" field found");
+        let target = position("\"./main.js\" in");
+        let result = position("[result]");
+        assert!(position("[request]") < package, "{trace}");
+        assert!(package < exports, "{trace}");
+        assert!(exports < target, "{trace}");
+        assert!(target < result, "{trace}");
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn traces_relative_requests() {
+    run(&REGISTRATION, || async {
+        let trace = trace("./missing").await?;
+
+        assert!(trace.contains("[extensions]"), "{trace}");
+        assert!(trace.contains("extensions [.js]"), "{trace}");
+        assert!(trace.ends_with("[result] unresolveable\n"), "{trace}");
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn attaches_the_trace_to_resolving_issues() {
+    run(&REGISTRATION, || async {
+        let result = report_unresolveable("missing".into());
+        result.strongly_consistent().await?;
+
+        let issues = result.peek_collectibles::<Box<dyn Issue>>();
+        assert_eq!(issues.len(), 1);
+        for issue in issues {
+            let issue = Vc::try_resolve_downcast_type::<ResolvingIssue>(issue)
+                .await?
+                .expect("a resolving issue");
+            let trace = issue
+                .await?
+                .resolve_trace
+                .expect("a resolve trace")
+                .trace()
+                .to_string()
+                .await?;
+            assert!(
+                trace.contains("[modules] no package named missing found"),
+                "{trace}"
+            );
+        }
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
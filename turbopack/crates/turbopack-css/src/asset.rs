@@ -125,7 +125,6 @@ impl Module for CssModuleAsset {
     #[turbo_tasks::function]
     async fn references(self: Vc<Self>) -> Result<Vc<ModuleReferences>> {
         let result = self.parse_css().await?;
-        // TODO: include CSS source map
 
         match &*result {
             ParseCssResult::Ok { references, .. } => Ok(*references),
@@ -1,3 +1,4 @@
+pub mod order;
 pub(crate) mod single_item_chunk;
 pub mod source_map;
 
@@ -72,8 +73,9 @@ impl CssChunk {
 
             let content = &css_item.content().await?;
             for import in &content.imports {
-                if let CssImport::External(external_import) = import {
-                    external_imports.insert((*external_import.await?).to_string());
+                if let CssImport::External(url, import_context) = import {
+                    external_imports
+                        .insert(external_import_rule(&*url.await?, *import_context).await?);
                 }
             }
 
@@ -87,7 +89,7 @@ impl CssChunk {
         }
 
         for external_import in external_imports {
-            writeln!(code, "@import {};", stringify_js(&external_import))?;
+            writeln!(code, "{external_import}")?;
         }
 
         let built = &body.build();
@@ -124,27 +126,225 @@ pub async fn write_import_context(
     body: &mut impl std::io::Write,
     import_context: Option<Vc<ImportContext>>,
 ) -> Result<String> {
+    let Some(import_context) = import_context else {
+        return Ok(String::new());
+    };
+    let (open, close) = import_context_blocks(&*import_context.await?);
+    write!(body, "{open}")?;
+    Ok(close)
+}
+
+/// Returns the code opening and closing the blocks for the layers and
+/// conditions of an import context. Every import level gets its own block, so
+/// that nested layers, media query lists and negated supports conditions keep
+/// their meaning.
+fn import_context_blocks(import_context: &ImportContext) -> (String, String) {
+    let mut open = String::new();
     let mut close = String::new();
-    if let Some(import_context) = import_context {
-        let import_context = &*import_context.await?;
-        if !&import_context.layers.is_empty() {
-            writeln!(body, "@layer {} {{", import_context.layers.join("."))?;
-            close.push_str("\n}");
+    for layer in &import_context.layers {
+        if layer.is_empty() {
+            open.push_str("@layer {\n");
+        } else {
+            open.push_str(&format!("@layer {} {{\n", layer));
         }
-        if !&import_context.media.is_empty() {
-            writeln!(body, "@media {} {{", import_context.media.join(" and "))?;
-            close.push_str("\n}");
+        close.push_str("\n}");
+    }
+    for media in &import_context.media {
+        open.push_str(&format!("@media {} {{\n", media));
+        close.push_str("\n}");
+    }
+    for supports in &import_context.supports {
+        open.push_str(&format!("@supports {} {{\n", supports));
+        close.push_str("\n}");
+    }
+    (open, close)
+}
+
+/// Returns the `@import` rule for an external stylesheet, which isn't inlined.
+/// The layers and conditions of all imports leading to it are kept on the rule.
+pub async fn external_import_rule(url: &str, import_context: Vc<ImportContext>) -> Result<String> {
+    import_rule(url, &*import_context.await?)
+}
+
+fn import_rule(url: &str, import_context: &ImportContext) -> Result<String> {
+    let mut rule = format!("@import {}", stringify_js(url));
+    if !import_context.layers.is_empty() {
+        // A layer nested in an anonymous layer can't be named, so the whole import
+        // goes into an anonymous layer then.
+        if import_context.layers.iter().any(|layer| layer.is_empty()) {
+            rule.push_str(" layer");
+        } else {
+            write!(rule, " layer({})", import_context.layers.join("."))?;
         }
-        if !&import_context.supports.is_empty() {
-            writeln!(
-                body,
-                "@supports {} {{",
-                import_context.supports.join(" and ")
-            )?;
-            close.push_str("\n}");
+    }
+    match import_context.supports.as_slice() {
+        [] => {}
+        [supports] => write!(rule, " supports({})", supports)?,
+        supports => write!(
+            rule,
+            " supports({})",
+            supports
+                .iter()
+                .map(|supports| format!("({})", supports))
+                .collect::<Vec<_>>()
+                .join(" and ")
+        )?,
+    }
+    match import_context.media.as_slice() {
+        [] => {}
+        [media] => write!(rule, " {}", media)?,
+        media_lists => match intersect_media_query_lists(media_lists) {
+            Some(media) => write!(rule, " {}", media)?,
+            None => {
+                // Queries using `not` or `only` can't be combined with other queries, so
+                // every level imports the next one from a data url instead.
+                for (i, media) in media_lists.iter().enumerate().rev() {
+                    write!(rule, " {};", media)?;
+                    if i > 0 {
+                        rule = format!(
+                            "@import url({})",
+                            stringify_js(&format!("data:text/css,{}", urlencoding::encode(&rule)))
+                        );
+                    }
+                }
+                return Ok(rule);
+            }
+        },
+    }
+    rule.push(';');
+    Ok(rule)
+}
+
+/// A media query of the form `<type> and <condition> and ...`.
+#[derive(Clone, Debug, Default)]
+struct MediaQuery {
+    /// `None` for `all`.
+    media_type: Option<String>,
+    conditions: Vec<String>,
+}
+
+impl MediaQuery {
+    /// Returns `None` for queries using `not` or `only`.
+    fn parse(query: &str) -> Option<Self> {
+        let query = query.trim();
+        if query.starts_with('(') {
+            return Some(MediaQuery {
+                media_type: None,
+                conditions: vec![query.to_string()],
+            });
+        }
+        let (media_type, rest) = query.split_once(char::is_whitespace).unwrap_or((query, ""));
+        if media_type.eq_ignore_ascii_case("not") || media_type.eq_ignore_ascii_case("only") {
+            return None;
+        }
+        let rest = rest.trim_start();
+        let mut conditions = Vec::new();
+        if !rest.is_empty() {
+            let (and, condition) = rest.split_once(char::is_whitespace)?;
+            if !and.eq_ignore_ascii_case("and") {
+                return None;
+            }
+            conditions.push(condition.trim().to_string());
         }
+        Some(MediaQuery {
+            media_type: (!media_type.eq_ignore_ascii_case("all"))
+                .then(|| media_type.to_ascii_lowercase()),
+            conditions,
+        })
+    }
+
+    /// Returns the query matching when both queries match, or `None` when they
+    /// never match at the same time.
+    fn intersect(&self, other: &MediaQuery) -> Option<MediaQuery> {
+        let media_type = match (&self.media_type, &other.media_type) {
+            (Some(a), Some(b)) if a != b => return None,
+            (a, b) => a.clone().or_else(|| b.clone()),
+        };
+        Some(MediaQuery {
+            media_type,
+            conditions: self
+                .conditions
+                .iter()
+                .chain(other.conditions.iter())
+                .cloned()
+                .collect(),
+        })
     }
-    Ok(close)
+}
+
+impl std::fmt::Display for MediaQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let conditions = if self.conditions.len() > 1 {
+            // A condition using `or` or `not` needs parentheses when combined
+            // with `and`.
+            self.conditions
+                .iter()
+                .map(|condition| {
+                    let lowercase = condition.to_ascii_lowercase();
+                    if lowercase.starts_with("not ") || lowercase.contains(" or ") {
+                        format!("({})", condition)
+                    } else {
+                        condition.clone()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" and ")
+        } else {
+            self.conditions.join(" and ")
+        };
+        match (&self.media_type, conditions.is_empty()) {
+            (None, true) => write!(f, "all"),
+            (Some(media_type), true) => write!(f, "{}", media_type),
+            (None, false) => write!(f, "{}", conditions),
+            (Some(media_type), false) => write!(f, "{} and {}", media_type, conditions),
+        }
+    }
+}
+
+/// Splits a media query list at commas which are not nested in parentheses.
+fn split_media_query_list(list: &str) -> Vec<&str> {
+    let mut queries = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in list.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                queries.push(&list[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    queries.push(&list[start..]);
+    queries
+}
+
+/// Returns a media query list which matches when all of the lists match, or
+/// `None` when the lists can't be combined into a single list.
+fn intersect_media_query_lists(lists: &[RcStr]) -> Option<String> {
+    let mut queries = vec![MediaQuery::default()];
+    for list in lists {
+        let list = split_media_query_list(list)
+            .into_iter()
+            .map(MediaQuery::parse)
+            .collect::<Option<Vec<_>>>()?;
+        queries = queries
+            .iter()
+            .flat_map(|query| list.iter().filter_map(|other| query.intersect(other)))
+            .collect();
+    }
+    if queries.is_empty() {
+        return Some("not all".to_string());
+    }
+    Some(
+        queries
+            .iter()
+            .map(|query| query.to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
 }
 
 #[turbo_tasks::value]
@@ -358,7 +558,8 @@ pub struct CssChunkPlaceables(Vec<Vc<Box<dyn CssChunkPlaceable>>>);
 #[derive(Clone, Debug)]
 #[turbo_tasks::value(shared)]
 pub enum CssImport {
-    External(Vc<RcStr>),
+    /// The url of an external stylesheet and the context it is imported in.
+    External(Vc<RcStr>, Vc<ImportContext>),
     Internal(Vc<ImportAssetReference>, Vc<Box<dyn CssChunkItem>>),
     Composes(Vc<Box<dyn CssChunkItem>>),
 }
@@ -493,6 +694,12 @@ impl ChunkType for CssChunkType {
                 .map_or(0, |content| content.inner_code.len()),
         ))
     }
+    #[turbo_tasks::function]
+    fn must_keep_item_order(&self) -> Vc<bool> {
+        // Rules of later chunk items override rules of earlier ones with the
+        // same specificity
+        Vc::cell(true)
+    }
 }
 
 #[turbo_tasks::value_impl]
@@ -502,3 +709,101 @@ impl ValueDefault for CssChunkType {
         Self::default().cell()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use turbopack_core::reference_type::ImportContext;
+
+    use super::{import_context_blocks, import_rule};
+
+    fn import_context(layers: &[&str], media: &[&str], supports: &[&str]) -> ImportContext {
+        ImportContext {
+            layers: layers.iter().map(|&layer| layer.into()).collect(),
+            media: media.iter().map(|&media| media.into()).collect(),
+            supports: supports.iter().map(|&supports| supports.into()).collect(),
+        }
+    }
+
+    #[test]
+    fn nests_a_block_per_level() {
+        let (open, close) = import_context_blocks(&import_context(
+            &["base", ""],
+            &["screen, print", "(color)"],
+            &["not (display: grid)"],
+        ));
+        assert_eq!(
+            open,
+            "@layer base {\n@layer {\n@media screen, print {\n@media (color) {\n@supports not \
+             (display: grid) {\n"
+        );
+        assert_eq!(close, "\n}\n}\n}\n}\n}");
+    }
+
+    #[test]
+    fn keeps_a_single_level_on_the_rule() {
+        assert_eq!(
+            import_rule(
+                "https://example.com/a.css",
+                &import_context(&["base"], &["screen, print"], &["display: grid"])
+            )
+            .unwrap(),
+            r#"@import "https://example.com/a.css" layer(base) supports(display: grid) screen, print;"#
+        );
+    }
+
+    #[test]
+    fn combines_nested_levels_on_the_rule() {
+        assert_eq!(
+            import_rule(
+                "https://example.com/a.css",
+                &import_context(&["base", "theme"], &[], &["display: grid", "not (gap: 0)"])
+            )
+            .unwrap(),
+            r#"@import "https://example.com/a.css" layer(base.theme) supports((display: grid) and (not (gap: 0)));"#
+        );
+        assert_eq!(
+            import_rule(
+                "https://example.com/a.css",
+                &import_context(&["", "theme"], &[], &[])
+            )
+            .unwrap(),
+            r#"@import "https://example.com/a.css" layer;"#
+        );
+    }
+
+    #[test]
+    fn intersects_media_query_lists() {
+        assert_eq!(
+            import_rule(
+                "a.css",
+                &import_context(&[], &["screen, print", "(min-width: 100px), (color)"], &[])
+            )
+            .unwrap(),
+            r#"@import "a.css" screen and (min-width: 100px), screen and (color), print and (min-width: 100px), print and (color);"#
+        );
+        assert_eq!(
+            import_rule(
+                "a.css",
+                &import_context(&[], &["all and (color)", "(hover) or (pointer: fine)"], &[])
+            )
+            .unwrap(),
+            r#"@import "a.css" (color) and ((hover) or (pointer: fine));"#
+        );
+        assert_eq!(
+            import_rule("a.css", &import_context(&[], &["screen", "print"], &[])).unwrap(),
+            r#"@import "a.css" not all;"#
+        );
+    }
+
+    #[test]
+    fn nests_media_queries_which_cant_be_combined() {
+        assert_eq!(
+            import_rule(
+                "https://x/a.css",
+                &import_context(&[], &["not print", "(color)"], &[])
+            )
+            .unwrap(),
+            r#"@import url("data:text/css,%40import%20%22https%3A%2F%2Fx%2Fa.css%22%20%28color%29%3B") not print;"#
+        );
+    }
+}
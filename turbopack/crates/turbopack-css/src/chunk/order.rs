@@ -0,0 +1,226 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use anyhow::Result;
+use turbo_tasks::{RcStr, TryJoinIterExt, ValueToString, Vc};
+use turbo_tasks_fs::FileSystemPath;
+use turbopack_core::{
+    issue::{Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString},
+    output::OutputAssets,
+    reference::all_assets_from_entries,
+};
+
+use super::{CssChunk, CssChunkItem};
+
+/// Returns the CSS chunk items of a chunk group in the order they are applied.
+async fn ordered_css_chunk_items(
+    chunk_group: Vc<OutputAssets>,
+) -> Result<Vec<Vc<Box<dyn CssChunkItem>>>> {
+    let mut chunk_items = Vec::new();
+    for &asset in all_assets_from_entries(chunk_group).await?.iter() {
+        if let Some(chunk) = Vc::try_resolve_downcast_type::<CssChunk>(asset).await? {
+            chunk_items.extend(chunk.await?.content.await?.chunk_items.iter().copied());
+        }
+    }
+    Ok(chunk_items)
+}
+
+/// Emits a warning for every pair of CSS modules which is ordered differently
+/// by two of the `chunk_groups`.
+///
+/// When both chunk groups are loaded on the same page, only one of the orders
+/// can be applied, so rules of equal specificity might resolve differently
+/// depending on which chunk group was loaded first.
+#[turbo_tasks::function]
+pub async fn detect_css_order_conflicts(chunk_groups: Vec<Vc<OutputAssets>>) -> Result<Vc<()>> {
+    let groups = chunk_groups
+        .iter()
+        .map(|&chunk_group| async move {
+            let name = match chunk_group.await?.first() {
+                Some(asset) => asset.ident().path().to_string().await?.clone_value(),
+                None => RcStr::default(),
+            };
+            Ok((name, ordered_css_chunk_items(chunk_group).await?))
+        })
+        .try_join()
+        .await?;
+    let chunk_items = groups
+        .iter()
+        .map(|(_, chunk_items)| chunk_items.as_slice())
+        .collect::<Vec<_>>();
+
+    for conflict in find_order_conflicts(&chunk_items) {
+        CssOrderConflictIssue {
+            path: conflict.second.asset_ident().path(),
+            first: conflict
+                .first
+                .asset_ident()
+                .to_string()
+                .await?
+                .clone_value(),
+            second: conflict
+                .second
+                .asset_ident()
+                .to_string()
+                .await?
+                .clone_value(),
+            first_group: groups[conflict.first_group].0.clone(),
+            second_group: groups[conflict.second_group].0.clone(),
+        }
+        .cell()
+        .emit();
+    }
+
+    Ok(Default::default())
+}
+
+/// Two items which are ordered differently by two groups.
+#[derive(Debug, PartialEq, Eq)]
+struct OrderConflict<T> {
+    /// The item which is ordered first by `first_group`.
+    first: T,
+    /// The item which is ordered first by `second_group`.
+    second: T,
+    first_group: usize,
+    second_group: usize,
+}
+
+/// Finds the pairs of items which are ordered differently by two of the
+/// `groups`. Each pair is only reported once, for the first two groups which
+/// disagree about it.
+fn find_order_conflicts<T: Copy + Eq + Hash>(groups: &[&[T]]) -> Vec<OrderConflict<T>> {
+    let positions = groups
+        .iter()
+        .map(|items| {
+            items
+                .iter()
+                .enumerate()
+                .map(|(index, &item)| (item, index))
+                .collect::<HashMap<_, _>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut reported = HashSet::new();
+    let mut conflicts = Vec::new();
+    for (first_group, items) in groups.iter().enumerate() {
+        for (second_group, positions) in positions.iter().enumerate().skip(first_group + 1) {
+            // The item which is ordered last by the second group so far
+            let mut last: Option<(T, usize)> = None;
+            for &item in items.iter() {
+                let Some(&position) = positions.get(&item) else {
+                    continue;
+                };
+                match last {
+                    Some((previous, last_position)) if position < last_position => {
+                        if reported.insert((previous, item)) {
+                            conflicts.push(OrderConflict {
+                                first: previous,
+                                second: item,
+                                first_group,
+                                second_group,
+                            });
+                        }
+                    }
+                    _ => last = Some((item, position)),
+                }
+            }
+        }
+    }
+    conflicts
+}
+
+#[turbo_tasks::value(shared)]
+struct CssOrderConflictIssue {
+    path: Vc<FileSystemPath>,
+    /// The module which is ordered first by `first_group`.
+    first: RcStr,
+    /// The module which is ordered first by `second_group`.
+    second: RcStr,
+    first_group: RcStr,
+    second_group: RcStr,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for CssOrderConflictIssue {
+    #[turbo_tasks::function]
+    fn severity(&self) -> Vc<IssueSeverity> {
+        IssueSeverity::Warning.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        self.path
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Text("Conflicting order of CSS modules".into()).cell()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Other("chunking".into()).cell()
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        Vc::cell(Some(
+            StyledString::Stack(vec![
+                StyledString::Line(vec![
+                    StyledString::Code(self.first.clone()),
+                    StyledString::Text(" is applied before ".into()),
+                    StyledString::Code(self.second.clone()),
+                    StyledString::Text(" in the chunk group of ".into()),
+                    StyledString::Code(self.first_group.clone()),
+                    StyledString::Text(", but after it in the chunk group of ".into()),
+                    StyledString::Code(self.second_group.clone()),
+                    StyledString::Text(".".into()),
+                ]),
+                StyledString::Text(
+                    "When both are loaded, rules with the same specificity might override each \
+                     other differently. Import these modules in a consistent order."
+                        .into(),
+                ),
+            ])
+            .cell(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_order_conflicts, OrderConflict};
+
+    #[test]
+    fn no_conflicts_for_consistent_orders() {
+        let groups: &[&[&str]] = &[&["a", "b", "c"], &["a", "c"], &["b", "d", "c"]];
+        assert_eq!(find_order_conflicts(groups), vec![]);
+    }
+
+    #[test]
+    fn reports_conflicting_orders() {
+        let groups: &[&[&str]] = &[&["a", "b", "c"], &["c", "a"]];
+        assert_eq!(
+            find_order_conflicts(groups),
+            vec![OrderConflict {
+                first: "a",
+                second: "c",
+                first_group: 0,
+                second_group: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_each_conflict_once() {
+        let groups: &[&[&str]] = &[&["a", "b"], &["b", "a"], &["b", "a"]];
+        let conflicts = find_order_conflicts(groups);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(
+            (conflicts[0].first_group, conflicts[0].second_group),
+            (0, 1)
+        );
+    }
+}
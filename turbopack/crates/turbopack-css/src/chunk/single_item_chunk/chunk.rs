@@ -14,7 +14,7 @@ use turbopack_core::{
 };
 
 use super::source_map::SingleItemCssChunkSourceMapAsset;
-use crate::chunk::{write_import_context, CssChunkItem};
+use crate::chunk::{external_import_rule, write_import_context, CssChunkItem, CssImport};
 
 /// A CSS chunk that only contains a single item. This is used for selectively
 /// loading CSS modules that are part of a larger chunk in development mode, and
@@ -52,8 +52,18 @@ impl SingleItemCssChunk {
 
         let id = &*this.item.id().await?;
 
-        writeln!(code, "/* {} */", id)?;
         let content = this.item.content().await?;
+        for import in &content.imports {
+            if let CssImport::External(url, import_context) = import {
+                writeln!(
+                    code,
+                    "{}",
+                    external_import_rule(&*url.await?, *import_context).await?
+                )?;
+            }
+        }
+
+        writeln!(code, "/* {} */", id)?;
         let close = write_import_context(&mut code, content.import_context).await?;
 
         code.push_source(&content.inner_code, content.source_map.map(Vc::upcast));
@@ -13,10 +13,11 @@ mod module_asset;
 pub(crate) mod parse;
 pub(crate) mod process;
 pub(crate) mod references;
+pub mod sass;
 pub(crate) mod util;
 
 pub use asset::CssModuleAsset;
-pub use module_asset::ModuleCssAsset;
+pub use module_asset::{ModuleCssAsset, ModuleCssExports};
 use serde::{Deserialize, Serialize};
 use turbo_tasks::{trace::TraceRawVcs, TaskInput};
 
@@ -6,7 +6,7 @@ use indoc::formatdoc;
 use lightningcss::css_modules::CssModuleReference;
 use swc_core::common::{BytePos, FileName, LineCol, SourceMap};
 use turbo_tasks::{RcStr, Value, ValueToString, Vc};
-use turbo_tasks_fs::FileSystemPath;
+use turbo_tasks_fs::{File, FileContent, FileSystemPath};
 use turbopack_core::{
     asset::{Asset, AssetContent},
     chunk::{ChunkItem, ChunkItemExt, ChunkType, ChunkableModule, ChunkingContext},
@@ -123,6 +123,17 @@ enum ModuleCssClass {
     },
 }
 
+/// The classes exported by a CSS module.
+#[turbo_tasks::value(shared)]
+#[derive(Debug)]
+pub struct ModuleCssExports {
+    /// The names of the exported classes.
+    pub classes: Vec<RcStr>,
+    /// Classes of other CSS modules which are added to the exported classes by
+    /// `composes: ... from "..."`, with the reference to the other module.
+    pub composes: Vec<(Vc<Box<dyn ModuleReference>>, RcStr)>,
+}
+
 /// A map of CSS classes exported from a CSS module.
 ///
 /// ## Example
@@ -213,6 +224,35 @@ impl ModuleCssAsset {
         Ok(Vc::cell(classes))
     }
 
+    /// Returns the exported class names and the classes composed from other
+    /// CSS modules.
+    #[turbo_tasks::function]
+    pub async fn exports(self: Vc<Self>) -> Result<Vc<ModuleCssExports>> {
+        let classes = self.classes().await?;
+        let mut composes = Vec::new();
+        for class_names in classes.values() {
+            for class_name in class_names {
+                if let ModuleCssClass::Import { original, from } = class_name {
+                    composes.push((Vc::upcast(*from), original.as_str().into()));
+                }
+            }
+        }
+        Ok(ModuleCssExports {
+            classes: classes.keys().map(|name| name.as_str().into()).collect(),
+            composes,
+        }
+        .cell())
+    }
+
+    /// Returns the TypeScript declarations of the exported class names, to be
+    /// written to a `*.module.css.d.ts` file.
+    #[turbo_tasks::function]
+    pub async fn typings(self: Vc<Self>) -> Result<Vc<FileContent>> {
+        let classes = self.classes().await?;
+        let code = typings_code(classes.keys().map(|class_name| class_name.as_str()))?;
+        Ok(FileContent::Content(File::from(code)).cell())
+    }
+
     #[turbo_tasks::function]
     async fn module_references(self: Vc<Self>) -> Result<Vc<ModuleReferences>> {
         let mut references = vec![];
@@ -232,6 +272,16 @@ impl ModuleCssAsset {
     }
 }
 
+/// Generates TypeScript declarations for a CSS module exporting `class_names`.
+fn typings_code<'a>(class_names: impl IntoIterator<Item = &'a str>) -> Result<String> {
+    let mut code = "declare const styles: {\n".to_string();
+    for class_name in class_names {
+        writeln!(code, "  readonly {}: string;", StringifyJs(class_name))?;
+    }
+    code += "};\nexport default styles;\n";
+    Ok(code)
+}
+
 #[turbo_tasks::value_impl]
 impl ChunkableModule for ModuleCssAsset {
     #[turbo_tasks::function]
@@ -360,8 +410,18 @@ impl EcmascriptChunkItem for ModuleChunkItem {
                             continue;
                         };
 
-                        // TODO(alexkirsz) We should also warn if `original_name` can't be found in
-                        // the target module.
+                        if !css_module.classes().await?.contains_key(original_name) {
+                            CssModuleComposesIssue {
+                                severity: IssueSeverity::Warning.cell(),
+                                source: self.module.ident(),
+                                message: formatdoc! {
+                                    r#"
+                                        Class `{original_name}` referenced in `composes: ... from {from};` is not exported by {from}.
+                                    "#,
+                                    from = &*from.await?.request.to_string().await?
+                                }.into(),
+                            }.cell().emit();
+                        }
 
                         let placeable: Vc<Box<dyn EcmascriptChunkPlaceable>> =
                             Vc::upcast(css_module);
@@ -462,3 +522,25 @@ impl Issue for CssModuleComposesIssue {
         Vc::cell(Some(StyledString::Text(self.message.clone()).cell()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::typings_code;
+
+    #[test]
+    fn typings_declare_all_class_names() {
+        assert_eq!(
+            typings_code(["button", "is-active"]).unwrap(),
+            "declare const styles: {\n  readonly \"button\": string;\n  readonly \"is-active\": \
+             string;\n};\nexport default styles;\n"
+        );
+    }
+
+    #[test]
+    fn typings_without_classes() {
+        assert_eq!(
+            typings_code([]).unwrap(),
+            "declare const styles: {\n};\nexport default styles;\n"
+        );
+    }
+}
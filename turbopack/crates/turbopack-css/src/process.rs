@@ -1,4 +1,5 @@
 use std::{
+    borrow::Cow,
     collections::HashMap,
     sync::{Arc, RwLock},
 };
@@ -19,7 +20,7 @@ use regex::Regex;
 use smallvec::smallvec;
 use swc_core::{
     atoms::Atom,
-    base::sourcemap::SourceMapBuilder,
+    base::sourcemap::{decode_data_url, SourceMapBuilder},
     common::{BytePos, FileName, LineCol, Span},
     css::{
         ast::{
@@ -42,11 +43,11 @@ use turbopack_core::{
         Issue, IssueExt, IssueSource, IssueStage, OptionIssueSource, OptionStyledString,
         StyledString,
     },
-    reference::ModuleReferences,
+    reference::{source_map::SourceMapReference, ModuleReferences},
     reference_type::ImportContext,
     resolve::origin::ResolveOrigin,
     source::Source,
-    source_map::{GenerateSourceMap, OptionSourceMap},
+    source_map::{GenerateSourceMap, OptionSourceMap, SourceMap},
     source_pos::SourcePos,
     SOURCE_MAP_PREFIX,
 };
@@ -65,6 +66,11 @@ use crate::{
 // Capture up until the first "."
 static BASENAME_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[^.]*").unwrap());
 
+static SOURCE_MAPPING_URL_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"/\*# sourceMappingURL=(\S+)\s*\*/").unwrap());
+
+static URL_SCHEME_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-zA-Z][a-zA-Z\d+\-.]*:").unwrap());
+
 #[derive(Debug)]
 pub enum StyleSheetLike<'i, 'o> {
     LightningCss(StyleSheet<'i, 'o>),
@@ -111,6 +117,7 @@ impl<'i, 'o> StyleSheetLike<'i, 'o> {
         &self,
         cm: Arc<swc_core::common::SourceMap>,
         code: &str,
+        original_source_map: Vc<OptionSourceMap>,
         enable_srcmap: bool,
         remove_imports: bool,
         handle_nesting: bool,
@@ -147,7 +154,9 @@ impl<'i, 'o> StyleSheetLike<'i, 'o> {
 
                 Ok((
                     result,
-                    srcmap.map(ParseCssResultSourceMap::new_lightningcss),
+                    srcmap.map(|srcmap| {
+                        ParseCssResultSourceMap::new_lightningcss(srcmap, original_source_map)
+                    }),
                 ))
             }
             StyleSheetLike::Swc {
@@ -253,8 +262,9 @@ impl<'i, 'o> StyleSheetLike<'i, 'o> {
 
                 code_gen.emit(&stylesheet)?;
 
-                let srcmap =
-                    srcmap.map(|srcmap| ParseCssResultSourceMap::new_swc(cm.clone(), srcmap));
+                let srcmap = srcmap.map(|srcmap| {
+                    ParseCssResultSourceMap::new_swc(cm.clone(), srcmap, original_source_map)
+                });
 
                 Ok((
                     ToCssResult {
@@ -291,6 +301,10 @@ pub enum ParseCssResult {
 
         #[turbo_tasks(trace_ignore)]
         options: ParserOptions<'static, 'static>,
+
+        /// The source map of the source, if it has been transformed, e.g. from
+        /// Sass. It's used to trace locations back to the original sources.
+        original_source_map: Vc<OptionSourceMap>,
     },
     Unparseable,
     NotFound,
@@ -352,6 +366,7 @@ pub async fn process_css_with_placeholder(
             references,
             url_references,
             code,
+            original_source_map,
             ..
         } => {
             let code = code.await?;
@@ -360,7 +375,8 @@ pub async fn process_css_with_placeholder(
                 _ => bail!("this case should be filtered out while parsing"),
             };
 
-            let (result, _) = stylesheet.to_css(cm.clone(), &code, false, false, false)?;
+            let (result, _) =
+                stylesheet.to_css(cm.clone(), &code, *original_source_map, false, false, false)?;
 
             let exports = result.exports.map(|exports| {
                 let mut exports = exports.into_iter().collect::<IndexMap<_, _>>();
@@ -398,13 +414,18 @@ pub async fn finalize_css(
             url_references,
             ..
         } => {
-            let (mut stylesheet, code) = match &*parse_result.await? {
+            let (mut stylesheet, code, original_source_map) = match &*parse_result.await? {
                 ParseCssResult::Ok {
                     stylesheet,
                     options,
                     code,
+                    original_source_map,
                     ..
-                } => (stylesheet.to_static(options.clone()), *code),
+                } => (
+                    stylesheet.to_static(options.clone()),
+                    *code,
+                    *original_source_map,
+                ),
                 ParseCssResult::Unparseable => return Ok(FinalCssResult::Unparseable.into()),
                 ParseCssResult::NotFound => return Ok(FinalCssResult::NotFound.into()),
             };
@@ -427,7 +448,8 @@ pub async fn finalize_css(
                 FileContent::Content(v) => v.content().to_str()?,
                 _ => bail!("this case should be filtered out while parsing"),
             };
-            let (result, srcmap) = stylesheet.to_css(cm.clone(), &code, true, true, true)?;
+            let (result, srcmap) =
+                stylesheet.to_css(cm.clone(), &code, original_source_map, true, true, true)?;
 
             Ok(FinalCssResult::Ok {
                 output_code: result.code,
@@ -699,9 +721,54 @@ async fn process_content(
     let config = without_warnings(config);
     let mut stylesheet = stylesheet.to_static(config.clone());
 
-    let (references, url_references) =
+    let (mut references, url_references) =
         analyze_references(&mut stylesheet, source, origin, import_context)?;
 
+    // Only use the last sourceMappingURL comment by spec
+    let source_mapping_url = SOURCE_MAPPING_URL_RE
+        .captures_iter(&code)
+        .last()
+        .map(|captures| captures[1].to_string());
+    let comment_source_map = match source_mapping_url {
+        Some(url) if url.starts_with("data:application/json;base64,") => {
+            match decode_data_url(&url) {
+                Ok(map) => Some(Vc::cell(Some(
+                    SourceMap::new_decoded(map)
+                        .cell()
+                        .with_resolved_sources(fs_path_vc),
+                ))),
+                Err(_) => None,
+            }
+        }
+        // Only files next to the stylesheet are read. URLs with a scheme (http:, data:, ...)
+        // and absolute paths can't be read from the file system.
+        Some(url) if url.ends_with(".map") => {
+            let source_map_path = if URL_SCHEME_RE.is_match(&url) || url.starts_with('/') {
+                None
+            } else {
+                *fs_path_vc.parent().try_join(url.into()).await?
+            };
+            source_map_path.map(|source_map_path| {
+                let reference = SourceMapReference::new(fs_path_vc, source_map_path);
+                references.push(Vc::upcast(reference));
+                with_resolved_sources(reference.generate_source_map(), source_map_path)
+            })
+        }
+        _ => None,
+    };
+    let source_map = if let Some(generate_source_map) =
+        Vc::try_resolve_sidecast::<Box<dyn GenerateSourceMap>>(source).await?
+    {
+        with_resolved_sources(generate_source_map.generate_source_map(), fs_path_vc)
+    } else {
+        OptionSourceMap::none()
+    };
+    // The comment refers to the stylesheet before it has been transformed.
+    let original_source_map = match comment_source_map {
+        Some(comment_source_map) => compose_source_maps(source_map, comment_source_map),
+        None => source_map,
+    };
+
     Ok(ParseCssResult::Ok {
         cm,
         code: content_vc,
@@ -709,10 +776,81 @@ async fn process_content(
         references: Vc::cell(references),
         url_references: Vc::cell(url_references),
         options: config,
+        original_source_map,
     }
     .cell())
 }
 
+#[turbo_tasks::function]
+async fn with_resolved_sources(
+    source_map: Vc<OptionSourceMap>,
+    origin: Vc<FileSystemPath>,
+) -> Result<Vc<OptionSourceMap>> {
+    let Some(source_map) = *source_map.await? else {
+        return Ok(Vc::cell(None));
+    };
+    Ok(Vc::cell(Some(source_map.with_resolved_sources(origin))))
+}
+
+/// Traces the mappings of `source_map` through `original_source_map`, so they
+/// point to the sources of `original_source_map`. Returns
+/// `original_source_map` when there is no `source_map`.
+#[turbo_tasks::function]
+async fn compose_source_maps(
+    source_map: Vc<OptionSourceMap>,
+    original_source_map: Vc<OptionSourceMap>,
+) -> Result<Vc<OptionSourceMap>> {
+    let Some(source_map_vc) = *source_map.await? else {
+        return Ok(original_source_map);
+    };
+    let Some(original_vc) = *original_source_map.await? else {
+        return Ok(source_map);
+    };
+    let source_map = source_map_vc.await?.to_source_map().await?;
+    let original = original_vc.await?.to_source_map().await?;
+    let (Some(source_map), Some(original)) = (
+        source_map.as_regular_source_map(),
+        original.as_regular_source_map(),
+    ) else {
+        return Ok(Vc::cell(Some(source_map_vc)));
+    };
+
+    let mut builder = SourceMapBuilder::new(None);
+    let ids = original
+        .sources()
+        .enumerate()
+        .map(|(idx, src)| {
+            let id = builder.add_source(src);
+            builder.set_source_contents(id, original.get_source_contents(idx as u32));
+            id
+        })
+        .collect::<Vec<_>>();
+    for token in source_map.tokens() {
+        if !token.has_source() {
+            continue;
+        }
+        let Some(original_token) = original.lookup_token(token.get_src_line(), token.get_src_col())
+        else {
+            continue;
+        };
+        if !original_token.has_source() {
+            continue;
+        }
+        builder.add_raw(
+            token.get_dst_line(),
+            token.get_dst_col(),
+            original_token.get_src_line(),
+            original_token.get_src_col(),
+            ids.get(original_token.get_src_id() as usize).copied(),
+            None,
+            false,
+        );
+    }
+    Ok(Vc::cell(Some(
+        SourceMap::new_regular(builder.into_sourcemap()).cell(),
+    )))
+}
+
 /// Visitor that lints wrong css module usage.
 ///
 /// ```css
@@ -923,6 +1061,9 @@ pub enum ParseCssResultSourceMap {
     Parcel {
         #[turbo_tasks(debug_ignore, trace_ignore)]
         source_map: parcel_sourcemap::SourceMap,
+
+        /// The source map of the parsed source, if it has been transformed.
+        original_source_map: Vc<OptionSourceMap>,
     },
 
     Swc {
@@ -933,6 +1074,9 @@ pub enum ParseCssResultSourceMap {
         /// (SWC) SourceMap.
         #[turbo_tasks(debug_ignore, trace_ignore)]
         mappings: Vec<(BytePos, LineCol)>,
+
+        /// The source map of the parsed source, if it has been transformed.
+        original_source_map: Vc<OptionSourceMap>,
     },
 }
 
@@ -943,17 +1087,25 @@ impl PartialEq for ParseCssResultSourceMap {
 }
 
 impl ParseCssResultSourceMap {
-    pub fn new_lightningcss(source_map: parcel_sourcemap::SourceMap) -> Self {
-        ParseCssResultSourceMap::Parcel { source_map }
+    pub fn new_lightningcss(
+        source_map: parcel_sourcemap::SourceMap,
+        original_source_map: Vc<OptionSourceMap>,
+    ) -> Self {
+        ParseCssResultSourceMap::Parcel {
+            source_map,
+            original_source_map,
+        }
     }
 
     pub fn new_swc(
         source_map: Arc<swc_core::common::SourceMap>,
         mappings: Vec<(BytePos, LineCol)>,
+        original_source_map: Vc<OptionSourceMap>,
     ) -> Self {
         ParseCssResultSourceMap::Swc {
             source_map,
             mappings,
+            original_source_map,
         }
     }
 }
@@ -961,11 +1113,73 @@ impl ParseCssResultSourceMap {
 #[turbo_tasks::value_impl]
 impl GenerateSourceMap for ParseCssResultSourceMap {
     #[turbo_tasks::function]
-    fn generate_source_map(&self) -> Vc<OptionSourceMap> {
-        match self {
-            ParseCssResultSourceMap::Parcel { source_map } => {
+    async fn generate_source_map(&self) -> Result<Vc<OptionSourceMap>> {
+        let (ParseCssResultSourceMap::Parcel {
+            original_source_map,
+            ..
+        }
+        | ParseCssResultSourceMap::Swc {
+            original_source_map,
+            ..
+        }) = self;
+        let original_source_map = if let Some(input) = *original_source_map.await? {
+            Some(input.await?.to_source_map().await?)
+        } else {
+            None
+        };
+        let input_map = original_source_map
+            .as_ref()
+            .and_then(|map| map.as_regular_source_map());
+
+        Ok(match self {
+            ParseCssResultSourceMap::Parcel { source_map, .. } => {
                 let mut builder = SourceMapBuilder::new(None);
 
+                if let Some(input_map) = &input_map {
+                    // Trace the mappings through the original source map, so they point
+                    // to the sources before the transformation.
+                    let ids = input_map
+                        .sources()
+                        .enumerate()
+                        .map(|(idx, src)| {
+                            let id = builder.add_source(src);
+                            builder
+                                .set_source_contents(id, input_map.get_source_contents(idx as u32));
+                            id
+                        })
+                        .collect::<Vec<_>>();
+
+                    for m in source_map.get_mappings() {
+                        let Some(original) = m.original else {
+                            continue;
+                        };
+                        let Some(token) = input_map
+                            .lookup_token(original.original_line, original.original_column)
+                        else {
+                            continue;
+                        };
+                        if !token.has_source() {
+                            continue;
+                        }
+                        builder.add_raw(
+                            m.generated_line,
+                            m.generated_column,
+                            token.get_src_line(),
+                            token.get_src_col(),
+                            ids.get(token.get_src_id() as usize).copied(),
+                            None,
+                            false,
+                        );
+                    }
+
+                    return Ok(Vc::cell(Some(
+                        turbopack_core::source_map::SourceMap::new_regular(
+                            builder.into_sourcemap(),
+                        )
+                        .cell(),
+                    )));
+                }
+
                 for src in source_map.get_sources() {
                     builder.add_source(&format!("{SOURCE_MAP_PREFIX}{src}"));
                 }
@@ -994,17 +1208,18 @@ impl GenerateSourceMap for ParseCssResultSourceMap {
             ParseCssResultSourceMap::Swc {
                 source_map,
                 mappings,
+                ..
             } => {
                 let map = source_map.build_source_map_with_config(
                     mappings,
-                    None,
+                    input_map.map(Cow::into_owned),
                     InlineSourcesContentConfig {},
                 );
                 Vc::cell(Some(
                     turbopack_core::source_map::SourceMap::new_regular(map).cell(),
                 ))
             }
-        }
+        })
     }
 }
 
@@ -29,8 +29,9 @@ use crate::{
 #[turbo_tasks::value(into = "new", eq = "manual", serialization = "none")]
 pub enum ImportAttributes {
     LightningCss {
+        /// `Some(None)` for an anonymous layer.
         #[turbo_tasks(trace_ignore)]
-        layer_name: Option<LayerName<'static>>,
+        layer_name: Option<Option<LayerName<'static>>>,
         #[turbo_tasks(trace_ignore)]
         supports: Option<SupportsCondition<'static>>,
         #[turbo_tasks(trace_ignore)]
@@ -54,7 +55,7 @@ impl PartialEq for ImportAttributes {
 
 impl ImportAttributes {
     pub fn new_from_lightningcss(prelude: &ImportRule<'static>) -> Self {
-        let layer_name = prelude.layer.clone().flatten();
+        let layer_name = prelude.layer.clone();
 
         let supports = prelude.supports.clone();
 
@@ -148,9 +149,14 @@ impl ImportAttributes {
                 supports,
                 media,
             } => turbopack_core::reference_type::ImportAttributes {
+                // An anonymous layer is represented by an empty name
                 layer: layer_name
                     .as_ref()
-                    .map(|l| l.to_css_string(Default::default()).unwrap())
+                    .map(|l| {
+                        l.as_ref()
+                            .map(|l| l.to_css_string(Default::default()).unwrap())
+                            .unwrap_or_default()
+                    })
                     .map(From::from),
                 supports: supports
                     .as_ref()
@@ -268,9 +274,17 @@ impl CodeGenerateable for ImportAssetReference {
             ..
         } = &*this.request.await?
         {
-            imports.push(CssImport::External(Vc::cell(
-                format!("{}{}", protocol, remainder).into(),
-            )))
+            // External stylesheets are not inlined, so the conditions of the import
+            // need to be kept on the `@import` rule.
+            let own_attrs = (*this.attributes.await?).as_reference_import_attributes();
+            imports.push(CssImport::External(
+                Vc::cell(format!("{}{}", protocol, remainder).into()),
+                this.import_context.add_attributes(
+                    own_attrs.layer,
+                    own_attrs.media,
+                    own_attrs.supports,
+                ),
+            ))
         }
 
         Ok(CodeGeneration { imports }.into())
@@ -0,0 +1,632 @@
+//! Compiles Sass stylesheets (`.scss` and `.sass`) to CSS.
+//!
+//! The compilation itself is done in-process by `grass`. The stylesheets the
+//! compiler looks for via `@use`, `@forward` and `@import` are resolved with
+//! Turbopack's resolver, so they are tracked for invalidation, and handed to
+//! the compiler via an in-memory file system.
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    io,
+    path::{Component, Path, PathBuf},
+};
+
+use anyhow::Result;
+use grass_compiler::{codemap::SpanLoc, ErrorKind as SassErrorKind, Fs, Logger, Options};
+use indexmap::{IndexMap, IndexSet};
+use swc_core::base::sourcemap::SourceMapBuilder;
+use turbo_tasks::{RcStr, Value, ValueToString, Vc};
+use turbo_tasks_fs::{File, FileContent, FileSystemEntryType, FileSystemPath};
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    file_source::FileSource,
+    ident::AssetIdent,
+    issue::{
+        Issue, IssueExt, IssueSeverity, IssueSource, IssueStage, OptionIssueSource,
+        OptionStyledString, StyledString,
+    },
+    reference_type::{CssReferenceSubType, ReferenceType},
+    resolve::{options::ResolveOptions, parse::Request, resolve},
+    source::Source,
+    source_map::{GenerateSourceMap, OptionSourceMap, SourceMap},
+    source_pos::SourcePos,
+    source_transform::SourceTransform,
+    SOURCE_MAP_PREFIX,
+};
+
+/// A source transform which compiles Sass to CSS.
+#[turbo_tasks::value]
+pub struct SassTransform {
+    resolve_options: Vc<ResolveOptions>,
+    rename_as: RcStr,
+}
+
+#[turbo_tasks::value_impl]
+impl SassTransform {
+    /// Creates a new Sass transform. Stylesheets which can't be found relative
+    /// to the importing stylesheet are resolved with `resolve_options`, e.g.
+    /// into packages. The compiled source is renamed with the `rename_as`
+    /// pattern, e.g. `*.css`, so CSS rules apply to it.
+    #[turbo_tasks::function]
+    pub fn new(resolve_options: Vc<ResolveOptions>, rename_as: RcStr) -> Vc<Self> {
+        SassTransform {
+            resolve_options,
+            rename_as,
+        }
+        .cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl SourceTransform for SassTransform {
+    #[turbo_tasks::function]
+    fn transform(self: Vc<Self>, source: Vc<Box<dyn Source>>) -> Vc<Box<dyn Source>> {
+        Vc::upcast(
+            SassCompiledSource {
+                transform: self,
+                source,
+            }
+            .cell(),
+        )
+    }
+}
+
+#[turbo_tasks::value]
+struct SassCompiledSource {
+    transform: Vc<SassTransform>,
+    source: Vc<Box<dyn Source>>,
+}
+
+#[turbo_tasks::value_impl]
+impl Source for SassCompiledSource {
+    #[turbo_tasks::function]
+    async fn ident(&self) -> Result<Vc<AssetIdent>> {
+        Ok(self
+            .source
+            .ident()
+            .rename_as(self.transform.await?.rename_as.clone()))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for SassCompiledSource {
+    #[turbo_tasks::function]
+    async fn content(self: Vc<Self>) -> Result<Vc<AssetContent>> {
+        Ok(self.compile().await?.content)
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl GenerateSourceMap for SassCompiledSource {
+    #[turbo_tasks::function]
+    async fn generate_source_map(self: Vc<Self>) -> Result<Vc<OptionSourceMap>> {
+        Ok(Vc::cell(self.compile().await?.source_map))
+    }
+}
+
+#[turbo_tasks::value]
+struct SassCompilationResult {
+    content: Vc<AssetContent>,
+    source_map: Option<Vc<SourceMap>>,
+}
+
+/// A stylesheet which is part of a compilation.
+struct SassFile {
+    path: Vc<FileSystemPath>,
+    source: Vc<Box<dyn Source>>,
+}
+
+#[turbo_tasks::value_impl]
+impl SassCompiledSource {
+    #[turbo_tasks::function]
+    async fn compile(self: Vc<Self>) -> Result<Vc<SassCompilationResult>> {
+        let this = self.await?;
+        let resolve_options = this.transform.await?.resolve_options;
+
+        let not_found = || {
+            SassCompilationResult {
+                content: AssetContent::File(FileContent::NotFound.cell()).cell(),
+                source_map: None,
+            }
+            .cell()
+        };
+
+        let AssetContent::File(file) = *this.source.content().await? else {
+            return Ok(not_found());
+        };
+        let FileContent::Content(content) = &*file.await? else {
+            return Ok(not_found());
+        };
+
+        let entry_path = this.source.ident().path();
+        let entry = virtual_path(0, entry_path).await?;
+        let mut files = IndexMap::new();
+        files.insert(
+            entry.clone(),
+            SassFile {
+                path: entry_path,
+                source: this.source,
+            },
+        );
+        let mut virtual_paths = HashMap::new();
+        virtual_paths.insert(entry_path.to_string().await?.to_string(), entry.clone());
+        let mut fs = SassFs::default();
+        fs.files
+            .insert(entry.clone(), content.content().to_str()?.into_owned());
+
+        // The compiler can't resolve stylesheets itself, so it's run until it
+        // doesn't look for any new stylesheets. The stylesheets it looked for
+        // are resolved in between.
+        let mut attempted = HashSet::new();
+        let (result, logger) = loop {
+            let logger = SassLogger::default();
+            let result = {
+                let options = Options::default().fs(&fs).logger(&logger);
+                grass_compiler::from_path(&entry, &options)
+            };
+            let requested = fs
+                .requested
+                .take()
+                .into_iter()
+                .filter(|request| attempted.insert(request.clone()))
+                .collect::<Vec<_>>();
+            if requested.is_empty() {
+                break (result, logger);
+            }
+            drop(result);
+
+            for request in requested {
+                let (path, is_dir) = match request {
+                    SassRequest::File(path) => (path, false),
+                    SassRequest::Dir(path) => (path, true),
+                };
+                let Some((importer, relative)) = split_virtual_path(&path) else {
+                    continue;
+                };
+                let Some((importer_path, importer)) = files.get_index(importer) else {
+                    continue;
+                };
+                let url = importer_path
+                    .parent()
+                    .and_then(|dir| requested_url(&path, dir))
+                    .map(str::to_string);
+                let importer = importer.path;
+                let relative = importer.root().try_join(relative.to_string_lossy().into());
+                let relative = match *relative.await? {
+                    Some(relative) => Some((relative, *relative.get_type().await?)),
+                    None => None,
+                };
+
+                if is_dir {
+                    // Urls which are resolved into packages might refer to a directory
+                    // with an index file in the package.
+                    if matches!(relative, Some((_, FileSystemEntryType::Directory)))
+                        || url.as_deref().is_some_and(is_package_url)
+                    {
+                        fs.dirs.insert(normalize(&path));
+                    }
+                    continue;
+                }
+                let resolved = match relative {
+                    Some((relative, FileSystemEntryType::File)) => Some(relative),
+                    _ => match url.as_deref().filter(|url| is_package_url(url)) {
+                        Some(url) => {
+                            resolve_sass_request(
+                                importer.parent(),
+                                url.strip_prefix('~').unwrap_or(url),
+                                resolve_options,
+                            )
+                            .await?
+                        }
+                        None => None,
+                    },
+                };
+                let Some(resolved) = resolved else {
+                    continue;
+                };
+
+                let key = resolved.to_string().await?.to_string();
+                let resolved_virtual = match virtual_paths.get(&key) {
+                    Some(resolved_virtual) => resolved_virtual.clone(),
+                    None => {
+                        let FileContent::Content(content) = &*resolved.read().await? else {
+                            continue;
+                        };
+                        let resolved_virtual = virtual_path(files.len(), resolved).await?;
+                        fs.files.insert(
+                            resolved_virtual.clone(),
+                            content.content().to_str()?.into_owned(),
+                        );
+                        files.insert(
+                            resolved_virtual.clone(),
+                            SassFile {
+                                path: resolved,
+                                source: Vc::upcast(FileSource::new(resolved)),
+                            },
+                        );
+                        virtual_paths.insert(key, resolved_virtual.clone());
+                        resolved_virtual
+                    }
+                };
+                let path = normalize(&path);
+                if path != resolved_virtual {
+                    fs.aliases.insert(path, resolved_virtual);
+                }
+            }
+        };
+
+        let issue_source = |loc: &SpanLoc| {
+            let file = files.get(Path::new(loc.file.name()))?;
+            let start = SourcePos {
+                line: loc.begin.line,
+                column: loc.begin.column,
+            };
+            let end = SourcePos {
+                line: loc.end.line,
+                column: loc.end.column,
+            };
+            Some(IssueSource::from_line_col(file.source, start, end))
+        };
+
+        for (severity, loc, message) in logger.messages.into_inner() {
+            SassIssue {
+                path: entry_path,
+                severity,
+                message: message.into(),
+                source: issue_source(&loc),
+            }
+            .cell()
+            .emit();
+        }
+
+        let css = match result {
+            Ok(css) => css,
+            Err(err) => {
+                let (message, source) = match err.kind() {
+                    SassErrorKind::ParseError { message, loc, .. } => (message, issue_source(&loc)),
+                    SassErrorKind::FromUtf8Error(message) => (message, None),
+                    SassErrorKind::IoError(err) => (err.to_string(), None),
+                    _ => ("unknown error".to_string(), None),
+                };
+                SassIssue {
+                    path: entry_path,
+                    severity: IssueSeverity::Error,
+                    message: message.into(),
+                    source,
+                }
+                .cell()
+                .emit();
+                return Ok(not_found());
+            }
+        };
+
+        // The compiler doesn't track where the generated CSS originates from, so
+        // the source map only maps the compiled stylesheet to the entry. It
+        // contains all stylesheets which have been loaded, relative to the
+        // compiled stylesheet like in source maps of other tools.
+        let loaded = fs.loaded.into_inner();
+        let entry_dir = entry_path.parent().await?;
+        let mut builder = SourceMapBuilder::new(None);
+        for (virtual_path, file) in files.iter() {
+            if !loaded.contains(virtual_path) {
+                continue;
+            }
+            let source = match entry_dir.get_relative_path_to(&*file.path.await?) {
+                Some(relative) => relative.to_string(),
+                None => format!("{SOURCE_MAP_PREFIX}{}", file.path.to_string().await?),
+            };
+            let id = builder.add_source(&source);
+            builder.set_source_contents(id, fs.files.get(virtual_path).map(String::as_str));
+        }
+        builder.add_raw(0, 0, 0, 0, Some(0), None, false);
+        let source_map = SourceMap::new_regular(builder.into_sourcemap()).cell();
+
+        Ok(SassCompilationResult {
+            content: AssetContent::file(File::from(css).into()),
+            source_map: Some(source_map),
+        }
+        .cell())
+    }
+}
+
+/// The path of the `index`th stylesheet of a compilation in the in-memory file
+/// system of the compiler. Each stylesheet has its own root, so the importer
+/// of every path the compiler looks for is known.
+async fn virtual_path(index: usize, path: Vc<FileSystemPath>) -> Result<PathBuf> {
+    Ok(PathBuf::from(format!("/{index}/{}", path.await?.path)))
+}
+
+/// Splits a path the compiler looked for into the index of the stylesheet it
+/// is relative to, and the path relative to the root of its file system.
+fn split_virtual_path(path: &Path) -> Option<(usize, PathBuf)> {
+    let path = normalize(path);
+    let mut components = path.components();
+    if components.next() != Some(Component::RootDir) {
+        return None;
+    }
+    let index = components.next()?.as_os_str().to_str()?.parse().ok()?;
+    Some((index, components.as_path().to_path_buf()))
+}
+
+/// Returns the url a path the compiler looked for has been requested with,
+/// i.e. the path relative to the directory of the importing stylesheet, as
+/// it was written. Returns [None] when the path isn't inside that directory.
+fn requested_url<'a>(path: &'a Path, importer_dir: &Path) -> Option<&'a str> {
+    path.to_str()?
+        .strip_prefix(importer_dir.to_str()?)?
+        .strip_prefix('/')
+}
+
+/// Whether a url is resolved as a request, e.g. into a package, when it can't
+/// be found relative to the importing stylesheet. A leading `~` is removed
+/// before the url is resolved.
+fn is_package_url(url: &str) -> bool {
+    !(url.starts_with("./") || url.starts_with("../") || url.starts_with('/'))
+}
+
+/// Lexically resolves `.` and `..` segments, as the compiler joins urls to
+/// the path of the importing stylesheet without normalizing them.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+async fn resolve_sass_request(
+    lookup_path: Vc<FileSystemPath>,
+    request: &str,
+    resolve_options: Vc<ResolveOptions>,
+) -> Result<Option<Vc<FileSystemPath>>> {
+    let result = resolve(
+        lookup_path,
+        Value::new(ReferenceType::Css(CssReferenceSubType::AtImport(None))),
+        Request::parse(Value::new(RcStr::from(request).into())),
+        resolve_options,
+    );
+    Ok(match *result.first_source().await? {
+        Some(source) => Some(source.ident().path().resolve().await?),
+        None => None,
+    })
+}
+
+/// A file or directory the compiler looked for, which isn't part of the
+/// compilation yet.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum SassRequest {
+    File(PathBuf),
+    Dir(PathBuf),
+}
+
+/// The file system seen by the compiler. It contains all resolved stylesheets.
+/// Urls resolved into packages are aliases of the resolved stylesheet, so
+/// further urls are loaded relative to the resolved stylesheet.
+#[derive(Debug, Default)]
+struct SassFs {
+    files: HashMap<PathBuf, String>,
+    aliases: HashMap<PathBuf, PathBuf>,
+    dirs: HashSet<PathBuf>,
+    /// The files and directories the compiler looked for but didn't find, as
+    /// they were requested.
+    requested: RefCell<IndexSet<SassRequest>>,
+    /// The stylesheets the compiler has loaded.
+    loaded: RefCell<HashSet<PathBuf>>,
+}
+
+impl SassFs {
+    fn lookup(&self, path: &Path) -> PathBuf {
+        let path = normalize(path);
+        self.aliases.get(&path).cloned().unwrap_or(path)
+    }
+}
+
+impl Fs for SassFs {
+    fn is_dir(&self, path: &Path) -> bool {
+        let normalized = normalize(path);
+        let is_dir = self.dirs.contains(&normalized)
+            || self
+                .files
+                .keys()
+                .chain(self.aliases.keys())
+                .any(|file| file != &normalized && file.starts_with(&normalized));
+        if !is_dir {
+            self.requested
+                .borrow_mut()
+                .insert(SassRequest::Dir(path.to_path_buf()));
+        }
+        is_dir
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        let is_file = self.files.contains_key(&self.lookup(path));
+        if !is_file {
+            self.requested
+                .borrow_mut()
+                .insert(SassRequest::File(path.to_path_buf()));
+        }
+        is_file
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        let path = self.lookup(path);
+        let code = self
+            .files
+            .get(&path)
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+        self.loaded.borrow_mut().insert(path);
+        Ok(code.clone().into_bytes())
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        Ok(self.lookup(path))
+    }
+}
+
+/// Collects `@warn` and `@debug` messages, which are reported as issues.
+#[derive(Debug, Default)]
+struct SassLogger {
+    messages: RefCell<Vec<(IssueSeverity, SpanLoc, String)>>,
+}
+
+impl Logger for SassLogger {
+    fn debug(&self, location: SpanLoc, message: &str) {
+        self.messages
+            .borrow_mut()
+            .push((IssueSeverity::Info, location, message.to_string()));
+    }
+
+    fn warn(&self, location: SpanLoc, message: &str) {
+        self.messages
+            .borrow_mut()
+            .push((IssueSeverity::Warning, location, message.to_string()));
+    }
+}
+
+#[turbo_tasks::value(shared)]
+struct SassIssue {
+    path: Vc<FileSystemPath>,
+    severity: IssueSeverity,
+    message: RcStr,
+    source: Option<Vc<IssueSource>>,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for SassIssue {
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        self.path
+    }
+
+    #[turbo_tasks::function]
+    fn severity(&self) -> Vc<IssueSeverity> {
+        self.severity.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Text(match self.severity {
+            IssueSeverity::Error => "Sass compilation failed".into(),
+            _ => "Sass message".into(),
+        })
+        .cell()
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        Vc::cell(Some(StyledString::Text(self.message.clone()).cell()))
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::SourceTransform.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn source(&self) -> Vc<OptionIssueSource> {
+        Vc::cell(self.source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, path::PathBuf};
+
+    use grass_compiler::Options;
+
+    use super::{normalize, requested_url, split_virtual_path, SassFs, SassRequest};
+
+    #[test]
+    fn compile_with_aliases() {
+        let fs = SassFs {
+            files: HashMap::from([
+                (
+                    PathBuf::from("/project/src/index.scss"),
+                    "@use \"lib/colors\";\n@use \"../shared\";\n.a { color: colors.$primary; }\n"
+                        .to_string(),
+                ),
+                (
+                    PathBuf::from("/project/node_modules/lib/_colors.scss"),
+                    "@use \"base\";\n$primary: base.$red;\n".to_string(),
+                ),
+                (
+                    PathBuf::from("/project/node_modules/lib/_base.scss"),
+                    "$red: #f00;\n".to_string(),
+                ),
+                (
+                    PathBuf::from("/project/shared/_index.scss"),
+                    ".shared { display: block; }\n".to_string(),
+                ),
+            ]),
+            aliases: HashMap::from([(
+                PathBuf::from("/project/src/lib/colors.scss"),
+                PathBuf::from("/project/node_modules/lib/_colors.scss"),
+            )]),
+            ..Default::default()
+        };
+        let css = grass_compiler::from_path("/project/src/index.scss", &Options::default().fs(&fs))
+            .unwrap();
+        assert_eq!(
+            css,
+            ".shared {\n  display: block;\n}\n\n.a {\n  color: #f00;\n}\n"
+        );
+        assert!(fs
+            .loaded
+            .borrow()
+            .contains(&PathBuf::from("/project/node_modules/lib/_base.scss")));
+    }
+
+    #[test]
+    fn records_requested_stylesheets() {
+        let fs = SassFs {
+            files: HashMap::from([(
+                PathBuf::from("/0/src/index.scss"),
+                "@use \"./theme\";\n".to_string(),
+            )]),
+            ..Default::default()
+        };
+        assert!(
+            grass_compiler::from_path("/0/src/index.scss", &Options::default().fs(&fs)).is_err()
+        );
+        assert!(fs
+            .requested
+            .borrow()
+            .contains(&SassRequest::File(PathBuf::from("/0/src/./theme.scss"))));
+    }
+
+    #[test]
+    fn splits_virtual_paths() {
+        assert_eq!(
+            split_virtual_path("/2/src/styles/../_theme.scss".as_ref()),
+            Some((2, PathBuf::from("src/_theme.scss")))
+        );
+        assert_eq!(split_virtual_path("/src/_theme.scss".as_ref()), None);
+        assert_eq!(
+            requested_url("/2/src/./_theme.scss".as_ref(), "/2/src".as_ref()),
+            Some("./_theme.scss")
+        );
+        assert_eq!(
+            requested_url("/2/src/~pkg/_theme.scss".as_ref(), "/2/src".as_ref()),
+            Some("~pkg/_theme.scss")
+        );
+        assert_eq!(
+            requested_url("/2/other/_theme.scss".as_ref(), "/2/src".as_ref()),
+            None
+        );
+    }
+
+    #[test]
+    fn normalizes_paths() {
+        assert_eq!(
+            normalize("/a/b/../c/./d.scss".as_ref()),
+            PathBuf::from("/a/c/d.scss")
+        );
+    }
+}
@@ -14,6 +14,7 @@ use turbopack_core::{
     output::{OutputAsset, OutputAssets},
     version::{Version, VersionedContent},
 };
+use turbopack_css::chunk::order::detect_css_order_conflicts;
 
 // TODO(WEB-945) This should become a struct once we have a
 // `turbo_tasks::input` attribute macro/`Input` derive macro.
@@ -129,7 +130,7 @@ impl DevHtmlAsset {
     async fn chunks(self: Vc<Self>) -> Result<Vc<OutputAssets>> {
         let this = self.await?;
 
-        let all_assets = this
+        let chunk_groups = this
             .entries
             .iter()
             .map(|entry| async move {
@@ -152,9 +153,18 @@ impl DevHtmlAsset {
                     chunking_context.root_chunk_group_assets(Vc::upcast(chunkable_module))
                 };
 
-                assets.await
+                assets.resolve().await
             })
             .try_join()
+            .await?;
+
+        // All entries are loaded on the same page, so their CSS must be applied in
+        // the same order.
+        detect_css_order_conflicts(chunk_groups.clone()).await?;
+
+        let all_assets = chunk_groups
+            .iter()
+            .try_join()
             .await?
             .iter()
             .flatten()
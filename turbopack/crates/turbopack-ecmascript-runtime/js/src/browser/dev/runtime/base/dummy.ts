@@ -10,13 +10,14 @@
 /// <reference path="../../../../shared/require-type.d.ts" />
 
 declare var BACKEND: RuntimeBackend;
+declare var DEV_BACKEND: DevRuntimeBackend;
 declare var _eval: (code: EcmascriptModuleEntry) => any;
 /**
- * Adds additional properties to the `TurbopackDevBaseContext` interface.
+ * Adds additional properties to the `TurbopackBrowserBaseContext` interface.
  */
 declare var augmentContext: (
-  context: TurbopackDevBaseContext
-) => TurbopackDevContext;
+  context: TurbopackBrowserBaseContext
+) => TurbopackBrowserContext;
 declare var loadWebAssembly: (
   source: SourceInfo,
   wasmChunkPath: ChunkPath,
@@ -62,4 +62,6 @@ interface Hot {
 
 interface Module {
   hot: Hot;
+  children: ModuleId[];
+  parents: ModuleId[];
 }
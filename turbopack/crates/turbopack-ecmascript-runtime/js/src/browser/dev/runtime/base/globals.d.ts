@@ -7,10 +7,6 @@
 
 type UpdateCallback = (update: ServerMessage) => void;
 
-type ChunkRegistry = {
-  push: (registration: ChunkRegistration) => void;
-};
-
 type ChunkListProvider = {
   push: (registration: ChunkList) => void;
 };
@@ -19,7 +15,6 @@ type ChunkUpdateProvider = {
   push: (registration: [ChunkPath, UpdateCallback]) => void;
 };
 
-declare var TURBOPACK: ChunkRegistry | ChunkRegistration[] | undefined;
 declare var TURBOPACK_CHUNK_LISTS: ChunkListProvider | ChunkList[] | undefined;
 declare var TURBOPACK_CHUNK_UPDATE_LISTENERS:
   | ChunkUpdateProvider
@@ -1,14 +1,15 @@
 /**
- * This file contains runtime types and functions that are shared between all
- * Turbopack *development* ECMAScript runtimes.
+ * This file contains runtime types and functions that are only used by the
+ * Turbopack *development* ECMAScript runtimes for the browser: tracking module
+ * parents and chunk membership, and applying hot module updates.
  *
  * It will be appended to the runtime code of each runtime right after the
- * shared runtime utils.
+ * shared browser runtime base (`browser/runtime/base/runtime-base.ts`).
  */
 
 /* eslint-disable @typescript-eslint/no-unused-vars */
 
-/// <reference path="../../../../shared/runtime-utils.ts" />
+/// <reference path="../../../runtime/base/runtime-base.ts" />
 /// <reference path="./globals.d.ts" />
 /// <reference path="./protocol.d.ts" />
 /// <reference path="./extensions.d.ts" />
@@ -19,7 +20,6 @@
 type RefreshRuntimeGlobals =
   import("@next/react-refresh-utils/dist/runtime").RefreshRuntimeGlobals;
 
-declare var CHUNK_BASE_PATH: string;
 declare var $RefreshHelpers$: RefreshRuntimeGlobals["$RefreshHelpers$"];
 declare var $RefreshReg$: RefreshRuntimeGlobals["$RefreshReg$"];
 declare var $RefreshSig$: RefreshRuntimeGlobals["$RefreshSig$"];
@@ -34,71 +34,25 @@ type RefreshContext = {
 
 type RefreshHelpers = RefreshRuntimeGlobals["$RefreshHelpers$"];
 
-interface TurbopackDevBaseContext extends TurbopackBaseContext {
+interface TurbopackBrowserBaseContext {
   k: RefreshContext;
-  R: ResolvePathFromModule;
+  h: Hot;
 }
 
-interface TurbopackDevContext extends TurbopackDevBaseContext {}
-
 // string encoding of a module factory (used in hmr updates)
 type ModuleFactoryString = string;
 
-type ModuleFactory = (
-  this: Module["exports"],
-  context: TurbopackDevContext
-) => undefined;
-
-type DevRuntimeParams = {
-  otherChunks: ChunkData[];
-  runtimeModuleIds: ModuleId[];
-};
-
-type ChunkRegistration = [
-  chunkPath: ChunkPath,
-  chunkModules: ModuleFactories,
-  params: DevRuntimeParams | undefined
-];
 type ChunkList = {
   path: ChunkPath;
   chunks: ChunkData[];
   source: "entry" | "dynamic";
 };
 
-enum SourceType {
-  /**
-   * The module was instantiated because it was included in an evaluated chunk's
-   * runtime.
-   */
-  Runtime = 0,
-  /**
-   * The module was instantiated because a parent module imported it.
-   */
-  Parent = 1,
-  /**
-   * The module was instantiated because it was included in a chunk's hot module
-   * update.
-   */
-  Update = 2,
-}
-
-type SourceInfo =
-  | {
-      type: SourceType.Runtime;
-      chunkPath: ChunkPath;
-    }
-  | {
-      type: SourceType.Parent;
-      parentId: ModuleId;
-    }
-  | {
-      type: SourceType.Update;
-      parents?: ModuleId[];
-    };
-
-interface RuntimeBackend {
-  registerChunk: (chunkPath: ChunkPath, params?: DevRuntimeParams) => void;
-  loadChunk: (chunkPath: ChunkPath, source: SourceInfo) => Promise<void>;
+/**
+ * The parts of the runtime backend that are only needed to apply hot module
+ * updates.
+ */
+interface DevRuntimeBackend {
   reloadChunk?: (chunkPath: ChunkPath) => Promise<void>;
   unloadChunk?: (chunkPath: ChunkPath) => void;
 
@@ -116,8 +70,6 @@ class UpdateApplyError extends Error {
   }
 }
 
-const moduleFactories: ModuleFactories = Object.create(null);
-const moduleCache: ModuleCache = Object.create(null);
 /**
  * Maps module IDs to persisted data between executions of their hot module
  * implementation (`hot.data`).
@@ -162,130 +114,6 @@ const chunkListChunksMap: Map<ChunkPath, Set<ChunkPath>> = new Map();
  */
 const chunkChunkListsMap: Map<ChunkPath, Set<ChunkPath>> = new Map();
 
-const availableModules: Map<ModuleId, Promise<any> | true> = new Map();
-
-const availableModuleChunks: Map<ChunkPath, Promise<any> | true> = new Map();
-
-async function loadChunk(
-  source: SourceInfo,
-  chunkData: ChunkData
-): Promise<any> {
-  if (typeof chunkData === "string") {
-    return loadChunkPath(source, chunkData);
-  }
-
-  const includedList = chunkData.included || [];
-  const modulesPromises = includedList.map((included) => {
-    if (moduleFactories[included]) return true;
-    return availableModules.get(included);
-  });
-  if (modulesPromises.length > 0 && modulesPromises.every((p) => p)) {
-    // When all included items are already loaded or loading, we can skip loading ourselves
-    return Promise.all(modulesPromises);
-  }
-
-  const includedModuleChunksList = chunkData.moduleChunks || [];
-  const moduleChunksPromises = includedModuleChunksList
-    .map((included) => {
-      // TODO(alexkirsz) Do we need this check?
-      // if (moduleFactories[included]) return true;
-      return availableModuleChunks.get(included);
-    })
-    .filter((p) => p);
-
-  let promise;
-  if (moduleChunksPromises.length > 0) {
-    // Some module chunks are already loaded or loading.
-
-    if (moduleChunksPromises.length === includedModuleChunksList.length) {
-      // When all included module chunks are already loaded or loading, we can skip loading ourselves
-      return Promise.all(moduleChunksPromises);
-    }
-
-    const moduleChunksToLoad: Set<ChunkPath> = new Set();
-    for (const moduleChunk of includedModuleChunksList) {
-      if (!availableModuleChunks.has(moduleChunk)) {
-        moduleChunksToLoad.add(moduleChunk);
-      }
-    }
-
-    for (const moduleChunkToLoad of moduleChunksToLoad) {
-      const promise = loadChunkPath(source, moduleChunkToLoad);
-
-      availableModuleChunks.set(moduleChunkToLoad, promise);
-
-      moduleChunksPromises.push(promise);
-    }
-
-    promise = Promise.all(moduleChunksPromises);
-  } else {
-    promise = loadChunkPath(source, chunkData.path);
-
-    // Mark all included module chunks as loading if they are not already loaded or loading.
-    for (const includedModuleChunk of includedModuleChunksList) {
-      if (!availableModuleChunks.has(includedModuleChunk)) {
-        availableModuleChunks.set(includedModuleChunk, promise);
-      }
-    }
-  }
-
-  for (const included of includedList) {
-    if (!availableModules.has(included)) {
-      // It might be better to race old and new promises, but it's rare that the new promise will be faster than a request started earlier.
-      // In production it's even more rare, because the chunk optimization tries to deduplicate modules anyway.
-      availableModules.set(included, promise);
-    }
-  }
-
-  return promise;
-}
-
-async function loadChunkPath(
-  source: SourceInfo,
-  chunkPath: ChunkPath
-): Promise<any> {
-  try {
-    await BACKEND.loadChunk(chunkPath, source);
-  } catch (error) {
-    let loadReason;
-    switch (source.type) {
-      case SourceType.Runtime:
-        loadReason = `as a runtime dependency of chunk ${source.chunkPath}`;
-        break;
-      case SourceType.Parent:
-        loadReason = `from module ${source.parentId}`;
-        break;
-      case SourceType.Update:
-        loadReason = "from an HMR update";
-        break;
-      default:
-        invariant(source, (source) => `Unknown source type: ${source?.type}`);
-    }
-    throw new Error(
-      `Failed to load chunk ${chunkPath} ${loadReason}${
-        error ? `: ${error}` : ""
-      }`,
-      error
-        ? {
-            cause: error,
-          }
-        : undefined
-    );
-  }
-}
-
-/**
- * Returns an absolute url to an asset.
- */
-function createResolvePathFromModule(
-  resolver: (moduleId: string) => Exports
-): (moduleId: string) => string {
-  return function resolvePathFromModule(moduleId: string): string {
-    const exported = resolver(moduleId);
-    return exported?.default ?? exported;
-  };
-}
-
 function instantiateModule(id: ModuleId, source: SourceInfo): Module {
   const moduleFactory = moduleFactories[id];
   if (typeof moduleFactory !== "function") {
@@ -375,6 +203,7 @@ function instantiateModule(id: ModuleId, source: SourceInfo): Module {
           P: resolveAbsolutePath,
           U: relativeURL,
           k: refresh,
+          h: hot,
           R: createResolvePathFromModule(r),
           __dirname: module.id.replace(/(^|\/)\/+$/, ""),
         })
@@ -394,14 +223,6 @@ function instantiateModule(id: ModuleId, source: SourceInfo): Module {
   return module;
 }
 
-/**
- * no-op for browser
- * @param modulePath
- */
-function resolveAbsolutePath(modulePath?: string): string {
-  return `/ROOT/${modulePath ?? ""}`;
-}
-
 /**
  * NOTE(alexkirsz) Webpack has a "module execution" interception hook that
  * Next.js' React Refresh runtime hooks into to add module context to the
@@ -791,10 +612,10 @@ function applyChunkListUpdate(update: ChunkListUpdate) {
           BACKEND.loadChunk(chunkPath, { type: SourceType.Update });
           break;
         case "total":
-          BACKEND.reloadChunk?.(chunkPath);
+          DEV_BACKEND.reloadChunk?.(chunkPath);
           break;
         case "deleted":
-          BACKEND.unloadChunk?.(chunkPath);
+          DEV_BACKEND.unloadChunk?.(chunkPath);
           break;
         case "partial":
           invariant(
@@ -1073,7 +894,7 @@ function handleApply(chunkListPath: ChunkPath, update: ServerMessage) {
       // This indicates that there is no way to apply the update to the
       // current state of the application, and that the application must be
       // restarted.
-      BACKEND.restart();
+      DEV_BACKEND.restart();
       break;
     }
     case "notFound": {
@@ -1082,7 +903,7 @@ function handleApply(chunkListPath: ChunkPath, update: ServerMessage) {
       // If it is a dynamic import, we simply discard all modules that the chunk has exclusive access to.
       // If it is a runtime chunk list, we restart the application.
       if (runtimeChunkLists.has(chunkListPath)) {
-        BACKEND.restart();
+        DEV_BACKEND.restart();
       } else {
         disposeChunkList(chunkListPath);
       }
@@ -1257,7 +1078,7 @@ function disposeChunkList(chunkListPath: ChunkPath): boolean {
 
   // We must also dispose of the chunk list's chunk itself to ensure it may
   // be reloaded properly in the future.
-  BACKEND.unloadChunk?.(chunkListPath);
+  DEV_BACKEND.unloadChunk?.(chunkListPath);
 
   return true;
 }
@@ -1270,7 +1091,7 @@ function disposeChunkList(chunkListPath: ChunkPath): boolean {
 function disposeChunk(chunkPath: ChunkPath): boolean {
   // This should happen whether the chunk has any modules in it or not.
   // For instance, CSS chunks have no modules in them, but they still need to be unloaded.
-  BACKEND.unloadChunk?.(chunkPath);
+  DEV_BACKEND.unloadChunk?.(chunkPath);
 
   const chunkModules = chunkModulesMap.get(chunkPath);
   if (chunkModules == null) {
@@ -1303,34 +1124,6 @@ function instantiateRuntimeModule(
   return instantiateModule(moduleId, { type: SourceType.Runtime, chunkPath });
 }
 
-/**
- * Gets or instantiates a runtime module.
- */
-function getOrInstantiateRuntimeModule(
-  moduleId: ModuleId,
-  chunkPath: ChunkPath
-): Module {
-  const module = moduleCache[moduleId];
-  if (module) {
-    if (module.error) {
-      throw module.error;
-    }
-    return module;
-  }
-
-  return instantiateModule(moduleId, { type: SourceType.Runtime, chunkPath });
-}
-
-/**
- * Returns the URL relative to the origin where a chunk can be fetched from.
- */
-function getChunkRelativeUrl(chunkPath: ChunkPath): string {
-  return `${CHUNK_BASE_PATH}${chunkPath
-    .split("/")
-    .map((p) => encodeURIComponent(p))
-    .join("/")}`;
-}
-
 /**
  * Subscribes to chunk list updates from the update server and applies them.
  */
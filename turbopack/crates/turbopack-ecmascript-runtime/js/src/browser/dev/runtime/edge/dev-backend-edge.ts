@@ -0,0 +1,24 @@
+/**
+ * This file contains the development-only runtime code specific to the
+ * Turbopack ECMAScript "None" runtime (e.g. for Edge).
+ *
+ * It will be appended to the base development runtime code and the shared
+ * "None" runtime backend.
+ */
+
+/* eslint-disable @typescript-eslint/no-unused-vars */
+
+/// <reference path="../base/runtime-base.ts" />
+/// <reference path="../../../runtime/edge/runtime-backend-edge.ts" />
+
+let DEV_BACKEND: DevRuntimeBackend;
+
+DEV_BACKEND = {
+  restart: () => {
+    throw new Error("restart is not supported");
+  },
+};
+
+function _eval(_: EcmascriptModuleEntry): ModuleFactory {
+  throw new Error("HMR evaluation is not implemented on this backend");
+}
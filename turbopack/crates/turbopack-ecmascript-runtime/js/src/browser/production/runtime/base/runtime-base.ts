@@ -0,0 +1,133 @@
+/**
+ * This file contains runtime types and functions that are only used by the
+ * Turbopack *production* ECMAScript runtimes for the browser.
+ *
+ * Unlike the development runtime, it doesn't keep track of module parents,
+ * children or chunk membership, and contains no hot module replacement code.
+ *
+ * It will be appended to the runtime code of each runtime right after the
+ * shared browser runtime base (`browser/runtime/base/runtime-base.ts`).
+ */
+
+/* eslint-disable @typescript-eslint/no-unused-vars */
+
+/// <reference path="../../../runtime/base/runtime-base.ts" />
+
+/**
+ * Hot module updates are only applied by the development runtime, so modules
+ * are only instantiated as runtime entries or when required by a parent.
+ */
+type InstantiationSourceInfo = Exclude<
+  SourceInfo,
+  { type: SourceType.Update }
+>;
+
+function instantiateModule(
+  id: ModuleId,
+  source: InstantiationSourceInfo
+): Module {
+  const moduleFactory = moduleFactories[id];
+  if (typeof moduleFactory !== "function") {
+    let instantiationReason;
+    switch (source.type) {
+      case SourceType.Runtime:
+        instantiationReason = `as a runtime entry of chunk ${source.chunkPath}`;
+        break;
+      case SourceType.Parent:
+        instantiationReason = `because it was required from module ${source.parentId}`;
+        break;
+      default:
+        invariant(source, (source) => `Unknown source type: ${source?.type}`);
+    }
+    throw new Error(
+      `Module ${id} was instantiated ${instantiationReason}, but the module factory is not available.`
+    );
+  }
+
+  const module: Module = {
+    exports: {},
+    error: undefined,
+    loaded: false,
+    id,
+    namespaceObject: undefined,
+  };
+
+  moduleCache[id] = module;
+
+  // NOTE(alexkirsz) This can fail when the module encounters a runtime error.
+  try {
+    const sourceInfo: SourceInfo = { type: SourceType.Parent, parentId: id };
+    const r = commonJsRequire.bind(null, module);
+
+    moduleFactory.call(
+      module.exports,
+      augmentContext({
+        a: asyncModule.bind(null, module),
+        e: module.exports,
+        r,
+        t: runtimeRequire,
+        f: moduleContext,
+        i: esmImport.bind(null, module),
+        s: esmExport.bind(null, module, module.exports),
+        j: dynamicExport.bind(null, module, module.exports),
+        v: exportValue.bind(null, module),
+        n: exportNamespace.bind(null, module),
+        m: module,
+        c: moduleCache,
+        M: moduleFactories,
+        l: loadChunk.bind(null, sourceInfo),
+        w: loadWebAssembly.bind(null, sourceInfo),
+        u: loadWebAssemblyModule.bind(null, sourceInfo),
+        g: globalThis,
+        P: resolveAbsolutePath,
+        U: relativeURL,
+        R: createResolvePathFromModule(r),
+        __dirname: module.id.replace(/(^|\/)\/+$/, ""),
+      })
+    );
+  } catch (error) {
+    module.error = error as any;
+    throw error;
+  }
+
+  module.loaded = true;
+  if (module.namespaceObject && module.exports !== module.namespaceObject) {
+    // in case of a circular dependency: cjs1 -> esm2 -> cjs1
+    interopEsm(module.exports, module.namespaceObject);
+  }
+
+  return module;
+}
+
+/**
+ * Retrieves a module from the cache, or instantiate it if it is not cached.
+ */
+const getOrInstantiateModuleFromParent: GetOrInstantiateModuleFromParent = (
+  id,
+  sourceModule
+) => {
+  const module = moduleCache[id];
+
+  if (module) {
+    return module;
+  }
+
+  return instantiateModule(id, {
+    type: SourceType.Parent,
+    parentId: sourceModule.id,
+  });
+};
+
+function registerChunk([
+  chunkPath,
+  chunkModules,
+  runtimeParams,
+]: ChunkRegistration) {
+  for (const [moduleId, moduleFactory] of Object.entries(chunkModules)) {
+    if (!moduleFactories[moduleId]) {
+      moduleFactories[moduleId] = moduleFactory;
+    }
+  }
+
+  return BACKEND.registerChunk(chunkPath, runtimeParams);
+}
@@ -0,0 +1,12 @@
+/**
+ * Definitions for globals that are injected by the Turbopack runtime.
+ *
+ * These are available from every module, but should only be used by Turbopack
+ * code, not by user code.
+ */
+
+type ChunkRegistry = {
+  push: (registration: ChunkRegistration) => void;
+};
+
+declare var TURBOPACK: ChunkRegistry | ChunkRegistration[] | undefined;
@@ -0,0 +1,237 @@
+/**
+ * This file contains runtime types and functions that are shared between all
+ * Turbopack ECMAScript runtimes for the browser, both in development and in
+ * production.
+ *
+ * It will be appended to the runtime code of each runtime right after the
+ * shared runtime utils, and is followed by either the development or the
+ * production base.
+ */
+
+/* eslint-disable @typescript-eslint/no-unused-vars */
+
+/// <reference path="../../../shared/runtime-utils.ts" />
+/// <reference path="./globals.d.ts" />
+
+declare var CHUNK_BASE_PATH: string;
+
+interface TurbopackBrowserBaseContext extends TurbopackBaseContext {
+  R: ResolvePathFromModule;
+}
+
+interface TurbopackBrowserContext extends TurbopackBrowserBaseContext {}
+
+type ModuleFactory = (
+  this: Module["exports"],
+  context: TurbopackBrowserContext
+) => undefined;
+
+type RuntimeParams = {
+  otherChunks: ChunkData[];
+  runtimeModuleIds: ModuleId[];
+};
+
+type ChunkRegistration = [
+  chunkPath: ChunkPath,
+  chunkModules: ModuleFactories,
+  params: RuntimeParams | undefined
+];
+
+enum SourceType {
+  /**
+   * The module was instantiated because it was included in an evaluated chunk's
+   * runtime.
+   */
+  Runtime = 0,
+  /**
+   * The module was instantiated because a parent module imported it.
+   */
+  Parent = 1,
+  /**
+   * The module was instantiated because it was included in a chunk's hot module
+   * update. Only used by the development runtime.
+   */
+  Update = 2,
+}
+
+type SourceInfo =
+  | {
+      type: SourceType.Runtime;
+      chunkPath: ChunkPath;
+    }
+  | {
+      type: SourceType.Parent;
+      parentId: ModuleId;
+    }
+  | {
+      type: SourceType.Update;
+      parents?: ModuleId[];
+    };
+
+interface RuntimeBackend {
+  registerChunk: (chunkPath: ChunkPath, params?: RuntimeParams) => void;
+  loadChunk: (chunkPath: ChunkPath, source: SourceInfo) => Promise<void>;
+}
+
+const moduleFactories: ModuleFactories = Object.create(null);
+const moduleCache: ModuleCache = Object.create(null);
+
+const availableModules: Map<ModuleId, Promise<any> | true> = new Map();
+
+const availableModuleChunks: Map<ChunkPath, Promise<any> | true> = new Map();
+
+async function loadChunk(
+  source: SourceInfo,
+  chunkData: ChunkData
+): Promise<any> {
+  if (typeof chunkData === "string") {
+    return loadChunkPath(source, chunkData);
+  }
+
+  const includedList = chunkData.included || [];
+  const modulesPromises = includedList.map((included) => {
+    if (moduleFactories[included]) return true;
+    return availableModules.get(included);
+  });
+  if (modulesPromises.length > 0 && modulesPromises.every((p) => p)) {
+    // When all included items are already loaded or loading, we can skip loading ourselves
+    return Promise.all(modulesPromises);
+  }
+
+  const includedModuleChunksList = chunkData.moduleChunks || [];
+  const moduleChunksPromises = includedModuleChunksList
+    .map((included) => {
+      // TODO(alexkirsz) Do we need this check?
+      // if (moduleFactories[included]) return true;
+      return availableModuleChunks.get(included);
+    })
+    .filter((p) => p);
+
+  let promise;
+  if (moduleChunksPromises.length > 0) {
+    // Some module chunks are already loaded or loading.
+
+    if (moduleChunksPromises.length === includedModuleChunksList.length) {
+      // When all included module chunks are already loaded or loading, we can skip loading ourselves
+      return Promise.all(moduleChunksPromises);
+    }
+
+    const moduleChunksToLoad: Set<ChunkPath> = new Set();
+    for (const moduleChunk of includedModuleChunksList) {
+      if (!availableModuleChunks.has(moduleChunk)) {
+        moduleChunksToLoad.add(moduleChunk);
+      }
+    }
+
+    for (const moduleChunkToLoad of moduleChunksToLoad) {
+      const promise = loadChunkPath(source, moduleChunkToLoad);
+
+      availableModuleChunks.set(moduleChunkToLoad, promise);
+
+      moduleChunksPromises.push(promise);
+    }
+
+    promise = Promise.all(moduleChunksPromises);
+  } else {
+    promise = loadChunkPath(source, chunkData.path);
+
+    // Mark all included module chunks as loading if they are not already loaded or loading.
+    for (const includedModuleChunk of includedModuleChunksList) {
+      if (!availableModuleChunks.has(includedModuleChunk)) {
+        availableModuleChunks.set(includedModuleChunk, promise);
+      }
+    }
+  }
+
+  for (const included of includedList) {
+    if (!availableModules.has(included)) {
+      // It might be better to race old and new promises, but it's rare that the new promise will be faster than a request started earlier.
+      // In production it's even more rare, because the chunk optimization tries to deduplicate modules anyway.
+      availableModules.set(included, promise);
+    }
+  }
+
+  return promise;
+}
+
+async function loadChunkPath(
+  source: SourceInfo,
+  chunkPath: ChunkPath
+): Promise<any> {
+  try {
+    await BACKEND.loadChunk(chunkPath, source);
+  } catch (error) {
+    let loadReason;
+    switch (source.type) {
+      case SourceType.Runtime:
+        loadReason = `as a runtime dependency of chunk ${source.chunkPath}`;
+        break;
+      case SourceType.Parent:
+        loadReason = `from module ${source.parentId}`;
+        break;
+      case SourceType.Update:
+        loadReason = "from an HMR update";
+        break;
+      default:
+        invariant(source, (source) => `Unknown source type: ${source?.type}`);
+    }
+    throw new Error(
+      `Failed to load chunk ${chunkPath} ${loadReason}${
+        error ? `: ${error}` : ""
+      }`,
+      error
+        ? {
+            cause: error,
+          }
+        : undefined
+    );
+  }
+}
+
+/**
+ * Returns an absolute url to an asset.
+ */
+function createResolvePathFromModule(
+  resolver: (moduleId: string) => Exports
+): (moduleId: string) => string {
+  return function resolvePathFromModule(moduleId: string): string {
+    const exported = resolver(moduleId);
+    return exported?.default ?? exported;
+  };
+}
+
+/**
+ * no-op for browser
+ * @param modulePath
+ */
+function resolveAbsolutePath(modulePath?: string): string {
+  return `/ROOT/${modulePath ?? ""}`;
+}
+
+/**
+ * Gets or instantiates a runtime module.
+ */
+function getOrInstantiateRuntimeModule(
+  moduleId: ModuleId,
+  chunkPath: ChunkPath
+): Module {
+  const module = moduleCache[moduleId];
+  if (module) {
+    if (module.error) {
+      throw module.error;
+    }
+    return module;
+  }
+
+  return instantiateModule(moduleId, { type: SourceType.Runtime, chunkPath });
+}
+
+/**
+ * Returns the URL relative to the origin where a chunk can be fetched from.
+ */
+function getChunkRelativeUrl(chunkPath: ChunkPath): string {
+  return `${CHUNK_BASE_PATH}${chunkPath
+    .split("/")
+    .map((p) => encodeURIComponent(p))
+    .join("/")}`;
+}
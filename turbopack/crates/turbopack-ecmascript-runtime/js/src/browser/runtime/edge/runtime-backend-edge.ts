@@ -1,15 +1,16 @@
 /**
- * This file contains the runtime code specific to the Turbopack development
- * ECMAScript "None" runtime (e.g. for Edge).
+ * This file contains the runtime code specific to the Turbopack ECMAScript
+ * "None" runtime (e.g. for Edge).
  *
- * It will be appended to the base development runtime code.
+ * It will be appended to the base runtime code. The development runtime
+ * additionally appends `dev/runtime/edge/dev-backend-edge.ts`.
  */
 
 /* eslint-disable @typescript-eslint/no-unused-vars */
 
 /// <reference path="../base/runtime-base.ts" />
-/// <reference path="../../../../shared-node/base-externals-utils.ts" />
-/// <reference path="../../../../shared/require-type.d.ts" />
+/// <reference path="../../../shared-node/base-externals-utils.ts" />
+/// <reference path="../../../shared/require-type.d.ts" />
 
 type ChunkRunner = {
   requiredChunks: Set<ChunkPath>;
@@ -25,13 +26,15 @@ type ExternalRequire = (
 ) => Exports | EsmNamespaceObject;
 type ExternalImport = (id: ModuleId) => Promise<Exports | EsmNamespaceObject>;
 
-interface TurbopackDevContext extends TurbopackDevBaseContext {
+interface TurbopackBrowserContext {
   x: ExternalRequire;
   y: ExternalImport;
 }
 
-function augmentContext(context: TurbopackDevBaseContext): TurbopackDevContext {
-  const nodejsContext = context as TurbopackDevContext;
+function augmentContext(
+  context: TurbopackBrowserBaseContext
+): TurbopackBrowserContext {
+  const nodejsContext = context as TurbopackBrowserContext;
   nodejsContext.x = externalRequire;
   nodejsContext.y = externalImport;
   return nodejsContext;
@@ -44,7 +47,9 @@ async function loadWebAssembly(
 ): Promise<Exports> {
   const module = await loadWebAssemblyModule(source, chunkPath);
 
-  return await WebAssembly.instantiate(module, imports);
+  const instance = await WebAssembly.instantiate(module, imports);
+
+  return instance.exports;
 }
 
 function getFileStem(path: string): string {
@@ -121,10 +126,6 @@ async function loadWebAssemblyModule(
     loadChunk(_chunkPath, _fromChunkPath) {
       throw new Error("chunk loading is not supported");
     },
-
-    restart: () => {
-      throw new Error("restart is not supported");
-    },
   };
 
   const registeredChunks: Set<ChunkPath> = new Set();
@@ -198,7 +199,3 @@ async function loadWebAssemblyModule(
     }
   }
 })();
-
-function _eval(_: EcmascriptModuleEntry): ModuleFactory {
-  throw new Error("HMR evaluation is not implemented on this backend");
-}
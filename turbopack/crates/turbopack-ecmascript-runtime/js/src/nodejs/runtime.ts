@@ -38,6 +38,11 @@ function stringifySourceInfo(source: SourceInfo): string {
   }
 }
 
+interface Module {
+  children: ModuleId[];
+  parents: ModuleId[];
+}
+
 type ExternalRequire = (id: ModuleId) => Exports | EsmNamespaceObject;
 type ExternalImport = (id: ModuleId) => Promise<Exports | EsmNamespaceObject>;
 
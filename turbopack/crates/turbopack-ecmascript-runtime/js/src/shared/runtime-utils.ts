@@ -24,8 +24,6 @@ interface BaseModule {
   error: Error | undefined;
   loaded: boolean;
   id: ModuleId;
-  children: ModuleId[];
-  parents: ModuleId[];
   namespaceObject?:
     | EsmNamespaceObject
     | Promise<EsmNamespaceObject>
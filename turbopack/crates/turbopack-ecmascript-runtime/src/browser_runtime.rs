@@ -10,7 +10,7 @@ use turbopack_core::{
 };
 use turbopack_ecmascript::utils::StringifyJs;
 
-use crate::{asset_context::get_runtime_asset_context, embed_js::embed_static_code};
+use crate::{asset_context::get_runtime_asset_context, embed_js::embed_static_code, RuntimeType};
 
 /// Returns the code for the development ECMAScript runtime.
 #[turbo_tasks::function]
@@ -18,15 +18,61 @@ pub async fn get_browser_runtime_code(
     environment: Vc<Environment>,
     chunk_base_path: Vc<Option<RcStr>>,
     output_root: Vc<RcStr>,
+) -> Result<Vc<Code>> {
+    browser_runtime_code(
+        environment,
+        chunk_base_path,
+        output_root,
+        RuntimeType::Development,
+    )
+    .await
+}
+
+/// Returns the code for the production ECMAScript runtime. It only supports
+/// registering chunks, loading async chunks and evaluating modules, and
+/// contains no hot module replacement code.
+#[turbo_tasks::function]
+pub async fn get_browser_production_runtime_code(
+    environment: Vc<Environment>,
+    chunk_base_path: Vc<Option<RcStr>>,
+    output_root: Vc<RcStr>,
+) -> Result<Vc<Code>> {
+    browser_runtime_code(
+        environment,
+        chunk_base_path,
+        output_root,
+        RuntimeType::Production,
+    )
+    .await
+}
+
+/// Assembles the runtime from the shared sources in `browser/runtime`, followed
+/// by the development or production specific sources. Hot module replacement is
+/// only included in the development runtime.
+async fn browser_runtime_code(
+    environment: Vc<Environment>,
+    chunk_base_path: Vc<Option<RcStr>>,
+    output_root: Vc<RcStr>,
+    runtime_type: RuntimeType,
 ) -> Result<Vc<Code>> {
     let asset_context = get_runtime_asset_context(environment);
 
     let shared_runtime_utils_code =
         embed_static_code(asset_context, "shared/runtime-utils.ts".into());
-    let runtime_base_code = embed_static_code(
-        asset_context,
-        "browser/dev/runtime/base/runtime-base.ts".into(),
-    );
+
+    let mut runtime_base_code = vec!["browser/runtime/base/runtime-base.ts"];
+    match runtime_type {
+        RuntimeType::Development => {
+            runtime_base_code.push("browser/dev/runtime/base/runtime-base.ts");
+        }
+        RuntimeType::Production => {
+            runtime_base_code.push("browser/production/runtime/base/runtime-base.ts");
+        }
+        #[cfg(feature = "test")]
+        RuntimeType::Dummy => {
+            panic!("This configuration is not supported in the browser runtime")
+        }
+    }
 
     let chunk_loading = &*asset_context
         .compile_time_info()
@@ -34,17 +80,25 @@ pub async fn get_browser_runtime_code(
         .chunk_loading()
         .await?;
 
-    let runtime_backend_code = embed_static_code(
-        asset_context,
-        match chunk_loading {
-            ChunkLoading::Edge => "browser/dev/runtime/edge/runtime-backend-edge.ts".into(),
-            // This case should never be hit.
-            ChunkLoading::NodeJs => {
-                panic!("Node.js runtime is not supported in the browser runtime!")
+    let mut runtime_backend_code = vec![];
+    match chunk_loading {
+        ChunkLoading::Edge => {
+            runtime_backend_code.push("browser/runtime/edge/runtime-backend-edge.ts");
+            if runtime_type == RuntimeType::Development {
+                runtime_backend_code.push("browser/dev/runtime/edge/dev-backend-edge.ts");
             }
-            ChunkLoading::Dom => "browser/dev/runtime/dom/runtime-backend-dom.ts".into(),
-        },
-    );
+        }
+        // This case should never be hit.
+        ChunkLoading::NodeJs => {
+            panic!("Node.js runtime is not supported in the browser runtime!")
+        }
+        ChunkLoading::Dom => {
+            runtime_backend_code.push("browser/runtime/dom/runtime-backend-dom.ts");
+            if runtime_type == RuntimeType::Development {
+                runtime_backend_code.push("browser/dev/runtime/dom/dev-backend-dom.ts");
+            }
+        }
+    }
 
     let mut code: CodeBuilder = CodeBuilder::default();
     let output_root = output_root.await?.to_string();
@@ -69,7 +123,9 @@ pub async fn get_browser_runtime_code(
     )?;
 
     code.push_code(&*shared_runtime_utils_code.await?);
-    code.push_code(&*runtime_base_code.await?);
+    for runtime_code in runtime_base_code {
+        code.push_code(&*embed_static_code(asset_context, runtime_code.into()).await?);
+    }
 
     if *environment.supports_commonjs_externals().await? {
         code.push_code(
@@ -89,7 +145,9 @@ pub async fn get_browser_runtime_code(
         );
     }
 
-    code.push_code(&*runtime_backend_code.await?);
+    for backend_code in runtime_backend_code {
+        code.push_code(&*embed_static_code(asset_context, backend_code.into()).await?);
+    }
 
     // Registering chunks depends on the BACKEND variable, which is set by the
     // specific runtime code, hence it must be appended after it.
@@ -8,7 +8,7 @@ pub(crate) mod embed_js;
 pub(crate) mod nodejs_runtime;
 pub(crate) mod runtime_type;
 
-pub use browser_runtime::get_browser_runtime_code;
+pub use browser_runtime::{get_browser_production_runtime_code, get_browser_runtime_code};
 #[cfg(feature = "test")]
 pub use dummy_runtime::get_dummy_runtime_code;
 pub use embed_js::{embed_file, embed_file_path, embed_fs};
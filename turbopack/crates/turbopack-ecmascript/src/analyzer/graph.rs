@@ -15,9 +15,13 @@ use swc_core::{
 use turbo_tasks::{RcStr, Vc};
 use turbopack_core::source::Source;
 
-use super::{ConstantNumber, ConstantValue, ImportMap, JsValue, ObjectPart, WellKnownFunctionKind};
+use super::{
+    ConstantNumber, ConstantValue, ImportMap, JsValue, ObjectPart, WellKnownFunctionKind,
+    WellKnownObjectKind,
+};
 use crate::{
-    analyzer::is_unresolved,
+    analyzer::{imports::static_member_prop, is_unresolved},
+    references::worker::WorkerKind,
     utils::{unparen, AstPathRange},
 };
 
@@ -165,6 +169,16 @@ pub enum Effect {
         span: Span,
         in_try: bool,
     },
+    /// A property read with a static name on an imported namespace object,
+    /// e.g. `ns.a` for `import * as ns from "..."`. Only emitted when the
+    /// namespace object is not used in any other way.
+    ImportedNamespaceMember {
+        esm_reference_index: usize,
+        export: RcStr,
+        ast_path: Vec<AstParentKind>,
+        span: Span,
+        in_try: bool,
+    },
     /// A reference to a free var access.
     FreeVar {
         var: JsValue,
@@ -179,6 +193,12 @@ pub enum Effect {
         span: Span,
         in_try: bool,
     },
+    /// A reference to `module.hot` or `import.meta.hot`.
+    ModuleHot {
+        ast_path: Vec<AstParentKind>,
+        span: Span,
+        in_try: bool,
+    },
     /// A reference to `new URL(..., import.meta.url)`.
     Url {
         input: JsValue,
@@ -186,6 +206,22 @@ pub enum Effect {
         span: Span,
         in_try: bool,
     },
+    /// A reference to `import.meta.glob(...)`.
+    ImportMetaGlob {
+        args: Vec<EffectArg>,
+        ast_path: Vec<AstParentKind>,
+        span: Span,
+        in_try: bool,
+    },
+    /// A reference to `new Worker(new URL(..., import.meta.url))` or
+    /// `new SharedWorker(new URL(..., import.meta.url))`.
+    Worker {
+        input: JsValue,
+        kind: WorkerKind,
+        ast_path: Vec<AstParentKind>,
+        span: Span,
+        in_try: bool,
+    },
     /// Unreachable code, e.g. after a `return` statement.
     Unreachable { start_ast_path: Vec<AstParentKind> },
 }
@@ -223,10 +259,20 @@ impl Effect {
                 var.normalize();
             }
             Effect::ImportedBinding { .. } => {}
+            Effect::ImportedNamespaceMember { .. } => {}
             Effect::ImportMeta { .. } => {}
+            Effect::ModuleHot { .. } => {}
             Effect::Url { input, .. } => {
                 input.normalize();
             }
+            Effect::ImportMetaGlob { args, .. } => {
+                for arg in args.iter_mut() {
+                    arg.normalize();
+                }
+            }
+            Effect::Worker { input, .. } => {
+                input.normalize();
+            }
             Effect::Unreachable { .. } => {}
         }
     }
@@ -269,6 +315,7 @@ pub fn create_graph(m: &Program, eval_context: &EvalContext) -> VarGraph {
             current_value: Default::default(),
             cur_fn_return_values: Default::default(),
             cur_fn_ident: Default::default(),
+            worker_url_span: Default::default(),
         },
         &mut Default::default(),
     );
@@ -348,6 +395,40 @@ impl EvalContext {
         JsValue::concat(values)
     }
 
+    /// Evaluates the arguments of a call. Spreads of array literals are
+    /// flattened into the argument list. When a spread value is not a known
+    /// array, all arguments are returned as a single array value instead,
+    /// like they would be passed to `Reflect.apply`.
+    fn eval_args(&self, args: &[ExprOrSpread]) -> Result<Vec<JsValue>, JsValue> {
+        let mut items = vec![];
+        // Arrays that are concatenated to `items` once an unknown spread occurs. The arrays
+        // created here are frozen, since the argument list can't be modified.
+        let mut rest = vec![];
+        for arg in args {
+            let value = self.eval(&arg.expr);
+            match (arg.spread.is_some(), value) {
+                (true, JsValue::Array { items: inner, .. }) if rest.is_empty() => {
+                    items.extend(inner)
+                }
+                (true, value) => rest.push(value),
+                (false, value) if rest.is_empty() => items.push(value),
+                (false, value) => rest.push(JsValue::frozen_array(vec![value])),
+            }
+        }
+
+        if rest.is_empty() {
+            Ok(items)
+        } else if items.is_empty() && rest.len() == 1 {
+            Err(rest.pop().unwrap())
+        } else {
+            Err(JsValue::member_call(
+                Box::new(JsValue::frozen_array(items)),
+                Box::new("concat".into()),
+                rest,
+            ))
+        }
+    }
+
     fn eval_ident(&self, i: &Ident) -> JsValue {
         let id = i.to_id();
         if let Some(imported) = self.imports.get_import(&id) {
@@ -488,15 +569,29 @@ impl EvalContext {
                     }),
                 tpl,
                 ..
-            }) => {
-                if &*tag_obj.sym == "String"
-                    && &*tag_prop.sym == "raw"
-                    && is_unresolved(tag_obj, self.unresolved_mark)
-                {
-                    self.eval_tpl(tpl, true)
-                } else {
-                    JsValue::unknown_empty(true, "tagged template literal is not supported yet")
-                }
+            }) if &*tag_obj.sym == "String"
+                && &*tag_prop.sym == "raw"
+                && is_unresolved(tag_obj, self.unresolved_mark) =>
+            {
+                self.eval_tpl(tpl, true)
+            }
+
+            // A tagged template calls the tag function with the array of cooked strings,
+            // followed by the interpolated values
+            Expr::TaggedTpl(TaggedTpl { tag, tpl, .. }) => {
+                let strings = tpl
+                    .quasis
+                    .iter()
+                    .map(|quasi| match &quasi.cooked {
+                        Some(cooked) => JsValue::from(cooked.clone()),
+                        // Invalid escape sequences are `undefined` in the cooked strings
+                        None => JsValue::Constant(ConstantValue::Undefined),
+                    })
+                    .collect();
+                let args = iter::once(JsValue::array(strings))
+                    .chain(tpl.exprs.iter().map(|e| self.eval(e)))
+                    .collect();
+                JsValue::call(Box::new(self.eval(tag)), args)
             }
 
             Expr::Fn(expr) => {
@@ -532,6 +627,10 @@ impl EvalContext {
                 last
             }
 
+            Expr::Member(member_expr) if is_module_hot(member_expr, self.unresolved_mark) => {
+                JsValue::WellKnownObject(WellKnownObjectKind::ModuleHot)
+            }
+
             Expr::Member(MemberExpr {
                 obj,
                 prop: MemberProp::Ident(prop),
@@ -551,20 +650,26 @@ impl EvalContext {
                 JsValue::member(Box::new(obj), Box::new(prop))
             }
 
+            // Private fields can't be accessed from outside of the class, so accessing them is
+            // opaque. They might be private getters, or throw for objects without the field.
+            Expr::Member(MemberExpr {
+                prop: MemberProp::PrivateName(_),
+                ..
+            }) => JsValue::unknown_empty(true, "private field access"),
+
+            // `#field in obj`
+            Expr::Bin(BinExpr {
+                op: op!("in"),
+                left: box Expr::PrivateName(_),
+                ..
+            }) => JsValue::unknown_empty(false, "private field check"),
+
             Expr::Call(CallExpr {
                 callee: Callee::Expr(box callee),
                 args,
                 ..
             }) => {
-                // We currently do not handle spreads.
-                if args.iter().any(|arg| arg.spread.is_some()) {
-                    return JsValue::unknown_empty(
-                        true,
-                        "spread in function calls is not supported",
-                    );
-                }
-
-                let args = args.iter().map(|arg| self.eval(&arg.expr)).collect();
+                let args = self.eval_args(args);
                 if let Expr::Member(MemberExpr { obj, prop, .. }) = unparen(callee) {
                     let obj = Box::new(self.eval(obj));
                     let prop = Box::new(match prop {
@@ -578,11 +683,29 @@ impl EvalContext {
                         }
                         MemberProp::Computed(ComputedPropName { expr, .. }) => self.eval(expr),
                     });
-                    JsValue::member_call(obj, prop, args)
+                    match args {
+                        Ok(args) => JsValue::member_call(obj, prop, args),
+                        // `obj.prop(...args)` is `Reflect.apply(obj.prop, obj, args)`
+                        Err(args) => JsValue::call(
+                            Box::new(JsValue::WellKnownFunction(
+                                WellKnownFunctionKind::ReflectApply,
+                            )),
+                            vec![JsValue::member(obj.clone(), prop), *obj, args],
+                        ),
+                    }
                 } else {
                     let callee = Box::new(self.eval(callee));
 
-                    JsValue::call(callee, args)
+                    match args {
+                        Ok(args) => JsValue::call(callee, args),
+                        // `func(...args)` is `Reflect.apply(func, undefined, args)`
+                        Err(args) => JsValue::call(
+                            Box::new(JsValue::WellKnownFunction(
+                                WellKnownFunctionKind::ReflectApply,
+                            )),
+                            vec![*callee, JsValue::Constant(ConstantValue::Undefined), args],
+                        ),
+                    }
                 }
             }
 
@@ -591,15 +714,12 @@ impl EvalContext {
                 args,
                 ..
             }) => {
-                // We currently do not handle spreads.
-                if args.iter().any(|arg| arg.spread.is_some()) {
+                let Ok(args) = self.eval_args(args) else {
                     return JsValue::unknown_empty(
                         true,
                         "spread in function calls is not supported",
                     );
-                }
-
-                let args = args.iter().map(|arg| self.eval(&arg.expr)).collect();
+                };
 
                 JsValue::super_call(args)
             }
@@ -609,11 +729,9 @@ impl EvalContext {
                 args,
                 ..
             }) => {
-                // We currently do not handle spreads.
-                if args.iter().any(|arg| arg.spread.is_some()) {
+                let Ok(args) = self.eval_args(args) else {
                     return JsValue::unknown_empty(true, "spread in import() is not supported");
-                }
-                let args = args.iter().map(|arg| self.eval(&arg.expr)).collect();
+                };
 
                 let callee = Box::new(JsValue::FreeVar(js_word!("import")));
 
@@ -708,6 +826,71 @@ struct Analyzer<'a> {
     cur_fn_return_values: Option<Vec<JsValue>>,
 
     cur_fn_ident: u32,
+
+    /// The span of the `new URL(..., import.meta.url)` expression passed to
+    /// the worker constructor that is currently visited. This URL is handled
+    /// by the worker effect and must not produce a URL effect.
+    worker_url_span: Option<Span>,
+}
+
+/// Returns the input of a `new URL(input, import.meta.url)` expression.
+fn import_meta_url_input(new_expr: &NewExpr, unresolved_mark: Mark) -> Option<&Expr> {
+    let box Expr::Ident(callee) = &new_expr.callee else {
+        return None;
+    };
+    if &*callee.sym != "URL" || !is_unresolved(callee, unresolved_mark) {
+        return None;
+    }
+    let [input, base] = new_expr.args.as_deref()? else {
+        return None;
+    };
+    let Expr::Member(MemberExpr {
+        obj:
+            box Expr::MetaProp(MetaPropExpr {
+                kind: MetaPropKind::ImportMeta,
+                ..
+            }),
+        prop: MemberProp::Ident(prop),
+        ..
+    }) = &*base.expr
+    else {
+        return None;
+    };
+    (&*prop.sym == "url").then_some(&*input.expr)
+}
+
+/// Returns true when `member_expr` is `module.hot` or `import.meta.hot`.
+fn is_module_hot(member_expr: &MemberExpr, unresolved_mark: Mark) -> bool {
+    let MemberProp::Ident(prop) = &member_expr.prop else {
+        return false;
+    };
+    if &*prop.sym != "hot" {
+        return false;
+    }
+    match &*member_expr.obj {
+        Expr::MetaProp(MetaPropExpr {
+            kind: MetaPropKind::ImportMeta,
+            ..
+        }) => true,
+        Expr::Ident(obj) => &*obj.sym == "module" && is_unresolved(obj, unresolved_mark),
+        _ => false,
+    }
+}
+
+/// Returns the kind of worker when `new_expr` calls the global `Worker` or
+/// `SharedWorker` constructor.
+fn worker_kind(new_expr: &NewExpr, unresolved_mark: Mark) -> Option<WorkerKind> {
+    let box Expr::Ident(callee) = &new_expr.callee else {
+        return None;
+    };
+    if !is_unresolved(callee, unresolved_mark) {
+        return None;
+    }
+    match &*callee.sym {
+        "Worker" => Some(WorkerKind::Worker),
+        "SharedWorker" => Some(WorkerKind::SharedWorker),
+        _ => None,
+    }
 }
 
 pub fn as_parent_path(ast_path: &AstNodePath<AstParentNodeRef<'_>>) -> Vec<AstParentKind> {
@@ -725,6 +908,21 @@ pub fn as_parent_path_with(
         .collect()
 }
 
+/// Whether `callee` is `import.meta.glob`.
+fn is_import_meta_glob(callee: &Expr) -> bool {
+    matches!(
+        unparen(callee),
+        Expr::Member(MemberExpr {
+            obj: box Expr::MetaProp(MetaPropExpr {
+                kind: MetaPropKind::ImportMeta,
+                ..
+            }),
+            prop: MemberProp::Ident(prop),
+            ..
+        }) if &*prop.sym == "glob"
+    )
+}
+
 pub fn is_in_try(ast_path: &AstNodePath<AstParentNodeRef<'_>>) -> bool {
     ast_path
         .iter()
@@ -738,6 +936,22 @@ pub fn is_in_try(ast_path: &AstNodePath<AstParentNodeRef<'_>>) -> bool {
         .unwrap_or(false)
 }
 
+/// Returns whether the node at `ast_path` is inside of a function body. Such
+/// code is not executed when the module is evaluated, but only when the
+/// function is called.
+pub fn is_in_function(ast_path: &[AstParentKind]) -> bool {
+    ast_path.iter().any(|kind| {
+        matches!(
+            kind,
+            AstParentKind::Function(FunctionField::Body)
+                | AstParentKind::ArrowExpr(ArrowExprField::Body)
+                | AstParentKind::Constructor(ConstructorField::Body)
+                | AstParentKind::GetterProp(GetterPropField::Body)
+                | AstParentKind::SetterProp(SetterPropField::Body)
+        )
+    })
+}
+
 impl Analyzer<'_> {
     fn add_value(&mut self, id: Id, value: JsValue) {
         if let Some(prev) = self.data.values.get_mut(&id) {
@@ -977,7 +1191,14 @@ impl Analyzer<'_> {
                 });
             }
             Callee::Expr(box expr) => {
-                if let Expr::Member(MemberExpr { obj, prop, .. }) = unparen(expr) {
+                if is_import_meta_glob(expr) {
+                    self.add_effect(Effect::ImportMetaGlob {
+                        args,
+                        ast_path: as_parent_path(ast_path),
+                        span: n.span(),
+                        in_try: is_in_try(ast_path),
+                    });
+                } else if let Expr::Member(MemberExpr { obj, prop, .. }) = unparen(expr) {
                     let obj_value = self.eval_context.eval(obj);
                     let prop_value = match prop {
                         // TODO avoid clone
@@ -1304,32 +1525,41 @@ impl VisitAstPath for Analyzer<'_> {
         new_expr: &'ast NewExpr,
         ast_path: &mut AstNodePath<AstParentNodeRef<'r>>,
     ) {
+        let unresolved_mark = self.eval_context.unresolved_mark;
+        // new Worker(new URL("path", import.meta.url), options)
+        if let Some(kind) = worker_kind(new_expr, unresolved_mark) {
+            if let Some((url_expr, input)) = new_expr
+                .args
+                .as_ref()
+                .and_then(|args| args.first())
+                .and_then(|arg| match &*arg.expr {
+                    Expr::New(url_expr) => import_meta_url_input(url_expr, unresolved_mark)
+                        .map(|input| (url_expr, input)),
+                    _ => None,
+                })
+            {
+                self.add_effect(Effect::Worker {
+                    input: self.eval_context.eval(input),
+                    kind,
+                    ast_path: as_parent_path(ast_path),
+                    span: new_expr.span(),
+                    in_try: is_in_try(ast_path),
+                });
+                self.worker_url_span = Some(url_expr.span());
+            }
+        }
         // new URL("path", import.meta.url)
-        if let box Expr::Ident(ref callee) = &new_expr.callee {
-            if &*callee.sym == "URL" && is_unresolved(callee, self.eval_context.unresolved_mark) {
-                if let Some(args) = &new_expr.args {
-                    if args.len() == 2 {
-                        if let Expr::Member(MemberExpr {
-                            obj:
-                                box Expr::MetaProp(MetaPropExpr {
-                                    kind: MetaPropKind::ImportMeta,
-                                    ..
-                                }),
-                            prop: MemberProp::Ident(prop),
-                            ..
-                        }) = &*args[1].expr
-                        {
-                            if &*prop.sym == "url" {
-                                self.add_effect(Effect::Url {
-                                    input: self.eval_context.eval(&args[0].expr),
-                                    ast_path: as_parent_path(ast_path),
-                                    span: new_expr.span(),
-                                    in_try: is_in_try(ast_path),
-                                });
-                            }
-                        }
-                    }
-                }
+        if let Some(input) = import_meta_url_input(new_expr, unresolved_mark) {
+            // The URL of a worker is handled by the worker effect above.
+            if self.worker_url_span == Some(new_expr.span()) {
+                self.worker_url_span = None;
+            } else {
+                self.add_effect(Effect::Url {
+                    input: self.eval_context.eval(input),
+                    ast_path: as_parent_path(ast_path),
+                    span: new_expr.span(),
+                    in_try: is_in_try(ast_path),
+                });
             }
         }
         new_expr.visit_children_with_path(self, ast_path);
@@ -1340,7 +1570,36 @@ impl VisitAstPath for Analyzer<'_> {
         member_expr: &'ast MemberExpr,
         ast_path: &mut AstNodePath<AstParentNodeRef<'r>>,
     ) {
+        if is_module_hot(member_expr, self.eval_context.unresolved_mark) {
+            // The object (`module` or `import.meta`) isn't accessed on its own, so
+            // its children are not visited.
+            self.add_effect(Effect::ModuleHot {
+                ast_path: as_parent_path(ast_path),
+                span: member_expr.span(),
+                in_try: is_in_try(ast_path),
+            });
+            return;
+        }
         self.check_member_expr_for_effects(member_expr, ast_path);
+        if let Expr::Ident(obj) = &*member_expr.obj {
+            if let (Some(esm_reference_index), Some(export)) = (
+                self.eval_context
+                    .imports
+                    .get_static_namespace_import(&obj.to_id()),
+                static_member_prop(&member_expr.prop),
+            ) {
+                // The namespace object itself is not needed, only the accessed
+                // export, so the children are not visited.
+                self.add_effect(Effect::ImportedNamespaceMember {
+                    esm_reference_index,
+                    export: export.as_str().into(),
+                    ast_path: as_parent_path(ast_path),
+                    span: member_expr.span(),
+                    in_try: is_in_try(ast_path),
+                });
+                return;
+            }
+        }
         member_expr.visit_children_with_path(self, ast_path);
     }
 
@@ -1,4 +1,7 @@
-use std::{collections::BTreeMap, fmt::Display};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Display,
+};
 
 use indexmap::{IndexMap, IndexSet};
 use once_cell::sync::Lazy;
@@ -13,7 +16,7 @@ use swc_core::{
 use turbo_tasks::{RcStr, Vc};
 use turbopack_core::{issue::IssueSource, source::Source};
 
-use super::{top_level_await::has_top_level_await, JsValue, ModuleValue};
+use super::{top_level_await::has_top_level_await, JsValue, ModuleReferenceKind, ModuleValue};
 use crate::tree_shake::{find_turbopack_part_id_in_asserts, PartId};
 
 #[turbo_tasks::value(serialization = "auto_for_input")]
@@ -32,7 +35,8 @@ static ANNOTATION_TRANSITION: Lazy<JsWord> =
 static ANNOTATION_CHUNKING_TYPE: Lazy<JsWord> =
     Lazy::new(|| crate::annotations::ANNOTATION_CHUNKING_TYPE.into());
 
-/// Changes the type of the resolved module (only "json" is supported currently)
+/// Changes the type of the resolved module ("json", "text" and "bytes" are
+/// supported)
 static ATTRIBUTE_MODULE_TYPE: Lazy<JsWord> = Lazy::new(|| "type".into());
 
 impl ImportAnnotations {
@@ -122,6 +126,15 @@ pub(crate) struct ImportMap {
     /// Map from identifier to index in references
     namespace_imports: IndexMap<Id, usize>,
 
+    /// Namespace imports which are only used to read properties with static
+    /// names, e.g. `ns.a` or `ns["a"]`
+    static_namespace_imports: HashSet<Id>,
+
+    /// Map from index in references to the properties read with static names
+    /// from the default import, when all default imports of the reference are
+    /// only used that way, e.g. `styles.a` for `import styles from "..."`
+    static_default_imports: IndexMap<usize, IndexSet<JsWord>>,
+
     /// List of (index in references, imported symbol, exported symbol)
     reexports: Vec<(usize, Reexport)>,
 
@@ -165,6 +178,7 @@ impl ImportMap {
             return Some(JsValue::member(
                 Box::new(JsValue::Module(ModuleValue {
                     module: r.module_path.clone(),
+                    kind: ModuleReferenceKind::Import,
                     annotations: r.annotations.clone(),
                 })),
                 Box::new(i_sym.clone().into()),
@@ -174,6 +188,7 @@ impl ImportMap {
             let r = &self.references[*i];
             return Some(JsValue::Module(ModuleValue {
                 module: r.module_path.clone(),
+                kind: ModuleReferenceKind::Import,
                 annotations: r.annotations.clone(),
             }));
         }
@@ -191,10 +206,34 @@ impl ImportMap {
         None
     }
 
+    /// Returns the index in references of the namespace import `id` if the
+    /// namespace object is only used to read properties with static names.
+    pub fn get_static_namespace_import(&self, id: &Id) -> Option<usize> {
+        if !self.static_namespace_imports.contains(id) {
+            return None;
+        }
+        self.namespace_imports.get(id).copied()
+    }
+
+    /// Returns the properties read from the default import of the reference
+    /// at `index` if the default import is only used to read properties with
+    /// static names.
+    pub fn get_static_default_import(&self, index: usize) -> Option<&IndexSet<JsWord>> {
+        self.static_default_imports.get(&index)
+    }
+
+    pub fn static_default_imports(&self) -> impl Iterator<Item = (usize, &IndexSet<JsWord>)> {
+        self.static_default_imports.iter().map(|(i, m)| (*i, m))
+    }
+
     pub fn references(&self) -> impl Iterator<Item = &ImportMapReference> {
         self.references.iter()
     }
 
+    pub fn reference(&self, index: usize) -> Option<&ImportMapReference> {
+        self.references.get_index(index)
+    }
+
     pub fn reexports(&self) -> impl Iterator<Item = (usize, &Reexport)> {
         self.reexports.iter().map(|(i, r)| (*i, r))
     }
@@ -208,10 +247,171 @@ impl ImportMap {
             source,
         });
 
+        let default_imports = data
+            .imports
+            .iter()
+            .filter(|(_, (_, sym))| *sym == js_word!("default"))
+            .map(|(id, (i, _))| (id.clone(), *i))
+            .collect::<HashMap<_, _>>();
+        if !data.namespace_imports.is_empty() || !default_imports.is_empty() {
+            // Namespace and default imports are analyzed in a single pass over
+            // the module.
+            let objects = data
+                .namespace_imports
+                .keys()
+                .chain(default_imports.keys())
+                .cloned()
+                .collect();
+            let mut static_members = find_static_member_reads(m, objects);
+
+            data.static_namespace_imports = data
+                .namespace_imports
+                .keys()
+                .filter(|id| static_members.contains_key(*id))
+                .cloned()
+                .collect();
+
+            let mut static_default_imports = IndexMap::<usize, IndexSet<JsWord>>::new();
+            let mut dynamic = HashSet::new();
+            for (id, i) in default_imports {
+                match static_members.remove(&id) {
+                    Some(members) => static_default_imports.entry(i).or_default().extend(members),
+                    None => {
+                        dynamic.insert(i);
+                    }
+                }
+            }
+            static_default_imports.retain(|i, _| !dynamic.contains(i));
+            static_default_imports.sort_keys();
+            data.static_default_imports = static_default_imports;
+        }
+
         data
     }
 }
 
+/// Returns the property name of a member expression when it's statically
+/// known, e.g. `a` for `ns.a` and `ns["a"]`.
+pub(crate) fn static_member_prop(prop: &MemberProp) -> Option<JsWord> {
+    match prop {
+        MemberProp::Ident(ident) => Some(ident.sym.clone()),
+        MemberProp::Computed(ComputedPropName {
+            expr: box Expr::Lit(Lit::Str(str)),
+            ..
+        }) => Some(str.value.clone()),
+        _ => None,
+    }
+}
+
+/// Returns those of the `namespaces` which are only used to read properties
+/// with static names. Any other usage, e.g. passing the namespace object to a
+/// function or re-exporting it, requires the whole namespace object.
+pub(crate) fn find_static_namespace_imports<N>(n: &N, namespaces: HashSet<Id>) -> HashSet<Id>
+where
+    N: VisitWith<NamespaceUsageVisitor>,
+{
+    find_static_member_reads(n, namespaces)
+        .into_keys()
+        .collect()
+}
+
+/// Like [find_static_namespace_imports], but also returns the names of the
+/// properties read from each of the objects.
+pub(crate) fn find_static_member_reads<N>(
+    n: &N,
+    namespaces: HashSet<Id>,
+) -> HashMap<Id, IndexSet<JsWord>>
+where
+    N: VisitWith<NamespaceUsageVisitor>,
+{
+    let mut visitor = NamespaceUsageVisitor {
+        namespaces,
+        escaping: Default::default(),
+        members: Default::default(),
+    };
+    n.visit_with(&mut visitor);
+    let NamespaceUsageVisitor {
+        namespaces,
+        escaping,
+        mut members,
+    } = visitor;
+    namespaces
+        .into_iter()
+        .filter(|id| !escaping.contains(id))
+        .map(|id| {
+            let members = members.remove(&id).unwrap_or_default();
+            (id, members)
+        })
+        .collect()
+}
+
+pub(crate) struct NamespaceUsageVisitor {
+    namespaces: HashSet<Id>,
+    escaping: HashSet<Id>,
+    members: HashMap<Id, IndexSet<JsWord>>,
+}
+
+impl NamespaceUsageVisitor {
+    fn is_namespace(&self, expr: &Expr) -> Option<Id> {
+        let Expr::Ident(ident) = expr else {
+            return None;
+        };
+        let id = ident.to_id();
+        self.namespaces.contains(&id).then_some(id)
+    }
+}
+
+impl Visit for NamespaceUsageVisitor {
+    // The bindings themselves are not usages
+    fn visit_import_decl(&mut self, _: &ImportDecl) {}
+
+    fn visit_member_expr(&mut self, n: &MemberExpr) {
+        if let (Some(id), Some(prop)) = (self.is_namespace(&n.obj), static_member_prop(&n.prop)) {
+            self.members.entry(id).or_default().insert(prop);
+            return;
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_simple_assign_target(&mut self, n: &SimpleAssignTarget) {
+        // Writing to the namespace object is not a plain read
+        if let SimpleAssignTarget::Member(member) = n {
+            if let Some(id) = self.is_namespace(&member.obj) {
+                self.escaping.insert(id);
+            }
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_unary_expr(&mut self, n: &UnaryExpr) {
+        // `delete ns.x` is not a plain read either
+        if n.op == UnaryOp::Delete {
+            if let Expr::Member(member) = &*n.arg {
+                if let Some(id) = self.is_namespace(&member.obj) {
+                    self.escaping.insert(id);
+                }
+            }
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_opt_chain_base(&mut self, n: &OptChainBase) {
+        if let OptChainBase::Member(member) = n {
+            if let Some(id) = self.is_namespace(&member.obj) {
+                self.escaping.insert(id);
+            }
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_ident(&mut self, n: &Ident) {
+        let id = n.to_id();
+        if self.namespaces.contains(&id) {
+            self.escaping.insert(id);
+        }
+    }
+}
+
 struct Analyzer<'a> {
     data: &'a mut ImportMap,
     source: Option<Vc<Box<dyn Source>>>,
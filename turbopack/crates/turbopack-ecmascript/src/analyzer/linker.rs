@@ -30,13 +30,16 @@ where
 const LIMIT_NODE_SIZE: usize = 300;
 const LIMIT_IN_PROGRESS_NODES: usize = 1000;
 const LIMIT_LINK_STEPS: usize = 1500;
+/// How deep a recursive function call with constant arguments is inlined
+/// before it's treated as unknown. Other recursive calls are never inlined.
+const LIMIT_RECURSIVE_CALL_DEPTH: usize = 3;
 
 pub(crate) async fn link_internal_iterative<'a, B, RB, F, RF>(
     graph: &'a VarGraph,
     val: JsValue,
     early_visitor: &'a B,
     visitor: &'a F,
-    mut fun_args_values: HashMap<u32, Vec<JsValue>>,
+    fun_args_values: HashMap<u32, Vec<JsValue>>,
 ) -> Result<JsValue>
 where
     RB: 'a + Future<Output = Result<(JsValue, bool)>> + Send,
@@ -55,6 +58,12 @@ where
         LeaveCall(u32),
     }
 
+    // A stack of argument values per function, one entry per (recursive) call
+    // that is currently being inlined
+    let mut fun_args_values: HashMap<u32, Vec<Vec<JsValue>>> = fun_args_values
+        .into_iter()
+        .map(|(func_ident, args)| (func_ident, vec![args]))
+        .collect();
     let mut work_queue_stack: Vec<Step> = Vec::new();
     let mut done: Vec<JsValue> = Vec::new();
     // Tracks the number of nodes in the queue and done combined
@@ -107,7 +116,10 @@ where
             // We want to replace the argument with the value from the function call
             Step::Enter(JsValue::Argument(func_ident, index)) => {
                 total_nodes -= 1;
-                if let Some(args) = fun_args_values.get(&func_ident) {
+                if let Some(args) = fun_args_values
+                    .get(&func_ident)
+                    .and_then(|frames| frames.last())
+                {
                     if let Some(val) = args.get(index) {
                         total_nodes += val.total_nodes();
                         done.push(val.clone());
@@ -136,12 +148,16 @@ where
                 args,
             )) => {
                 total_nodes -= 2; // Call + Function
-                if let Entry::Vacant(entry) = fun_args_values.entry(func_ident) {
+                let frames = fun_args_values.entry(func_ident).or_default();
+                if frames.is_empty()
+                    || (frames.len() < LIMIT_RECURSIVE_CALL_DEPTH
+                        && args.iter().all(|arg| matches!(arg, JsValue::Constant(_))))
+                {
                     // Return value will stay in total_nodes
                     for arg in args.iter() {
                         total_nodes -= arg.total_nodes();
                     }
-                    entry.insert(args);
+                    frames.push(args);
                     work_queue_stack.push(Step::LeaveCall(func_ident));
                     work_queue_stack.push(Step::Enter(*return_value));
                 } else {
@@ -158,9 +174,14 @@ where
                 }
             }
             // Leaving a function call evaluation
-            // - remove function arguments from the map
+            // - remove function arguments of this call from the map
             Step::LeaveCall(func_ident) => {
-                fun_args_values.remove(&func_ident);
+                if let Entry::Occupied(mut entry) = fun_args_values.entry(func_ident) {
+                    entry.get_mut().pop();
+                    if entry.get().is_empty() {
+                        entry.remove();
+                    }
+                }
             }
             // Enter a function
             // We don't want to process the function return value yet, this will happen after
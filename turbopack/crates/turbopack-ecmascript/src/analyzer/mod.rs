@@ -17,6 +17,7 @@ use num_bigint::BigInt;
 use num_traits::identities::Zero;
 use once_cell::sync::Lazy;
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 use swc_core::{
     common::Mark,
     ecma::{
@@ -24,13 +25,16 @@ use swc_core::{
         atoms::{Atom, JsWord},
     },
 };
-use turbo_tasks::{RcStr, Vc};
+use turbo_tasks::{trace::TraceRawVcs, RcStr, TaskInput, Vc};
 use turbopack_core::compile_time_info::CompileTimeDefineValue;
 use url::Url;
 
 use self::imports::ImportAnnotations;
 pub(crate) use self::imports::ImportMap;
-use crate::{references::require_context::RequireContextMap, utils::StringifyJs};
+use crate::{
+    references::require_context::{RequireContextMap, RequireContextMode},
+    utils::StringifyJs,
+};
 
 pub mod builtin;
 pub mod graph;
@@ -272,9 +276,21 @@ impl Display for ConstantValue {
     }
 }
 
+/// How a module is referenced.
+#[derive(
+    Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, TraceRawVcs, TaskInput, Hash,
+)]
+pub enum ModuleReferenceKind {
+    /// An `import` declaration or an `import()` expression.
+    Import,
+    /// A `require()` call.
+    Require,
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct ModuleValue {
     pub module: JsWord,
+    pub kind: ModuleReferenceKind,
     pub annotations: ImportAnnotations,
 }
 
@@ -666,6 +682,7 @@ impl Display for JsValue {
             JsValue::Module(ModuleValue {
                 module: name,
                 annotations,
+                ..
             }) => {
                 write!(f, "Module({}, {})", name, annotations)
             }
@@ -1479,6 +1496,7 @@ impl JsValue {
             JsValue::Module(ModuleValue {
                 module: name,
                 annotations,
+                ..
             }) => {
                 format!("module<{}, {}>", name, annotations)
             }
@@ -1583,6 +1601,10 @@ impl JsValue {
                         "require.cache",
                         "The CommonJS require.cache object: https://nodejs.org/api/modules.html#requirecache"
                     ),
+                    WellKnownObjectKind::ModuleHot => (
+                        "module.hot",
+                        "The hot module replacement API"
+                    ),
                 };
                 if depth > 0 {
                     let i = hints.len();
@@ -1653,6 +1675,10 @@ impl JsValue {
                         "process.cwd".to_string(),
                         "The Node.js process.cwd method: https://nodejs.org/api/process.html#processcwd",
                     ),
+                    WellKnownFunctionKind::ProcessDlopen => (
+                        "process.dlopen".to_string(),
+                        "The Node.js process.dlopen method: https://nodejs.org/api/process.html#processdlopenmodule-filename-flags",
+                    ),
                     WellKnownFunctionKind::NodePreGypFind => (
                         "find".to_string(),
                         "The Node.js @mapbox/node-pre-gyp module: https://github.com/mapbox/node-pre-gyp",
@@ -1689,6 +1715,10 @@ impl JsValue {
                       "load/loadSync".to_string(),
                       "require('@grpc/proto-loader').load(filepath, { includeDirs: [root] }) https://github.com/grpc/grpc-node"
                     ),
+                    WellKnownFunctionKind::ReflectApply => (
+                        "Reflect.apply".to_string(),
+                        "The Reflect.apply method, also used for calls with spread arguments: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/apply",
+                    ),
                 };
                 if depth > 0 {
                     let i = hints.len();
@@ -3201,13 +3231,15 @@ impl JsValue {
             (
                 JsValue::Module(ModuleValue {
                     module: l,
+                    kind: lk,
                     annotations: la,
                 }),
                 JsValue::Module(ModuleValue {
                     module: r,
+                    kind: rk,
                     annotations: ra,
                 }),
-            ) => l == r && la == ra,
+            ) => l == r && lk == rk && la == ra,
             (JsValue::WellKnownObject(l), JsValue::WellKnownObject(r)) => l == r,
             (JsValue::WellKnownFunction(l), JsValue::WellKnownFunction(r)) => l == r,
             (
@@ -3311,9 +3343,11 @@ impl JsValue {
             }
             JsValue::Module(ModuleValue {
                 module: v,
+                kind: k,
                 annotations: a,
             }) => {
                 Hash::hash(v, state);
+                Hash::hash(k, state);
                 Hash::hash(a, state);
             }
             JsValue::WellKnownObject(v) => Hash::hash(v, state),
@@ -3382,6 +3416,8 @@ pub enum WellKnownObjectKind {
     NodeProtobufLoader,
     NodeBuffer,
     RequireCache,
+    /// `module.hot` or `import.meta.hot`
+    ModuleHot,
 }
 
 impl WellKnownObjectKind {
@@ -3409,6 +3445,7 @@ pub struct RequireContextOptions {
     pub include_subdirs: bool,
     /// this is a regex (pattern, flags)
     pub filter: Regex,
+    pub mode: RequireContextMode,
 }
 
 /// Convert an ECMAScript regex to a Rust regex.
@@ -3449,9 +3486,8 @@ fn regex_from_js(pattern: &str, flags: &str) -> Result<Regex> {
 /// Parse the arguments passed to a require.context invocation, validate them
 /// and convert them to the appropriate rust values.
 pub fn parse_require_context(args: &[JsValue]) -> Result<RequireContextOptions> {
-    if !(1..=3).contains(&args.len()) {
-        // https://linear.app/vercel/issue/WEB-910/add-support-for-requirecontexts-mode-argument
-        bail!("require.context() only supports 1-3 arguments (mode is not supported)");
+    if !(1..=4).contains(&args.len()) {
+        bail!("require.context() only supports 1-4 arguments");
     }
 
     let Some(dir) = args[0].as_str().map(|s| s.into()) else {
@@ -3485,13 +3521,152 @@ pub fn parse_require_context(args: &[JsValue]) -> Result<RequireContextOptions>
         DEFAULT_REGEX.clone()
     };
 
+    let mode = if let Some(mode) = args.get(3) {
+        if let Some(mode) = mode.as_str().and_then(RequireContextMode::parse) {
+            mode
+        } else {
+            bail!(
+                "require.context(..., ..., ..., mode) requires mode to be one of \"sync\", \
+                 \"eager\", \"weak\", \"lazy\" or \"lazy-once\"",
+            );
+        }
+    } else {
+        RequireContextMode::Sync
+    };
+
     Ok(RequireContextOptions {
         dir,
         include_subdirs,
         filter,
+        mode,
     })
 }
 
+#[derive(Debug, Clone)]
+pub struct ImportMetaGlobOptions {
+    /// Glob patterns relative to the importing module. Patterns starting with
+    /// `!` exclude matches.
+    pub patterns: Vec<RcStr>,
+    /// Import the matched modules synchronously instead of lazily.
+    pub eager: bool,
+    /// Only import this export of the matched modules.
+    pub import: Option<RcStr>,
+    /// A query appended to the requests of the matched modules.
+    pub query: Option<RcStr>,
+}
+
+/// Parse the arguments passed to an import.meta.glob invocation, validate them
+/// and convert them to the appropriate rust values.
+pub fn parse_import_meta_glob(args: &[JsValue]) -> Result<ImportMetaGlobOptions> {
+    if !(1..=2).contains(&args.len()) {
+        bail!("import.meta.glob() only supports 1-2 arguments");
+    }
+
+    let patterns: Vec<RcStr> = match &args[0] {
+        JsValue::Array { items, .. } => items
+            .iter()
+            .map(|item| item.as_str().map(RcStr::from))
+            .collect::<Option<_>>()
+            .context(
+                "import.meta.glob(patterns, ...) requires patterns to be an array of constant \
+                 strings",
+            )?,
+        pattern => vec![pattern
+            .as_str()
+            .map(RcStr::from)
+            .context("import.meta.glob(pattern, ...) requires pattern to be a constant string")?],
+    };
+    if let Some(pattern) = patterns.iter().find(|pattern| {
+        let pattern = pattern.strip_prefix('!').unwrap_or(pattern);
+        !pattern.starts_with("./") && !pattern.starts_with("../")
+    }) {
+        bail!("import.meta.glob() only supports relative patterns, but got \"{pattern}\"");
+    }
+    if patterns.iter().all(|pattern| pattern.starts_with('!')) {
+        bail!("import.meta.glob() requires at least one pattern that is not negated");
+    }
+
+    let mut options = ImportMetaGlobOptions {
+        patterns,
+        eager: false,
+        import: None,
+        query: None,
+    };
+
+    let Some(options_arg) = args.get(1) else {
+        return Ok(options);
+    };
+    let JsValue::Object { parts, .. } = options_arg else {
+        bail!("import.meta.glob(..., options) requires options to be a constant object");
+    };
+    for part in parts {
+        let ObjectPart::KeyValue(key, value) = part else {
+            bail!("import.meta.glob(..., options) does not support spread in options");
+        };
+        match key.as_str() {
+            Some("eager") => {
+                options.eager = value
+                    .as_bool()
+                    .context("import.meta.glob() requires eager to be a constant boolean")?;
+            }
+            Some("import") => {
+                options.import = Some(
+                    value
+                        .as_str()
+                        .context("import.meta.glob() requires import to be a constant string")?
+                        .into(),
+                );
+            }
+            Some("query") => {
+                options.query = Some(match value {
+                    JsValue::Object { parts, .. } => {
+                        let mut query = String::new();
+                        for part in parts {
+                            let ObjectPart::KeyValue(key, value) = part else {
+                                bail!("import.meta.glob() does not support spread in query");
+                            };
+                            let value = match value {
+                                JsValue::Constant(ConstantValue::Str(value)) => {
+                                    value.as_str().to_string()
+                                }
+                                JsValue::Constant(
+                                    value @ (ConstantValue::True
+                                    | ConstantValue::False
+                                    | ConstantValue::Num(_)),
+                                ) => value.to_string(),
+                                _ => {
+                                    bail!("import.meta.glob() requires query values to be constant")
+                                }
+                            };
+                            let Some(key) = key.as_str() else {
+                                bail!("import.meta.glob() requires query keys to be constant");
+                            };
+                            query.push(if query.is_empty() { '?' } else { '&' });
+                            query.push_str(key);
+                            query.push('=');
+                            query.push_str(&value);
+                        }
+                        query.into()
+                    }
+                    value => {
+                        let query = value.as_str().context(
+                            "import.meta.glob() requires query to be a constant string or object",
+                        )?;
+                        if query.is_empty() || query.starts_with('?') {
+                            query.into()
+                        } else {
+                            format!("?{query}").into()
+                        }
+                    }
+                });
+            }
+            _ => bail!("import.meta.glob() does not support the option {key}"),
+        }
+    }
+
+    Ok(options)
+}
+
 #[turbo_tasks::value(transparent)]
 #[derive(Debug, Clone)]
 pub struct RequireContextValue(IndexMap<RcStr, RcStr>);
@@ -3545,6 +3720,7 @@ pub enum WellKnownFunctionKind {
     OsPlatform,
     OsEndianness,
     ProcessCwd,
+    ProcessDlopen,
     NodePreGypFind,
     NodeGypBuild,
     NodeBindings,
@@ -3554,6 +3730,10 @@ pub enum WellKnownFunctionKind {
     NodeStrongGlobalizeSetRootDir,
     NodeResolveFrom,
     NodeProtobufLoad,
+    /// `Reflect.apply(target, thisArgument, argumentsList)`. Calls with spread
+    /// arguments are represented as this, since their arguments are only known
+    /// as a whole.
+    ReflectApply,
 }
 
 impl WellKnownFunctionKind {
@@ -3581,8 +3761,8 @@ pub mod test_utils {
     use turbopack_core::{compile_time_info::CompileTimeInfo, error::PrettyPrintError};
 
     use super::{
-        builtin::early_replace_builtin, well_known::replace_well_known, JsValue, ModuleValue,
-        WellKnownFunctionKind, WellKnownObjectKind,
+        builtin::early_replace_builtin, well_known::replace_well_known, JsValue,
+        ModuleReferenceKind, ModuleValue, WellKnownFunctionKind, WellKnownObjectKind,
     };
     use crate::analyzer::{
         builtin::replace_builtin, imports::ImportAnnotations, parse_require_context,
@@ -3605,6 +3785,7 @@ pub mod test_utils {
             ) => match &args[0] {
                 JsValue::Constant(v) => JsValue::Module(ModuleValue {
                     module: v.to_string().into(),
+                    kind: ModuleReferenceKind::Import,
                     annotations: ImportAnnotations::default(),
                 }),
                 _ => v.into_unknown(true, "import() non constant"),
@@ -3622,6 +3803,13 @@ pub mod test_utils {
                 box JsValue::WellKnownFunction(WellKnownFunctionKind::RequireContext),
                 ref args,
             ) => match parse_require_context(args) {
+                Ok(options) if !matches!(options.mode, RequireContextMode::Sync) => v.into_unknown(
+                    false,
+                    format!(
+                        "require.context() with mode \"{}\" can't be resolved statically",
+                        options.mode.as_str()
+                    ),
+                ),
                 Ok(options) => {
                     let mut map = IndexMap::new();
 
@@ -3686,11 +3874,42 @@ mod tests {
     };
 
     use super::{
-        graph::{create_graph, ConditionalKind, Effect, EffectArg, EvalContext, VarGraph},
+        graph::{
+            create_graph, is_in_function, ConditionalKind, Effect, EffectArg, EvalContext, VarGraph,
+        },
         linker::link,
         JsValue,
     };
 
+    #[test]
+    fn function_bodies() {
+        use swc_core::ecma::visit::{fields::*, AstParentKind};
+
+        let top_level = [
+            AstParentKind::ModuleItem(ModuleItemField::Stmt),
+            AstParentKind::Stmt(StmtField::Expr),
+        ];
+        assert!(!is_in_function(&top_level));
+
+        let params = [
+            AstParentKind::ModuleItem(ModuleItemField::Stmt),
+            AstParentKind::Function(FunctionField::Params(0)),
+        ];
+        assert!(!is_in_function(&params));
+
+        for body in [
+            AstParentKind::Function(FunctionField::Body),
+            AstParentKind::ArrowExpr(ArrowExprField::Body),
+        ] {
+            let path = [
+                AstParentKind::ModuleItem(ModuleItemField::Stmt),
+                body,
+                AstParentKind::Stmt(StmtField::Expr),
+            ];
+            assert!(is_in_function(&path));
+        }
+    }
+
     #[fixture("tests/analyzer/graph/**/input.js")]
     fn fixture(input: PathBuf) {
         crate::register();
@@ -6,8 +6,8 @@ use turbopack_core::compile_time_info::CompileTimeInfo;
 use url::Url;
 
 use super::{
-    imports::ImportAnnotations, ConstantValue, JsValue, ModuleValue, WellKnownFunctionKind,
-    WellKnownObjectKind,
+    imports::ImportAnnotations, ConstantValue, JsValue, ModuleReferenceKind, ModuleValue,
+    WellKnownFunctionKind, WellKnownObjectKind,
 };
 use crate::analyzer::RequireContextValue;
 
@@ -63,6 +63,7 @@ pub async fn well_known_function_call(
             "import() is not supported",
         ),
         WellKnownFunctionKind::Require => require(args),
+        WellKnownFunctionKind::ReflectApply => reflect_apply(args),
         WellKnownFunctionKind::RequireContextRequire(value) => {
             require_context_require(value, args).await?
         }
@@ -327,6 +328,7 @@ pub fn require(args: Vec<JsValue>) -> JsValue {
         if let Some(s) = args[0].as_str() {
             JsValue::Module(ModuleValue {
                 module: s.into(),
+                kind: ModuleReferenceKind::Require,
                 annotations: ImportAnnotations::default(),
             })
         } else {
@@ -351,6 +353,43 @@ pub fn require(args: Vec<JsValue>) -> JsValue {
     }
 }
 
+/// Turns `Reflect.apply(target, thisArgument, [arg1, arg2, ...])` back into a
+/// call when the arguments are known and the call has the same this context.
+pub fn reflect_apply(mut args: Vec<JsValue>) -> JsValue {
+    if let [target, this, JsValue::Array { items, mutable, .. }] = &mut args[..] {
+        let is_member_of_this = matches!(target, JsValue::Member(_, obj, _) if **obj == *this);
+        // Well-known functions don't depend on the this context
+        if is_member_of_this
+            || matches!(this, JsValue::Constant(ConstantValue::Undefined))
+            || matches!(target, JsValue::WellKnownFunction(_))
+        {
+            let mutable = *mutable;
+            let items = take(items);
+            let mut call = match take(target) {
+                JsValue::Member(_, obj, prop) if is_member_of_this => {
+                    JsValue::member_call(obj, prop, items)
+                }
+                target => JsValue::call(Box::new(target), items),
+            };
+            // The arguments might have been modified before the call
+            if mutable {
+                call.add_unknown_mutations(true);
+            }
+            return call;
+        }
+    }
+    JsValue::unknown(
+        JsValue::call(
+            Box::new(JsValue::WellKnownFunction(
+                WellKnownFunctionKind::ReflectApply,
+            )),
+            args,
+        ),
+        true,
+        "Reflect.apply is only supported with known arguments and this context",
+    )
+}
+
 /// (try to) statically evaluate `require.context(...)()`
 pub async fn require_context_require(
     val: Vc<RequireContextValue>,
@@ -399,6 +438,7 @@ pub async fn require_context_require(
 
     Ok(JsValue::Module(ModuleValue {
         module: m.to_string().into(),
+        kind: ModuleReferenceKind::Require,
         annotations: ImportAnnotations::default(),
     }))
 }
@@ -740,6 +780,7 @@ async fn node_process_member(
             .as_str()
             .into(),
         Some("cwd") => JsValue::WellKnownFunction(WellKnownFunctionKind::ProcessCwd),
+        Some("dlopen") => JsValue::WellKnownFunction(WellKnownFunctionKind::ProcessDlopen),
         Some("argv") => JsValue::WellKnownObject(WellKnownObjectKind::NodeProcessArgv),
         Some("env") => JsValue::WellKnownObject(WellKnownObjectKind::NodeProcessEnv),
         _ => JsValue::unknown(
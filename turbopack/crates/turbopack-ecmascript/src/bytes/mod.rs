@@ -0,0 +1,83 @@
+use anyhow::Result;
+use base64::{display::Base64Display, engine::general_purpose::STANDARD};
+use turbo_tasks::{RcStr, Vc};
+use turbo_tasks_fs::FileContent;
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    ident::AssetIdent,
+    source::Source,
+    source_transform::SourceTransform,
+};
+
+#[turbo_tasks::function]
+fn modifier() -> Vc<RcStr> {
+    Vc::cell("bytes content".into())
+}
+
+/// A source asset that exports the binary content of an asset as a
+/// `Uint8Array` in the default export of a JS module.
+#[turbo_tasks::value]
+pub struct BytesContentFileSource {
+    pub source: Vc<Box<dyn Source>>,
+}
+
+#[turbo_tasks::value_impl]
+impl BytesContentFileSource {
+    #[turbo_tasks::function]
+    pub fn new(source: Vc<Box<dyn Source>>) -> Vc<Self> {
+        BytesContentFileSource { source }.cell()
+    }
+}
+
+/// A source transform which turns sources into modules exporting their binary
+/// content, see [BytesContentFileSource].
+#[turbo_tasks::value]
+pub struct BytesContentTransform;
+
+#[turbo_tasks::value_impl]
+impl BytesContentTransform {
+    #[turbo_tasks::function]
+    pub fn new() -> Vc<Self> {
+        BytesContentTransform.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl SourceTransform for BytesContentTransform {
+    #[turbo_tasks::function]
+    fn transform(&self, source: Vc<Box<dyn Source>>) -> Vc<Box<dyn Source>> {
+        Vc::upcast(BytesContentFileSource::new(source))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Source for BytesContentFileSource {
+    #[turbo_tasks::function]
+    fn ident(&self) -> Vc<AssetIdent> {
+        self.source
+            .ident()
+            .with_modifier(modifier())
+            .rename_as("*.mjs".into())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for BytesContentFileSource {
+    #[turbo_tasks::function]
+    async fn content(&self) -> Result<Vc<AssetContent>> {
+        let source = self.source.content().file_content();
+        let FileContent::Content(content) = &*source.await? else {
+            return Ok(AssetContent::file(FileContent::NotFound.cell()));
+        };
+        let bytes = content.content().to_bytes()?;
+        // Base64 is more compact than an array literal and decoding it works
+        // in browsers and Node.js.
+        let code: RcStr = format!(
+            "export default Uint8Array.from(atob(\"{}\"), (c) => c.charCodeAt(0));",
+            Base64Display::new(&bytes, &STANDARD)
+        )
+        .into();
+        let content = FileContent::Content(code.into()).cell();
+        Ok(AssetContent::file(content))
+    }
+}
@@ -36,7 +36,9 @@ impl EcmascriptChunkItemContent {
         options: Vc<EcmascriptOptions>,
         async_module_options: Vc<OptionAsyncModuleOptions>,
     ) -> Result<Vc<Self>> {
-        let refresh = options.await?.refresh;
+        let options = options.await?;
+        let refresh = options.refresh;
+        let hot = options.hot_module_replacement;
         let externals = *chunking_context
             .environment()
             .supports_commonjs_externals()
@@ -52,6 +54,7 @@ impl EcmascriptChunkItemContent {
                 EcmascriptChunkItemOptions {
                     strict: true,
                     refresh,
+                    hot,
                     externals,
                     async_module,
                     ..Default::default()
@@ -63,6 +66,7 @@ impl EcmascriptChunkItemContent {
 
                 EcmascriptChunkItemOptions {
                     refresh,
+                    hot,
                     externals,
                     // These things are not available in ESM
                     module: true,
@@ -108,6 +112,9 @@ impl EcmascriptChunkItemContent {
         if this.options.refresh {
             args.push("k: __turbopack_refresh__");
         }
+        if this.options.hot {
+            args.push("h: __turbopack_hot__");
+        }
         if this.options.module || this.options.refresh {
             args.push("m: module");
         }
@@ -166,6 +173,9 @@ pub struct EcmascriptChunkItemOptions {
     /// Whether this chunk item's module factory should include a
     /// `__turbopack_refresh__` argument.
     pub refresh: bool,
+    /// Whether this chunk item's module factory should include a
+    /// `__turbopack_hot__` argument.
+    pub hot: bool,
     /// Whether this chunk item's module factory should include a `module`
     /// argument.
     pub module: bool,
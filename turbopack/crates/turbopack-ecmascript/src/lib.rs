@@ -10,6 +10,7 @@
 pub mod analyzer;
 pub mod annotations;
 pub mod async_chunk;
+pub mod bytes;
 pub mod chunk;
 pub mod chunk_group_files_asset;
 pub mod code_gen;
@@ -30,6 +31,7 @@ pub mod tree_shake;
 pub mod typescript;
 pub mod utils;
 pub mod webpack;
+pub mod worker_chunk;
 
 use std::fmt::{Display, Formatter};
 
@@ -78,6 +80,7 @@ use turbopack_core::{
 };
 // TODO remove this
 pub use turbopack_resolve::ecmascript as resolve;
+use turbopack_resolve::node_native_binding::NativeBindingDetectors;
 
 use self::{
     chunk::{EcmascriptChunkItemContent, EcmascriptChunkType, EcmascriptExports},
@@ -142,6 +145,12 @@ pub struct EcmascriptOptions {
     /// If false, they will reference the whole directory. If true, they won't
     /// reference anything and lead to an runtime error instead.
     pub ignore_dynamic_requests: bool,
+    /// Detects native addons of the package containing the module, which are
+    /// loaded in a way that can't be analyzed.
+    pub native_binding_detectors: Option<Vc<NativeBindingDetectors>>,
+    /// Whether `module.hot` and `import.meta.hot` refer to the runtime's hot
+    /// module replacement API. Otherwise they are compiled to `undefined`.
+    pub hot_module_replacement: bool,
 }
 
 #[turbo_tasks::value(serialization = "auto_for_input")]
@@ -232,7 +241,11 @@ impl EcmascriptModuleAssetBuilder {
     pub async fn build_part(self, part: Vc<ModulePart>) -> Result<Vc<EcmascriptModulePartAsset>> {
         let import_externals = self.options.await?.import_externals;
         let base = self.build();
-        Ok(EcmascriptModulePartAsset::new(base, part, import_externals))
+        Ok(EcmascriptModulePartAsset::select_part(
+            base,
+            part,
+            import_externals,
+        ))
     }
 }
 
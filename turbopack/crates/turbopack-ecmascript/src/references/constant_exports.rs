@@ -0,0 +1,666 @@
+use anyhow::Result;
+use indexmap::IndexMap;
+use swc_core::{
+    common::{Mark, GLOBALS},
+    ecma::{
+        ast::{
+            AssignExpr, AssignOp, CallExpr, Callee, Decl, ExportDecl, Expr, ExprOrSpread, ExprStmt,
+            Ident, KeyValueProp, Lit, MemberExpr, Module, ModuleDecl, ModuleItem, ObjectLit, Pat,
+            Program, Prop, PropName, PropOrSpread, SimpleAssignTarget, Stmt, UnaryExpr, UnaryOp,
+            UpdateExpr, VarDeclKind,
+        },
+        visit::{Visit, VisitWith},
+    },
+};
+use turbo_tasks::{RcStr, Value, Vc};
+use turbopack_core::{
+    reference_type::{CommonJsReferenceSubType, EcmaScriptModulesReferenceSubType, ReferenceType},
+    resolve::{
+        origin::{ResolveOrigin, ResolveOriginExt},
+        parse::Request,
+    },
+};
+use turbopack_resolve::ecmascript::{apply_cjs_specific_options, apply_esm_specific_options};
+
+use super::{early_value_visitor, value_visitor, ParsedVarGraph};
+use crate::{
+    analyzer::{
+        graph::EvalContext,
+        imports::{static_member_prop, ImportAnnotations},
+        linker::link,
+        ConstantValue, JsValue, ModuleReferenceKind,
+    },
+    parse::ParseResult,
+    EcmascriptModuleAsset, EcmascriptParsable,
+};
+
+/// How many modules deep imported bindings are followed when looking for
+/// constant values.
+pub(crate) const CROSS_MODULE_BUDGET: u32 = 3;
+
+/// The exports of a module that are compile-time constants, keyed by export
+/// name.
+#[turbo_tasks::value(transparent, serialization = "none")]
+pub(crate) struct ConstantExports(
+    #[turbo_tasks(debug_ignore, trace_ignore)] IndexMap<RcStr, ConstantValue>,
+);
+
+/// Analyzes which exports of `module` are primitive constants. Imported and
+/// required bindings in their values are followed into other modules while
+/// `budget` allows it.
+///
+/// For ES modules only `export const` declarations are considered, since they
+/// can't be reassigned, neither by the module itself nor by the importer. For
+/// CommonJS modules only properties of the exports object which are assigned
+/// once by a top-level statement are considered, see [cjs_exported_values].
+#[turbo_tasks::function]
+pub(crate) async fn constant_exports(
+    module: Vc<EcmascriptModuleAsset>,
+    budget: u32,
+) -> Result<Vc<ConstantExports>> {
+    let parsed = module.failsafe_parse().await?;
+    let ParseResult::Ok {
+        program,
+        globals,
+        eval_context,
+        ..
+    } = &*parsed
+    else {
+        return Ok(Vc::cell(IndexMap::new()));
+    };
+
+    let values = GLOBALS.set(globals, || exported_values(program, eval_context));
+    if values.is_empty() {
+        return Ok(Vc::cell(IndexMap::new()));
+    }
+    let var_graph = ParsedVarGraph::new(module.failsafe_parse()).await?;
+    let Some(var_graph) = var_graph.graph() else {
+        return Ok(Vc::cell(IndexMap::new()));
+    };
+    let origin = Vc::upcast::<Box<dyn ResolveOrigin>>(module);
+    let compile_time_info = module.await?.compile_time_info;
+
+    let mut constants = IndexMap::new();
+    for (name, value) in values {
+        let value = link(
+            var_graph,
+            value,
+            &|value| early_value_visitor(origin, value, budget.saturating_sub(1)),
+            &|value| value_visitor(origin, value, compile_time_info, false),
+            Default::default(),
+        )
+        .await?;
+
+        if let JsValue::Constant(constant) = value {
+            if is_primitive(&constant) {
+                constants.insert(name, constant);
+            }
+        }
+    }
+
+    Ok(Vc::cell(constants))
+}
+
+/// Resolves `module` from `origin` like the `import` or `require()` it's
+/// referenced by and returns its constant exports. Returns no exports when
+/// `budget` is exhausted.
+#[turbo_tasks::function]
+pub(crate) async fn imported_constant_exports(
+    origin: Vc<Box<dyn ResolveOrigin>>,
+    module: RcStr,
+    kind: ModuleReferenceKind,
+    annotations: Value<ImportAnnotations>,
+    budget: u32,
+) -> Result<Vc<ConstantExports>> {
+    if budget == 0 {
+        return Ok(Vc::cell(IndexMap::new()));
+    }
+
+    let request = Request::parse(Value::new(module.into()));
+    let result = match kind {
+        ModuleReferenceKind::Require => {
+            let ty = Value::new(ReferenceType::CommonJs(CommonJsReferenceSubType::Undefined));
+            let options = apply_cjs_specific_options(origin.resolve_options(ty.clone()));
+            origin.resolve_asset(request, options, ty)
+        }
+        ModuleReferenceKind::Import => {
+            // Imports with a `type` attribute don't import JavaScript
+            if annotations.module_type().is_some() {
+                return Ok(Vc::cell(IndexMap::new()));
+            }
+            let origin = match annotations.transition() {
+                Some(transition) => origin.with_transition(transition.into()),
+                None => origin,
+            };
+            let ty = Value::new(ReferenceType::EcmaScriptModules(
+                EcmaScriptModulesReferenceSubType::Import,
+            ));
+            let options =
+                apply_esm_specific_options(origin.resolve_options(ty.clone()), ty.clone());
+            origin.resolve_asset(request, options, ty)
+        }
+    };
+
+    let Some(module) = *result.first_module().await? else {
+        return Ok(Vc::cell(IndexMap::new()));
+    };
+    let Some(module) = Vc::try_resolve_downcast_type::<EcmascriptModuleAsset>(module).await? else {
+        return Ok(Vc::cell(IndexMap::new()));
+    };
+
+    Ok(constant_exports(module, budget))
+}
+
+/// Regular expressions are excluded, since every evaluation of a regex
+/// literal creates a new (stateful) object.
+fn is_primitive(value: &ConstantValue) -> bool {
+    !matches!(value, ConstantValue::Regex(..))
+}
+
+/// Collects the unlinked values of the exports of `program` which might be
+/// constant, both of ES module and of CommonJS exports.
+fn exported_values(program: &Program, eval_context: &EvalContext) -> IndexMap<RcStr, JsValue> {
+    let mut values = match program {
+        Program::Module(module) => exported_const_values(module, eval_context),
+        Program::Script(_) => IndexMap::new(),
+    };
+    for (name, value) in cjs_exported_values(program, eval_context) {
+        values.entry(name).or_insert(value);
+    }
+    values
+}
+
+/// Collects the unlinked values of all `export const` declarations.
+fn exported_const_values(module: &Module, eval_context: &EvalContext) -> IndexMap<RcStr, JsValue> {
+    let mut values = IndexMap::new();
+    for item in &module.body {
+        let ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+            decl: Decl::Var(var_decl),
+            ..
+        })) = item
+        else {
+            continue;
+        };
+        if var_decl.kind != VarDeclKind::Const {
+            continue;
+        }
+        for decl in &var_decl.decls {
+            if let Pat::Ident(ident) = &decl.name {
+                values.insert(
+                    ident.id.sym.as_str().into(),
+                    eval_context.eval(&Expr::Ident(ident.id.clone())),
+                );
+            }
+        }
+    }
+    values
+}
+
+/// Collects the unlinked values of the CommonJS exports of `program`, which
+/// are assigned by top-level statements like `exports.dir = "./dir"`,
+/// `module.exports.dir = "./dir"` or `module.exports = { dir: "./dir" }`.
+///
+/// Properties which are written anywhere else are skipped, and nothing is
+/// collected when the exports object is used in any way that could modify it,
+/// e.g. when it's passed to a function. Like for ES modules, it's assumed that
+/// importers don't modify the exports of the required module.
+fn cjs_exported_values(program: &Program, eval_context: &EvalContext) -> IndexMap<RcStr, JsValue> {
+    let mut visitor = CjsExportsVisitor {
+        unresolved_mark: eval_context.unresolved_mark,
+        writes: IndexMap::new(),
+        module_exports_assignments: 0,
+        uses_exports: false,
+        escaping: false,
+    };
+    program.visit_with(&mut visitor);
+    // `exports` still refers to the original exports object after `module.exports` is
+    // replaced.
+    if visitor.escaping
+        || visitor.module_exports_assignments > 1
+        || (visitor.module_exports_assignments == 1 && visitor.uses_exports)
+    {
+        return IndexMap::new();
+    }
+
+    let stmts: Vec<&Stmt> = match program {
+        Program::Module(module) => module.body.iter().filter_map(ModuleItem::as_stmt).collect(),
+        Program::Script(script) => script.body.iter().collect(),
+    };
+    let mut values = IndexMap::new();
+    for stmt in stmts {
+        let Stmt::Expr(ExprStmt {
+            expr:
+                box Expr::Assign(AssignExpr {
+                    op: AssignOp::Assign,
+                    left,
+                    right,
+                    ..
+                }),
+            ..
+        }) = stmt
+        else {
+            continue;
+        };
+        let Some(SimpleAssignTarget::Member(member)) = left.as_simple() else {
+            continue;
+        };
+        if visitor.is_exports_object(&member.obj) {
+            if let Some(name) = static_member_prop(&member.prop) {
+                values.insert(name.as_str().into(), eval_context.eval(right));
+            }
+        } else if visitor.is_module_exports(member) {
+            let Expr::Object(object) = &**right else {
+                continue;
+            };
+            for prop in &object.props {
+                let PropOrSpread::Prop(prop) = prop else {
+                    continue;
+                };
+                match &**prop {
+                    Prop::KeyValue(KeyValueProp { key, value }) => {
+                        if let Some(name) = prop_name(key) {
+                            values.insert(name, eval_context.eval(value));
+                        }
+                    }
+                    Prop::Shorthand(ident) => {
+                        values.insert(
+                            ident.sym.as_str().into(),
+                            eval_context.eval(&Expr::Ident(ident.clone())),
+                        );
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    values.retain(|name, _| visitor.writes.get(name) == Some(&1));
+    values
+}
+
+fn prop_name(key: &PropName) -> Option<RcStr> {
+    match key {
+        PropName::Ident(ident) => Some(ident.sym.as_str().into()),
+        PropName::Str(str) => Some(str.value.as_str().into()),
+        _ => None,
+    }
+}
+
+/// Finds all writes to the exports object of a CommonJS module.
+struct CjsExportsVisitor {
+    unresolved_mark: Mark,
+    /// How often each property of the exports object is written.
+    writes: IndexMap<RcStr, u32>,
+    module_exports_assignments: u32,
+    /// The free variable `exports` is used.
+    uses_exports: bool,
+    /// The exports object is used in a way which isn't tracked.
+    escaping: bool,
+}
+
+impl CjsExportsVisitor {
+    fn is_free_var(&self, expr: &Expr, name: &str) -> bool {
+        matches!(expr, Expr::Ident(ident) if self.is_free_ident(ident, name))
+    }
+
+    fn is_free_ident(&self, ident: &Ident, name: &str) -> bool {
+        &*ident.sym == name && ident.span.ctxt.outer() == self.unresolved_mark
+    }
+
+    fn is_module_exports(&self, member: &MemberExpr) -> bool {
+        self.is_free_var(&member.obj, "module")
+            && static_member_prop(&member.prop).is_some_and(|prop| &*prop == "exports")
+    }
+
+    /// Returns true for `exports` and `module.exports`.
+    fn is_exports_object(&self, expr: &Expr) -> bool {
+        self.is_free_var(expr, "exports")
+            || matches!(expr, Expr::Member(member) if self.is_module_exports(member))
+    }
+
+    /// Records a write to `member`. Returns false when `member` is not a property
+    /// of the exports object.
+    fn write_member(&mut self, member: &MemberExpr) -> bool {
+        if !self.is_exports_object(&member.obj) {
+            return false;
+        }
+        if self.is_free_var(&member.obj, "exports") {
+            self.uses_exports = true;
+        }
+        match static_member_prop(&member.prop) {
+            Some(name) => self.write(name.as_str().into()),
+            None => self.escaping = true,
+        }
+        true
+    }
+
+    fn write(&mut self, name: RcStr) {
+        *self.writes.entry(name).or_default() += 1;
+    }
+
+    fn write_object(&mut self, object: &ObjectLit) {
+        for prop in &object.props {
+            let PropOrSpread::Prop(prop) = prop else {
+                self.escaping = true;
+                continue;
+            };
+            let key = match &**prop {
+                Prop::Shorthand(ident) => Some(ident.sym.as_str().into()),
+                Prop::KeyValue(KeyValueProp { key, .. }) => prop_name(key),
+                Prop::Getter(getter) => prop_name(&getter.key),
+                Prop::Setter(setter) => prop_name(&setter.key),
+                Prop::Method(method) => prop_name(&method.key),
+                Prop::Assign(_) => None,
+            };
+            match key {
+                Some(key) => self.write(key),
+                None => self.escaping = true,
+            }
+        }
+    }
+}
+
+impl Visit for CjsExportsVisitor {
+    fn visit_assign_expr(&mut self, n: &AssignExpr) {
+        if let Some(SimpleAssignTarget::Member(member)) = n.left.as_simple() {
+            if self.is_module_exports(member) {
+                self.module_exports_assignments += 1;
+                match &*n.right {
+                    Expr::Object(object) if n.op == AssignOp::Assign => self.write_object(object),
+                    _ => self.escaping = true,
+                }
+                n.right.visit_with(self);
+                return;
+            }
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_simple_assign_target(&mut self, n: &SimpleAssignTarget) {
+        if let SimpleAssignTarget::Member(member) = n {
+            if self.write_member(member) {
+                return;
+            }
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_pat(&mut self, n: &Pat) {
+        // e.g. `[exports.a] = values`
+        if let Pat::Expr(box Expr::Member(member)) = n {
+            if self.write_member(member) {
+                return;
+            }
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_update_expr(&mut self, n: &UpdateExpr) {
+        if let Expr::Member(member) = &*n.arg {
+            if self.write_member(member) {
+                return;
+            }
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_unary_expr(&mut self, n: &UnaryExpr) {
+        if n.op == UnaryOp::Delete {
+            if let Expr::Member(member) = &*n.arg {
+                if self.write_member(member) {
+                    return;
+                }
+            }
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_call_expr(&mut self, n: &CallExpr) {
+        // `Object.defineProperty(exports, "name", descriptor)`, which is e.g. emitted by
+        // TypeScript for `__esModule`
+        if let (
+            Callee::Expr(box Expr::Member(callee)),
+            [ExprOrSpread {
+                spread: None,
+                expr: object,
+            }, ExprOrSpread {
+                spread: None,
+                expr: box Expr::Lit(Lit::Str(name)),
+            }, rest @ ..],
+        ) = (&n.callee, &*n.args)
+        {
+            if self.is_free_var(&callee.obj, "Object")
+                && static_member_prop(&callee.prop).is_some_and(|prop| &*prop == "defineProperty")
+                && self.is_exports_object(object)
+            {
+                if self.is_free_var(object, "exports") {
+                    self.uses_exports = true;
+                }
+                self.write(name.value.as_str().into());
+                rest.visit_with(self);
+                return;
+            }
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_member_expr(&mut self, n: &MemberExpr) {
+        // Reading a property of the exports object
+        if self.is_exports_object(&n.obj) {
+            if self.is_free_var(&n.obj, "exports") {
+                self.uses_exports = true;
+            }
+            n.prop.visit_with(self);
+            return;
+        }
+        // Reading a property of `module` other than `exports`
+        if self.is_free_var(&n.obj, "module") && !self.is_module_exports(n) {
+            n.prop.visit_with(self);
+            return;
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_ident(&mut self, n: &Ident) {
+        // The exports object (or `module`, which gives access to it) is used as a
+        // value.
+        if self.is_free_ident(n, "exports") || self.is_free_ident(n, "module") {
+            self.escaping = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_core::{
+        common::{FileName, Mark},
+        ecma::{
+            ast::{EsVersion, Program},
+            parser::parse_file_as_module,
+            transforms::base::resolver,
+            visit::VisitMutWith,
+        },
+        testing::run_test,
+    };
+
+    use super::{exported_values, is_primitive};
+    use crate::analyzer::{
+        graph::{create_graph, EvalContext},
+        linker::link,
+        JsValue,
+    };
+
+    /// Returns the exports of `code` which link to primitive constants,
+    /// without following imports.
+    fn constant_exports(code: &str) -> Vec<(String, String)> {
+        run_test(false, |cm, _handler| {
+            let fm = cm.new_source_file(FileName::Anon, code.into());
+            let module = parse_file_as_module(
+                &fm,
+                Default::default(),
+                EsVersion::latest(),
+                None,
+                &mut vec![],
+            )
+            .unwrap();
+            let unresolved_mark = Mark::new();
+            let top_level_mark = Mark::new();
+            let mut program = Program::Module(module);
+            program.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, false));
+
+            let eval_context = EvalContext::new(&program, unresolved_mark, top_level_mark, None);
+            let var_graph = create_graph(&program, &eval_context);
+
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .build()
+                .unwrap();
+            let mut constants = Vec::new();
+            for (name, value) in exported_values(&program, &eval_context) {
+                let value = runtime
+                    .block_on(link(
+                        &var_graph,
+                        value,
+                        &|value| async move { Ok((value, false)) },
+                        &|value| async move { Ok((value, false)) },
+                        Default::default(),
+                    ))
+                    .unwrap();
+                if let JsValue::Constant(constant) = value {
+                    if is_primitive(&constant) {
+                        constants.push((name.to_string(), constant.to_string()));
+                    }
+                }
+            }
+            Ok(constants)
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn collects_export_const() {
+        assert_eq!(
+            constant_exports(
+                r#"
+                const local = "local";
+                export const a = 1, b = "b";
+                export const c = local;
+                export const d = true;
+                "#
+            ),
+            vec![
+                ("a".to_string(), "1".to_string()),
+                ("b".to_string(), "\"b\"".to_string()),
+                ("c".to_string(), "\"local\"".to_string()),
+                ("d".to_string(), "true".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_reassignable_exports() {
+        assert_eq!(
+            constant_exports(
+                r#"
+                export let a = 1;
+                export var b = 2;
+                let c = 3;
+                export { c };
+                export default 4;
+                export function setA(value) {
+                    a = value;
+                    c = value;
+                }
+                "#
+            ),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn ignores_non_constant_values() {
+        assert_eq!(
+            constant_exports(
+                r#"
+                let mode = "a";
+                if (Math.random() > 0.5) {
+                    mode = "b";
+                }
+                export const a = mode;
+                export const b = /regex/g;
+                export const c = { key: "value" };
+                export const d = Math.random();
+                "#
+            ),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn collects_cjs_exports() {
+        assert_eq!(
+            constant_exports(
+                r#"
+                Object.defineProperty(exports, "__esModule", { value: true });
+                exports.a = 1;
+                module.exports.b = "b";
+                exports["c"] = true;
+                "#
+            ),
+            vec![
+                ("a".to_string(), "1".to_string()),
+                ("b".to_string(), "\"b\"".to_string()),
+                ("c".to_string(), "true".to_string()),
+            ]
+        );
+        assert_eq!(
+            constant_exports(
+                r#"
+                const dir = "./dir";
+                module.exports = { dir, "mode": "production", get other() {} };
+                "#
+            ),
+            vec![
+                ("dir".to_string(), "\"./dir\"".to_string()),
+                ("mode".to_string(), "\"production\"".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_modified_cjs_exports() {
+        assert_eq!(
+            constant_exports(
+                r#"
+                exports.a = 1;
+                exports.a = 2;
+                exports.b = 1;
+                exports.b++;
+                exports.c = 1;
+                function setC(value) {
+                    module.exports.c = value;
+                }
+                exports.d = 1;
+                Object.defineProperty(exports, "d", { get: () => 2 });
+                "#
+            ),
+            vec![]
+        );
+        assert_eq!(
+            constant_exports(
+                r#"
+                exports.a = 1;
+                Object.assign(exports, { a: 2 });
+                "#
+            ),
+            vec![]
+        );
+        assert_eq!(
+            constant_exports(
+                r#"
+                exports.a = 1;
+                module.exports = { b: 2 };
+                "#
+            ),
+            vec![]
+        );
+    }
+}
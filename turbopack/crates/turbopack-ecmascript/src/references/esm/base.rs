@@ -11,7 +11,8 @@ use turbopack_core::{
         ChunkItemExt, ChunkableModule, ChunkableModuleReference, ChunkingContext, ChunkingType,
         ChunkingTypeOption, ModuleId,
     },
-    issue::{IssueSeverity, IssueSource},
+    ident::AssetIdent,
+    issue::{analyze::AnalyzeIssue, IssueExt, IssueSeverity, IssueSource, StyledString},
     module::Module,
     reference::ModuleReference,
     reference_type::{EcmaScriptModulesReferenceSubType, ImportWithType},
@@ -143,8 +144,34 @@ impl EsmAssetReference {
 impl ModuleReference for EsmAssetReference {
     #[turbo_tasks::function]
     async fn resolve_reference(&self) -> Result<Vc<ModuleResolveResult>> {
-        let ty = if matches!(self.annotations.module_type(), Some("json")) {
-            EcmaScriptModulesReferenceSubType::ImportWithType(ImportWithType::Json)
+        let with_type = match self.annotations.module_type() {
+            Some("json") => Some(ImportWithType::Json),
+            Some("text") => Some(ImportWithType::Text),
+            Some("bytes") => Some(ImportWithType::Bytes),
+            Some(ty) => {
+                AnalyzeIssue {
+                    code: None,
+                    message: StyledString::Text(
+                        format!(
+                            "The import attribute `type: \"{ty}\"` is not supported. Supported \
+                             types are \"json\", \"text\" and \"bytes\"."
+                        )
+                        .into(),
+                    )
+                    .cell(),
+                    source_ident: AssetIdent::from_path(self.origin.origin_path()),
+                    severity: IssueSeverity::Error.into(),
+                    source: self.issue_source,
+                    title: Vc::cell("unknown import type".into()),
+                }
+                .cell()
+                .emit();
+                return Ok(ModuleResolveResult::unresolveable().cell());
+            }
+            None => None,
+        };
+        let ty = if let Some(with_type) = with_type {
+            EcmaScriptModulesReferenceSubType::ImportWithType(with_type)
         } else if let Some(part) = &self.export_name {
             EcmaScriptModulesReferenceSubType::ImportPart(*part)
         } else {
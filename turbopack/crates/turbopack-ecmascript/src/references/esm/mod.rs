@@ -6,6 +6,7 @@ pub(crate) mod meta;
 pub(crate) mod module_id;
 pub(crate) mod module_item;
 pub(crate) mod url;
+pub(crate) mod usage;
 
 pub use self::{
     base::EsmAssetReference,
@@ -15,4 +16,5 @@ pub use self::{
     meta::{ImportMetaBinding, ImportMetaRef},
     module_item::EsmModuleItem,
     url::{UrlAssetReference, UrlRewriteBehavior},
+    usage::{ImportUsage, ImportUsages},
 };
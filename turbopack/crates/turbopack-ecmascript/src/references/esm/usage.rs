@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use turbo_tasks::{trace::TraceRawVcs, RcStr, Vc};
+use turbopack_core::reference::ModuleReference;
+
+/// Describes which part of an ESM import is actually used by the importing
+/// module. Imports that are only evaluated for their side effects are not
+/// recorded.
+#[derive(Clone, Hash, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs)]
+pub enum ImportUsage {
+    /// A single export is used, e.g. `import { a } from "..."` or `ns.a`.
+    Export(RcStr),
+    /// A property of the default export is read with a static name, e.g.
+    /// `styles.a` for `import styles from "..."`. Only recorded when the
+    /// default import is not used in any other way.
+    DefaultExportMember(RcStr),
+    /// The namespace object is used as a whole, so any export might be
+    /// accessed.
+    Namespace,
+}
+
+/// The used imports of a module, together with the references they are
+/// imported through.
+#[turbo_tasks::value(transparent)]
+pub struct ImportUsages(Vec<(Vc<Box<dyn ModuleReference>>, ImportUsage)>);
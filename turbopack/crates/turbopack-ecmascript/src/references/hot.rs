@@ -0,0 +1,60 @@
+use anyhow::Result;
+use swc_core::{
+    common::DUMMY_SP,
+    ecma::ast::{Expr, Ident},
+    quote,
+};
+use turbo_tasks::Vc;
+use turbopack_core::chunk::ChunkingContext;
+
+use super::AstPath;
+use crate::{
+    code_gen::{CodeGenerateable, CodeGeneration},
+    create_visitor,
+};
+
+/// Replaces `module.hot` and `import.meta.hot` with the runtime's hot module
+/// replacement API.
+///
+/// When hot module replacement is disabled in the module options or in the
+/// chunking context, the expression is replaced with `undefined` instead, as
+/// the runtime doesn't provide the API then. This is also used to remove whole calls like
+/// `module.hot.accept()` in that case.
+#[turbo_tasks::value(shared)]
+#[derive(Hash, Debug)]
+pub struct ModuleHotReference {
+    ast_path: Vc<AstPath>,
+    enabled: bool,
+}
+
+#[turbo_tasks::value_impl]
+impl ModuleHotReference {
+    #[turbo_tasks::function]
+    pub fn new(ast_path: Vc<AstPath>, enabled: bool) -> Vc<Self> {
+        ModuleHotReference { ast_path, enabled }.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl CodeGenerateable for ModuleHotReference {
+    #[turbo_tasks::function]
+    async fn code_generation(
+        &self,
+        context: Vc<Box<dyn ChunkingContext>>,
+    ) -> Result<Vc<CodeGeneration>> {
+        let ast_path = &self.ast_path.await?;
+        let enabled = self.enabled && *context.is_hot_module_replacement_enabled().await?;
+        let visitor = create_visitor!(ast_path, visit_mut_expr(expr: &mut Expr) {
+            *expr = if enabled {
+                Expr::Ident(Ident::new("__turbopack_hot__".into(), DUMMY_SP))
+            } else {
+                quote!("undefined" as Expr)
+            };
+        });
+
+        Ok(CodeGeneration {
+            visitors: vec![visitor],
+        }
+        .into())
+    }
+}
@@ -0,0 +1,551 @@
+use std::{borrow::Cow, sync::Arc};
+
+use anyhow::{bail, Result};
+use indexmap::IndexMap;
+use swc_core::{
+    common::DUMMY_SP,
+    ecma::{
+        ast::{
+            Expr, ExprStmt, KeyValueProp, Lit, ModuleItem, ObjectLit, Prop, PropName, PropOrSpread,
+            Stmt, {self},
+        },
+        codegen::{text_writer::JsWriter, Emitter},
+    },
+    quote, quote_expr,
+};
+use turbo_tasks::{RcStr, Value, ValueToString, Vc};
+use turbo_tasks_fs::{glob::Glob, DirectoryEntry, FileSystemPath};
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    chunk::{
+        ChunkItem, ChunkItemExt, ChunkType, ChunkableModule, ChunkableModuleReference,
+        ChunkingContext, ChunkingType, ChunkingTypeOption,
+    },
+    ident::AssetIdent,
+    issue::{IssueSeverity, IssueSource},
+    module::Module,
+    reference::{ModuleReference, ModuleReferences},
+    reference_type::EcmaScriptModulesReferenceSubType,
+    resolve::{origin::ResolveOrigin, parse::Request, ModuleResolveResult},
+    source::Source,
+};
+use turbopack_resolve::ecmascript::{esm_resolve, try_to_severity};
+
+use crate::{
+    chunk::{
+        EcmascriptChunkItem, EcmascriptChunkItemContent, EcmascriptChunkType, EcmascriptExports,
+    },
+    code_gen::CodeGeneration,
+    create_visitor,
+    references::{
+        pattern_mapping::{PatternMapping, ResolveType, SinglePatternMapping},
+        AstPath,
+    },
+    utils::module_id_to_lit,
+    CodeGenerateable, EcmascriptChunkPlaceable,
+};
+
+/// Lists the files matching the glob `patterns`, keyed by their path relative
+/// to `dir`. Patterns starting with `!` exclude matches.
+///
+/// Reading goes through [FileSystemPath::read_glob], so the list is invalidated when matching
+/// files are added or removed.
+#[turbo_tasks::function]
+pub(crate) async fn read_glob_patterns(
+    dir: Vc<FileSystemPath>,
+    patterns: Vec<RcStr>,
+) -> Result<Vc<FlatGlobList>> {
+    let dir_value = &*dir.await?;
+
+    let mut excludes = Vec::new();
+    let mut includes = Vec::new();
+    for pattern in &patterns {
+        if let Some(exclude) = pattern.strip_prefix('!') {
+            excludes.push(Glob::parse(exclude)?);
+        } else {
+            includes.push(pattern);
+        }
+    }
+
+    let mut list = IndexMap::new();
+
+    for pattern in includes {
+        // Split the pattern into the static directory it starts from and the
+        // glob, which is matched relative to that directory.
+        let glob_start = pattern.find(['*', '?', '[', '{']).unwrap_or(pattern.len());
+        let base_end = pattern[..glob_start].rfind('/').map_or(0, |i| i + 1);
+        let (base, glob) = pattern.split_at(base_end);
+
+        let mut queue = vec![dir
+            .join(base.trim_end_matches('/').into())
+            .read_glob(Glob::new(glob.into()), false)];
+        while let Some(result) = queue.pop() {
+            let result = result.await?;
+            for entry in result.results.values() {
+                let DirectoryEntry::File(path) = entry else {
+                    continue;
+                };
+                let Some(relative_path) = dir_value.get_relative_path_to(&*path.await?) else {
+                    continue;
+                };
+                if excludes.iter().any(|glob| glob.execute(&relative_path)) {
+                    continue;
+                }
+                list.insert(relative_path, *path);
+            }
+            queue.extend(result.inner.values().copied());
+        }
+    }
+
+    list.sort_keys();
+
+    Ok(Vc::cell(list))
+}
+
+#[turbo_tasks::value(transparent)]
+pub(crate) struct FlatGlobList(IndexMap<RcStr, Vc<FileSystemPath>>);
+
+#[turbo_tasks::value]
+#[derive(Debug)]
+pub struct ImportMetaGlobMapEntry {
+    pub request: Vc<Request>,
+    pub result: Vc<ModuleResolveResult>,
+}
+
+/// The resolved map for an `import.meta.glob(..)` call, keyed by the path of
+/// each matched module relative to the importing module.
+#[turbo_tasks::value(transparent)]
+pub struct ImportMetaGlobMap(IndexMap<RcStr, ImportMetaGlobMapEntry>);
+
+#[turbo_tasks::value_impl]
+impl ImportMetaGlobMap {
+    #[turbo_tasks::function]
+    pub(crate) async fn generate(
+        origin: Vc<Box<dyn ResolveOrigin>>,
+        patterns: Vec<RcStr>,
+        eager: bool,
+        query: Option<RcStr>,
+        issue_source: Option<Vc<IssueSource>>,
+        issue_severity: Vc<IssueSeverity>,
+    ) -> Result<Vc<Self>> {
+        let list = &*read_glob_patterns(origin.origin_path().parent(), patterns).await?;
+
+        let mut map = IndexMap::new();
+
+        for origin_relative in list.keys() {
+            let request = Request::parse(Value::new(
+                format!("{origin_relative}{}", query.as_deref().unwrap_or_default()).into(),
+            ));
+            let result = esm_resolve(
+                origin,
+                request,
+                Value::new(if eager {
+                    EcmaScriptModulesReferenceSubType::Import
+                } else {
+                    EcmaScriptModulesReferenceSubType::DynamicImport
+                }),
+                issue_severity,
+                issue_source,
+            );
+
+            map.insert(
+                origin_relative.clone(),
+                ImportMetaGlobMapEntry { request, result },
+            );
+        }
+
+        Ok(Vc::cell(map))
+    }
+}
+
+/// A reference for `import.meta.glob()`, will replace it with a generated
+/// module that maps the matched paths to their (lazy or eager) imports.
+#[turbo_tasks::value]
+#[derive(Hash, Debug)]
+pub struct ImportMetaGlobAssetReference {
+    pub inner: Vc<ImportMetaGlobAsset>,
+    pub patterns: Vec<RcStr>,
+
+    pub path: Vc<AstPath>,
+    pub issue_source: Option<Vc<IssueSource>>,
+    pub in_try: bool,
+}
+
+#[turbo_tasks::value_impl]
+impl ImportMetaGlobAssetReference {
+    #[turbo_tasks::function]
+    pub fn new(
+        source: Vc<Box<dyn Source>>,
+        origin: Vc<Box<dyn ResolveOrigin>>,
+        patterns: Vec<RcStr>,
+        eager: bool,
+        import: Option<RcStr>,
+        query: Option<RcStr>,
+        import_externals: bool,
+        path: Vc<AstPath>,
+        issue_source: Option<Vc<IssueSource>>,
+        in_try: bool,
+    ) -> Vc<Self> {
+        let map = ImportMetaGlobMap::generate(
+            origin,
+            patterns.clone(),
+            eager,
+            query.clone(),
+            issue_source,
+            try_to_severity(in_try),
+        );
+        let inner = ImportMetaGlobAsset {
+            source,
+            origin,
+            map,
+
+            patterns: patterns.clone(),
+            eager,
+            import,
+            query,
+            import_externals,
+        }
+        .cell();
+
+        Self::cell(ImportMetaGlobAssetReference {
+            inner,
+            patterns,
+            path,
+            issue_source,
+            in_try,
+        })
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ModuleReference for ImportMetaGlobAssetReference {
+    #[turbo_tasks::function]
+    fn resolve_reference(&self) -> Vc<ModuleResolveResult> {
+        ModuleResolveResult::module(Vc::upcast(self.inner)).cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ValueToString for ImportMetaGlobAssetReference {
+    #[turbo_tasks::function]
+    async fn to_string(&self) -> Result<Vc<RcStr>> {
+        Ok(Vc::cell(
+            format!("import.meta.glob {}", self.patterns.join(", ")).into(),
+        ))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ChunkableModuleReference for ImportMetaGlobAssetReference {}
+
+#[turbo_tasks::value_impl]
+impl CodeGenerateable for ImportMetaGlobAssetReference {
+    #[turbo_tasks::function]
+    async fn code_generation(
+        &self,
+        chunking_context: Vc<Box<dyn ChunkingContext>>,
+    ) -> Result<Vc<CodeGeneration>> {
+        let chunk_item = self.inner.as_chunk_item(Vc::upcast(chunking_context));
+        let module_id = chunk_item.id().await?.clone_value();
+
+        let mut visitors = Vec::new();
+
+        let path = &self.path.await?;
+        visitors.push(create_visitor!(path, visit_mut_expr(expr: &mut Expr) {
+            if let Expr::Call(_) = expr {
+                *expr = quote!(
+                    "__turbopack_require__($id)" as Expr,
+                    id: Expr = module_id_to_lit(&module_id)
+                );
+            }
+        }));
+
+        Ok(CodeGeneration { visitors }.into())
+    }
+}
+
+/// A reference from the generated glob module to one of the matched modules.
+/// Lazy matches are put into async chunk groups.
+#[turbo_tasks::value]
+pub struct ImportMetaGlobEntryReference {
+    result: Vc<ModuleResolveResult>,
+    eager: bool,
+}
+
+#[turbo_tasks::value_impl]
+impl ModuleReference for ImportMetaGlobEntryReference {
+    #[turbo_tasks::function]
+    fn resolve_reference(&self) -> Vc<ModuleResolveResult> {
+        self.result
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ValueToString for ImportMetaGlobEntryReference {
+    #[turbo_tasks::function]
+    fn to_string(&self) -> Vc<RcStr> {
+        Vc::cell(
+            if self.eager {
+                "eager import.meta.glob entry"
+            } else {
+                "lazy import.meta.glob entry"
+            }
+            .into(),
+        )
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ChunkableModuleReference for ImportMetaGlobEntryReference {
+    #[turbo_tasks::function]
+    fn chunking_type(&self) -> Vc<ChunkingTypeOption> {
+        Vc::cell(Some(if self.eager {
+            ChunkingType::Parallel
+        } else {
+            ChunkingType::Async
+        }))
+    }
+}
+
+#[turbo_tasks::value]
+pub struct ImportMetaGlobAsset {
+    source: Vc<Box<dyn Source>>,
+
+    origin: Vc<Box<dyn ResolveOrigin>>,
+    map: Vc<ImportMetaGlobMap>,
+
+    patterns: Vec<RcStr>,
+    eager: bool,
+    import: Option<RcStr>,
+    query: Option<RcStr>,
+    import_externals: bool,
+}
+
+#[turbo_tasks::function]
+fn modifier(
+    patterns: Vec<RcStr>,
+    eager: bool,
+    import: Option<RcStr>,
+    query: Option<RcStr>,
+) -> Vc<RcStr> {
+    let mut modifier = format!("import.meta.glob {}", patterns.join(", "));
+    if eager {
+        modifier.push_str(" eager");
+    }
+    if let Some(import) = import {
+        modifier.push_str(&format!(" import {import}"));
+    }
+    if let Some(query) = query {
+        modifier.push_str(&format!(" query {query}"));
+    }
+    Vc::cell(modifier.into())
+}
+
+#[turbo_tasks::value_impl]
+impl Module for ImportMetaGlobAsset {
+    #[turbo_tasks::function]
+    fn ident(&self) -> Vc<AssetIdent> {
+        self.source.ident().with_modifier(modifier(
+            self.patterns.clone(),
+            self.eager,
+            self.import.clone(),
+            self.query.clone(),
+        ))
+    }
+
+    #[turbo_tasks::function]
+    async fn references(&self) -> Result<Vc<ModuleReferences>> {
+        let map = &*self.map.await?;
+
+        Ok(Vc::cell(
+            map.values()
+                .map(|entry| {
+                    Vc::upcast(
+                        ImportMetaGlobEntryReference {
+                            result: entry.result,
+                            eager: self.eager,
+                        }
+                        .cell(),
+                    )
+                })
+                .collect(),
+        ))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for ImportMetaGlobAsset {
+    #[turbo_tasks::function]
+    fn content(&self) -> Result<Vc<AssetContent>> {
+        bail!("import.meta.glob module has no contents, its code is generated by the chunk item")
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ChunkableModule for ImportMetaGlobAsset {
+    #[turbo_tasks::function]
+    fn as_chunk_item(
+        self: Vc<Self>,
+        chunking_context: Vc<Box<dyn ChunkingContext>>,
+    ) -> Vc<Box<dyn turbopack_core::chunk::ChunkItem>> {
+        Vc::upcast(
+            ImportMetaGlobChunkItem {
+                chunking_context,
+                inner: self,
+            }
+            .cell(),
+        )
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl EcmascriptChunkPlaceable for ImportMetaGlobAsset {
+    #[turbo_tasks::function]
+    fn get_exports(&self) -> Vc<EcmascriptExports> {
+        EcmascriptExports::Value.cell()
+    }
+}
+
+#[turbo_tasks::value]
+pub struct ImportMetaGlobChunkItem {
+    chunking_context: Vc<Box<dyn ChunkingContext>>,
+    inner: Vc<ImportMetaGlobAsset>,
+}
+
+#[turbo_tasks::value_impl]
+impl EcmascriptChunkItem for ImportMetaGlobChunkItem {
+    #[turbo_tasks::function]
+    fn chunking_context(&self) -> Vc<Box<dyn ChunkingContext>> {
+        self.chunking_context
+    }
+
+    #[turbo_tasks::function]
+    async fn content(&self) -> Result<Vc<EcmascriptChunkItemContent>> {
+        let inner = self.inner.await?;
+        let map = &*inner.map.await?;
+
+        let mut glob_map = ObjectLit {
+            span: DUMMY_SP,
+            props: vec![],
+        };
+
+        for (key, entry) in map {
+            let pm = PatternMapping::resolve_request(
+                entry.request,
+                inner.origin,
+                Vc::upcast(self.chunking_context),
+                entry.result,
+                Value::new(if inner.eager {
+                    ResolveType::ChunkItem
+                } else {
+                    ResolveType::AsyncChunkLoader
+                }),
+            )
+            .await?;
+
+            let PatternMapping::Single(pm) = &*pm else {
+                continue;
+            };
+
+            let key_expr = Expr::Lit(Lit::Str(key.as_str().into()));
+
+            let value = if inner.eager {
+                let module = match pm {
+                    SinglePatternMapping::Module(module_id) => quote_expr!(
+                        "__turbopack_import__($id)",
+                        id: Expr = module_id_to_lit(module_id)
+                    ),
+                    _ => Box::new(pm.create_require(Cow::Borrowed(&key_expr))),
+                };
+                match &inner.import {
+                    Some(import) => quote_expr!(
+                        "$module[$import]",
+                        module: Expr = *module,
+                        import: Expr = import.as_str().into(),
+                    ),
+                    None => module,
+                }
+            } else {
+                let module = pm.create_import(Cow::Borrowed(&key_expr), inner.import_externals);
+                match &inner.import {
+                    Some(import) => quote_expr!(
+                        "() => $module.then((m) => m[$import])",
+                        module: Expr = module,
+                        import: Expr = import.as_str().into(),
+                    ),
+                    None => quote_expr!("() => $module", module: Expr = module),
+                }
+            };
+
+            let prop = KeyValueProp {
+                key: PropName::Str(key.as_str().into()),
+                value,
+            };
+
+            glob_map
+                .props
+                .push(PropOrSpread::Prop(Box::new(Prop::KeyValue(prop))));
+        }
+
+        let expr = quote_expr!(
+            "__turbopack_export_value__($obj);",
+            obj: Expr = Expr::Object(glob_map),
+        );
+
+        let module = ast::Module {
+            span: DUMMY_SP,
+            body: vec![ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+                span: DUMMY_SP,
+                expr,
+            }))],
+            shebang: None,
+        };
+
+        let source_map: Arc<swc_core::common::SourceMap> = Default::default();
+        let mut bytes: Vec<u8> = vec![];
+        let mut emitter = Emitter {
+            cfg: swc_core::ecma::codegen::Config::default(),
+            cm: source_map.clone(),
+            comments: None,
+            wr: JsWriter::new(source_map, "\n", &mut bytes, None),
+        };
+
+        emitter.emit_module(&module)?;
+
+        Ok(EcmascriptChunkItemContent {
+            inner_code: bytes.into(),
+            ..Default::default()
+        }
+        .cell())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ChunkItem for ImportMetaGlobChunkItem {
+    #[turbo_tasks::function]
+    fn asset_ident(&self) -> Vc<AssetIdent> {
+        self.inner.ident()
+    }
+
+    #[turbo_tasks::function]
+    fn references(&self) -> Vc<ModuleReferences> {
+        self.inner.references()
+    }
+
+    #[turbo_tasks::function]
+    async fn chunking_context(&self) -> Vc<Box<dyn ChunkingContext>> {
+        Vc::upcast(self.chunking_context)
+    }
+
+    #[turbo_tasks::function]
+    async fn ty(&self) -> Result<Vc<Box<dyn ChunkType>>> {
+        Ok(Vc::upcast(
+            Vc::<EcmascriptChunkType>::default().resolve().await?,
+        ))
+    }
+
+    #[turbo_tasks::function]
+    fn module(&self) -> Vc<Box<dyn Module>> {
+        Vc::upcast(self.inner)
+    }
+}
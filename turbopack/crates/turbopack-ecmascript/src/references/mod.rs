@@ -2,10 +2,13 @@ pub mod amd;
 pub mod async_module;
 pub mod cjs;
 pub mod constant_condition;
+pub mod constant_exports;
 pub mod constant_value;
 pub mod dynamic_expression;
 pub mod esm;
 pub mod external_module;
+pub mod hot;
+pub mod import_meta_glob;
 pub mod node;
 pub mod pattern_mapping;
 pub mod raw;
@@ -14,10 +17,11 @@ pub mod type_issue;
 pub mod typescript;
 pub mod unreachable;
 pub mod util;
+pub mod worker;
 
 use std::{
     borrow::Cow,
-    collections::{BTreeMap, HashMap},
+    collections::{hash_map::Entry, BTreeMap, HashMap},
     future::Future,
     mem::take,
     pin::Pin,
@@ -46,7 +50,10 @@ use swc_core::{
     ecma::{
         ast::*,
         visit::{
-            fields::{AssignExprField, AssignTargetField, SimpleAssignTargetField},
+            fields::{
+                AssignExprField, AssignTargetField, ExprField, MemberExprField,
+                SimpleAssignTargetField,
+            },
             AstParentKind, AstParentNodeRef, VisitAstPath, VisitWithPath,
         },
     },
@@ -84,6 +91,7 @@ use self::{
         AmdDefineWithDependenciesCodeGen,
     },
     cjs::CjsAssetReference,
+    constant_exports::{imported_constant_exports, CROSS_MODULE_BUDGET},
     esm::{
         binding::EsmBindings, export::EsmExport, EsmAssetReference, EsmAsyncAssetReference,
         EsmExports, EsmModuleItem, ImportMetaBinding, ImportMetaRef, UrlAssetReference,
@@ -91,6 +99,7 @@ use self::{
     node::DirAssetReference,
     raw::FileSourceReference,
     typescript::{TsConfigReference, TsReferencePathAssetReference, TsReferenceTypeAssetReference},
+    worker::WorkerAssetReference,
 };
 use super::{
     analyzer::{
@@ -117,7 +126,7 @@ use crate::{
         builtin::early_replace_builtin,
         graph::{ConditionalKind, EffectArg, EvalContext, VarGraph},
         imports::{ImportAnnotations, ImportedSymbol, Reexport},
-        parse_require_context,
+        parse_import_meta_glob, parse_require_context,
         top_level_await::has_top_level_await,
         ConstantNumber, ConstantString, ModuleValue, RequireContextValue,
     },
@@ -128,9 +137,14 @@ use crate::{
         async_module::{AsyncModule, OptionAsyncModule},
         cjs::{CjsRequireAssetReference, CjsRequireCacheAccess, CjsRequireResolveAssetReference},
         dynamic_expression::DynamicExpression,
-        esm::{module_id::EsmModuleIdAssetReference, EsmBinding, UrlRewriteBehavior},
+        esm::{
+            module_id::EsmModuleIdAssetReference, EsmBinding, ImportUsage, ImportUsages,
+            UrlRewriteBehavior,
+        },
+        hot::ModuleHotReference,
+        import_meta_glob::ImportMetaGlobAssetReference,
         node::PackageJsonReference,
-        require_context::{RequireContextAssetReference, RequireContextMap},
+        require_context::{RequireContextAssetReference, RequireContextMap, RequireContextMode},
         type_issue::SpecifiedModuleTypeIssue,
     },
     tree_shake::{find_turbopack_part_id_in_asserts, part_of_module, split},
@@ -152,6 +166,8 @@ pub struct AnalyzeEcmascriptModuleResult {
     /// `true` when the analysis was successful.
     pub successful: bool,
     pub source_map: Vc<OptionSourceMap>,
+    /// The imported bindings which are actually used by the module.
+    pub import_usages: Vc<ImportUsages>,
 }
 
 /// A temporary analysis result builder to pass around, to be turned into an
@@ -167,6 +183,7 @@ pub struct AnalyzeEcmascriptModuleResultBuilder {
     successful: bool,
     source_map: Option<Vc<OptionSourceMap>>,
     bindings: Vec<EsmBinding>,
+    import_usages: IndexSet<(Vc<Box<dyn ModuleReference>>, ImportUsage)>,
 }
 
 impl AnalyzeEcmascriptModuleResultBuilder {
@@ -182,6 +199,7 @@ impl AnalyzeEcmascriptModuleResultBuilder {
             successful: false,
             source_map: None,
             bindings: Vec::new(),
+            import_usages: IndexSet::new(),
         }
     }
 
@@ -249,6 +267,14 @@ impl AnalyzeEcmascriptModuleResultBuilder {
         self.bindings.push(binding);
     }
 
+    /// Records how an import is used by the module.
+    pub fn add_import_usage<R>(&mut self, reference: Vc<R>, usage: ImportUsage)
+    where
+        R: Upcast<Box<dyn ModuleReference>>,
+    {
+        self.import_usages.insert((Vc::upcast(reference), usage));
+    }
+
     /// Sets the analysis result ES export.
     pub fn set_source_map(&mut self, source_map: Vc<OptionSourceMap>) {
         self.source_map = Some(source_map);
@@ -334,6 +360,7 @@ impl AnalyzeEcmascriptModuleResultBuilder {
                 async_module: self.async_module,
                 successful: self.successful,
                 source_map,
+                import_usages: Vc::cell(self.import_usages.into_iter().collect()),
             },
         ))
     }
@@ -360,16 +387,32 @@ struct AnalysisState<'a> {
     tree_shaking_mode: Option<TreeShakingMode>,
     import_externals: bool,
     ignore_dynamic_requests: bool,
+    hot_module_replacement: bool,
 }
 
 impl<'a> AnalysisState<'a> {
     async fn link_value(&self, value: JsValue, in_try: bool) -> Result<JsValue> {
         let fun_args_values = self.fun_args_values.lock().clone();
+        let origin = self.origin;
+        let compile_time_info = self.compile_time_info;
+        let hot_module_replacement = self.hot_module_replacement;
         link(
             self.var_graph,
             value,
-            &early_value_visitor,
-            &|value| value_visitor(self.origin, value, self.compile_time_info, in_try),
+            &|value| early_value_visitor(origin, value, CROSS_MODULE_BUDGET),
+            &|value| async move {
+                // Without hot module replacement, `module.hot` is compiled to
+                // `undefined`, so e. g. `if (module.hot) { ... }` can be removed.
+                if !hot_module_replacement
+                    && matches!(
+                        value,
+                        JsValue::WellKnownObject(WellKnownObjectKind::ModuleHot)
+                    )
+                {
+                    return Ok((JsValue::Constant(JsConstantValue::Undefined), true));
+                }
+                value_visitor(origin, value, compile_time_info, in_try).await
+            },
             fun_args_values,
         )
         .await
@@ -383,6 +426,43 @@ where
     HANDLER.set(handler, || GLOBALS.set(globals, f))
 }
 
+/// The [VarGraph] of a parsed module, or [None] when it failed to parse. It's
+/// shared by the analysis of the module and by the lookup of its constant
+/// exports.
+#[turbo_tasks::value(shared, serialization = "none", eq = "manual")]
+pub(crate) struct ParsedVarGraph(#[turbo_tasks(debug_ignore, trace_ignore)] Option<VarGraph>);
+
+impl PartialEq for ParsedVarGraph {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ParsedVarGraph {
+    #[turbo_tasks::function]
+    pub(crate) async fn new(parsed: Vc<ParseResult>) -> Result<Vc<Self>> {
+        let parsed = parsed.await?;
+        let ParseResult::Ok {
+            program,
+            globals,
+            eval_context,
+            ..
+        } = &*parsed
+        else {
+            return Ok(ParsedVarGraph(None).cell());
+        };
+        let var_graph = GLOBALS.set(globals, || create_graph(program, eval_context));
+        Ok(ParsedVarGraph(Some(var_graph)).cell())
+    }
+}
+
+impl ParsedVarGraph {
+    pub(crate) fn graph(&self) -> Option<&VarGraph> {
+        self.0.as_ref()
+    }
+}
+
 #[turbo_tasks::function]
 pub(crate) async fn analyse_ecmascript_module(
     module: Vc<EcmascriptModuleAsset>,
@@ -459,6 +539,22 @@ pub(crate) async fn analyse_ecmascript_module_internal(
 
     special_cases(&path.await?.path, &mut analysis);
 
+    if let Some(detectors) = options.native_binding_detectors {
+        use turbopack_resolve::node_native_binding::{
+            has_native_bindings, NativeBindingsReference,
+        };
+
+        let compile_target = compile_time_info.environment().compile_target();
+        if *has_native_bindings(path.parent(), compile_target, detectors).await? {
+            analysis.add_reference(NativeBindingsReference::new(
+                path.parent(),
+                compile_target,
+                detectors,
+            ));
+        }
+    }
+
+    let var_graph = ParsedVarGraph::new(parsed);
     let parsed = parsed.await?;
 
     let ParseResult::Ok {
@@ -561,8 +657,10 @@ pub(crate) async fn analyse_ecmascript_module_internal(
         Box::new(IssueEmitter::new(source, source_map.clone(), None)),
     );
 
-    let mut var_graph =
-        set_handler_and_globals(&handler, globals, || create_graph(program, eval_context));
+    let var_graph = var_graph.await?;
+    let Some(var_graph) = var_graph.graph() else {
+        return analysis.build(false).await;
+    };
 
     let mut evaluation_references = Vec::new();
 
@@ -619,6 +717,15 @@ pub(crate) async fn analyse_ecmascript_module_internal(
         analysis.add_import_reference(reference);
     }
 
+    for (i, members) in eval_context.imports.static_default_imports() {
+        for member in members {
+            analysis.add_import_usage(
+                import_references[i],
+                ImportUsage::DefaultExportMember(member.as_str().into()),
+            );
+        }
+    }
+
     let (webpack_runtime, webpack_entry, webpack_chunks, esm_exports, esm_star_exports) =
         set_handler_and_globals(&handler, globals, || {
             // TODO migrate to effects
@@ -814,19 +921,20 @@ pub(crate) async fn analyse_ecmascript_module_internal(
 
     analysis.set_exports(exports);
 
-    let effects = take(&mut var_graph.effects);
+    let effects = var_graph.effects.clone();
 
     let mut analysis_state = AnalysisState {
         handler: &handler,
         source,
         origin,
         compile_time_info,
-        var_graph: &var_graph,
+        var_graph,
         fun_args_values: Mutex::new(HashMap::<u32, Vec<JsValue>>::new()),
         first_import_meta: true,
         tree_shaking_mode: options.tree_shaking_mode,
         import_externals: options.import_externals,
         ignore_dynamic_requests: options.ignore_dynamic_requests,
+        hot_module_replacement: options.hot_module_replacement,
     };
 
     enum Action {
@@ -839,6 +947,9 @@ pub(crate) async fn analyse_ecmascript_module_internal(
     // processing. Using a stack where effects are appended in reverse
     // order allows us to do that. It's recursion implemented as Stack.
     let mut queue_stack = Mutex::new(Vec::new());
+    // References to single exports of namespace imports, keyed by the index of
+    // the namespace import and the export name
+    let mut namespace_member_references = HashMap::new();
     queue_stack
         .get_mut()
         .extend(effects.into_iter().map(Action::Effect).rev());
@@ -1046,6 +1157,17 @@ pub(crate) async fn analyse_ecmascript_module_internal(
                         continue;
                     }
                 }
+                if !analysis_state.hot_module_replacement
+                    && matches!(
+                        obj,
+                        JsValue::WellKnownObject(WellKnownObjectKind::ModuleHot)
+                    )
+                {
+                    // e. g. `module.hot.accept()` is removed together with its
+                    // arguments, so effects of closures passed to it are skipped.
+                    analysis.add_code_gen(ModuleHotReference::new(Vc::cell(ast_path), false));
+                    continue;
+                }
                 let mut obj = analysis_state.link_value(obj, in_try).await?;
                 let prop = analysis_state.link_value(prop, in_try).await?;
 
@@ -1134,10 +1256,75 @@ pub(crate) async fn analyse_ecmascript_module_internal(
                     } else {
                         analysis.add_local_reference(*r);
                         analysis.add_import_reference(*r);
+                        let is_static_default_import = export.as_deref() == Some("default")
+                            && eval_context
+                                .imports
+                                .get_static_default_import(esm_reference_index)
+                                .is_some();
+                        // The read members of static default imports are already recorded
+                        if !is_static_default_import {
+                            analysis.add_import_usage(
+                                *r,
+                                match &export {
+                                    Some(export) => ImportUsage::Export(export.clone()),
+                                    None => ImportUsage::Namespace,
+                                },
+                            );
+                        }
                         analysis.add_binding(EsmBinding::new(*r, export, Vc::cell(ast_path)));
                     }
                 }
             }
+            Effect::ImportedNamespaceMember {
+                esm_reference_index,
+                export,
+                mut ast_path,
+                span: _,
+                in_try: _,
+            } => {
+                if matches!(
+                    options.tree_shaking_mode,
+                    Some(TreeShakingMode::ModuleFragments)
+                ) {
+                    // Only the accessed export is referenced, so the other exports
+                    // of the namespace can be tree shaken away.
+                    let Some(import) = eval_context.imports.reference(esm_reference_index) else {
+                        continue;
+                    };
+                    let r = match namespace_member_references
+                        .entry((esm_reference_index, export.clone()))
+                    {
+                        Entry::Occupied(e) => *e.get(),
+                        Entry::Vacant(e) => {
+                            let r = EsmAssetReference::new(
+                                origin,
+                                Request::parse(Value::new(
+                                    RcStr::from(&*import.module_path).into(),
+                                )),
+                                import.issue_source,
+                                Value::new(import.annotations.clone()),
+                                Some(ModulePart::export(export.clone())),
+                                import_externals,
+                            )
+                            .resolve()
+                            .await?;
+                            *e.insert(r)
+                        }
+                    };
+                    analysis.add_local_reference(r);
+                    analysis.add_import_reference(r);
+                    analysis.add_import_usage(r, ImportUsage::Export(export.clone()));
+                    analysis.add_binding(EsmBinding::new(r, Some(export), Vc::cell(ast_path)));
+                } else if let Some(r) = import_references.get(esm_reference_index) {
+                    // Bind the namespace object itself, i.e. the `ns` in `ns.a`
+                    ast_path.push(AstParentKind::MemberExpr(MemberExprField::Obj));
+                    ast_path.push(AstParentKind::Expr(ExprField::Ident));
+                    analysis.add_local_reference(*r);
+                    analysis.add_import_reference(*r);
+                    analysis.add_import_usage(*r, ImportUsage::Export(export));
+                    analysis.add_binding(EsmBinding::new(*r, None, Vc::cell(ast_path)));
+                }
+            }
             Effect::ImportMeta {
                 ast_path,
                 span: _,
@@ -1150,6 +1337,16 @@ pub(crate) async fn analyse_ecmascript_module_internal(
 
                 analysis.add_code_gen(ImportMetaRef::new(Vc::cell(ast_path)));
             }
+            Effect::ModuleHot {
+                ast_path,
+                span: _,
+                in_try: _,
+            } => {
+                analysis.add_code_gen(ModuleHotReference::new(
+                    Vc::cell(ast_path),
+                    analysis_state.hot_module_replacement,
+                ));
+            }
             Effect::Url {
                 input,
                 ast_path,
@@ -1182,6 +1379,88 @@ pub(crate) async fn analyse_ecmascript_module_internal(
                         .cell(),
                 ));
             }
+            Effect::ImportMetaGlob {
+                args,
+                ast_path,
+                span,
+                in_try,
+            } => {
+                let mut linked_args = Vec::with_capacity(args.len());
+                for arg in args {
+                    let value = match arg {
+                        EffectArg::Value(value) => value,
+                        EffectArg::Closure(value, block) => {
+                            add_effects(block.effects);
+                            value
+                        }
+                        EffectArg::Spread => JsValue::unknown_empty(
+                            true,
+                            "spread in import.meta.glob() is not supported",
+                        ),
+                    };
+                    linked_args.push(analysis_state.link_value(value, in_try).await?);
+                }
+                let glob_options = match parse_import_meta_glob(&linked_args) {
+                    Ok(glob_options) => glob_options,
+                    Err(err) => {
+                        let (args, hints) = JsValue::explain_args(&linked_args, 10, 2);
+                        handler.span_err_with_code(
+                            span,
+                            &format!(
+                                "import.meta.glob({args}) is not statically analyze-able: \
+                                 {}{hints}",
+                                PrettyPrintError(&err)
+                            ),
+                            DiagnosticId::Error(
+                                errors::failed_to_analyse::ecmascript::IMPORT_META_GLOB.to_string(),
+                            ),
+                        );
+                        continue;
+                    }
+                };
+                analysis.add_reference(ImportMetaGlobAssetReference::new(
+                    source,
+                    origin,
+                    glob_options.patterns,
+                    glob_options.eager,
+                    glob_options.import,
+                    glob_options.query,
+                    options.import_externals,
+                    Vc::cell(ast_path),
+                    Some(issue_source(source, span)),
+                    in_try,
+                ));
+            }
+            Effect::Worker {
+                input,
+                kind,
+                ast_path,
+                span,
+                in_try,
+            } => {
+                let pat = js_value_to_pattern(&input);
+                if !pat.has_constant_parts() {
+                    handler.span_warn_with_code(
+                        span,
+                        &format!("new {kind:?}(new URL({input}, import.meta.url)) is very dynamic"),
+                        DiagnosticId::Lint(
+                            errors::failed_to_analyse::ecmascript::NEW_WORKER_IMPORT_META
+                                .to_string(),
+                        ),
+                    );
+                    if options.ignore_dynamic_requests {
+                        continue;
+                    }
+                }
+                analysis.add_reference(WorkerAssetReference::new(
+                    origin,
+                    Request::parse(Value::new(pat)),
+                    Value::new(kind),
+                    Vc::cell(ast_path),
+                    IssueSource::from_swc_offsets(source, span.lo.to_usize(), span.hi.to_usize()),
+                    in_try,
+                ));
+            }
         }
     }
 
@@ -1432,6 +1711,7 @@ async fn handle_call<G: Fn(Vec<Effect>) + Send + Sync>(
                 options.dir,
                 options.include_subdirs,
                 Vc::cell(options.filter),
+                Value::new(options.mode),
                 Vc::cell(ast_path.to_vec()),
                 Some(issue_source(source, span)),
                 in_try,
@@ -1466,6 +1746,37 @@ async fn handle_call<G: Fn(Vec<Effect>) + Send + Sync>(
             )
         }
 
+        JsValue::WellKnownFunction(WellKnownFunctionKind::ProcessDlopen) => {
+            let args = linked_args(args).await?;
+            // process.dlopen(module, filename[, flags])
+            if args.len() >= 2 {
+                let pat = js_value_to_pattern(&args[1]);
+                if !pat.has_constant_parts() {
+                    let (args, hints) = explain_args(&args);
+                    handler.span_warn_with_code(
+                        span,
+                        &format!("process.dlopen({args}) is very dynamic{hints}",),
+                        DiagnosticId::Lint(
+                            errors::failed_to_analyse::ecmascript::PROCESS_DLOPEN.to_string(),
+                        ),
+                    );
+                    if ignore_dynamic_requests {
+                        return Ok(());
+                    }
+                }
+                analysis.add_reference(FileSourceReference::new(source, Pattern::new(pat)));
+                return Ok(());
+            }
+            let (args, hints) = explain_args(&args);
+            handler.span_warn_with_code(
+                span,
+                &format!("process.dlopen({args}) is not statically analyse-able{hints}",),
+                DiagnosticId::Error(
+                    errors::failed_to_analyse::ecmascript::PROCESS_DLOPEN.to_string(),
+                ),
+            )
+        }
+
         JsValue::WellKnownFunction(WellKnownFunctionKind::PathResolve(..)) => {
             let parent_path = origin.origin_path().parent().await?;
             let args = linked_args(args).await?;
@@ -2250,7 +2561,36 @@ async fn require_resolve(path: Vc<FileSystemPath>) -> Result<JsValue> {
     Ok(format!("/ROOT/{}", path.await?.path.as_str()).into())
 }
 
-async fn early_value_visitor(mut v: JsValue) -> Result<(JsValue, bool)> {
+async fn early_value_visitor(
+    origin: Vc<Box<dyn ResolveOrigin>>,
+    mut v: JsValue,
+    cross_module_budget: u32,
+) -> Result<(JsValue, bool)> {
+    // Imported bindings are members of an (unknown) module. They are replaced
+    // with the value of the export when it's a constant.
+    if let JsValue::Member(_, box JsValue::Unknown { original_value, .. }, box prop) = &v {
+        if let (
+            Some(JsValue::Module(ModuleValue {
+                module,
+                kind,
+                annotations,
+            })),
+            Some(export),
+        ) = (original_value.as_deref(), prop.as_str())
+        {
+            let constants = imported_constant_exports(
+                origin,
+                module.as_str().into(),
+                *kind,
+                Value::new(annotations.clone()),
+                cross_module_budget,
+            )
+            .await?;
+            if let Some(constant) = constants.get(export) {
+                return Ok((JsValue::Constant(constant.clone()), true));
+            }
+        }
+    }
     let modified = early_replace_builtin(&mut v);
     Ok((v, modified))
 }
@@ -2451,6 +2791,24 @@ async fn require_context_visitor(
         }
     };
 
+    if !matches!(options.mode, RequireContextMode::Sync) {
+        // the matched modules are either loaded asynchronously or not bundled at
+        // all, so requiring from the context can't be resolved to a module
+        return Ok(JsValue::unknown(
+            JsValue::call(
+                Box::new(JsValue::WellKnownFunction(
+                    WellKnownFunctionKind::RequireContext,
+                )),
+                args,
+            ),
+            false,
+            format!(
+                "require.context() with mode \"{}\" can't be resolved statically",
+                options.mode.as_str()
+            ),
+        ));
+    }
+
     let dir = origin.origin_path().parent().join(options.dir.clone());
 
     let map = RequireContextMap::generate(
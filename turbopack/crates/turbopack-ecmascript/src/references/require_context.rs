@@ -19,7 +19,7 @@ use turbopack_core::{
     asset::{Asset, AssetContent},
     chunk::{
         ChunkItem, ChunkItemExt, ChunkType, ChunkableModule, ChunkableModuleReference,
-        ChunkingContext,
+        ChunkingContext, ChunkingType, ChunkingTypeOption,
     },
     ident::AssetIdent,
     issue::{IssueSeverity, IssueSource},
@@ -44,6 +44,50 @@ use crate::{
     CodeGenerateable, EcmascriptChunkPlaceable,
 };
 
+/// The `mode` argument of `require.context()`, which determines how the
+/// matched modules are loaded.
+#[turbo_tasks::value(serialization = "auto_for_input")]
+#[derive(Debug, Default, Copy, Clone, Hash)]
+pub enum RequireContextMode {
+    /// The matched modules are bundled with the context and required
+    /// synchronously.
+    #[default]
+    Sync,
+    /// The matched modules are bundled with the context, but requiring them
+    /// returns a promise.
+    Eager,
+    /// The matched modules are not bundled. Requiring them only succeeds when
+    /// the module was already loaded by other means.
+    Weak,
+    /// Each matched module is placed into its own async chunk group.
+    Lazy,
+    /// All matched modules are placed into a single async chunk group.
+    LazyOnce,
+}
+
+impl RequireContextMode {
+    pub fn parse(mode: &str) -> Option<Self> {
+        match mode {
+            "sync" => Some(Self::Sync),
+            "eager" => Some(Self::Eager),
+            "weak" => Some(Self::Weak),
+            "lazy" => Some(Self::Lazy),
+            "lazy-once" => Some(Self::LazyOnce),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Sync => "sync",
+            Self::Eager => "eager",
+            Self::Weak => "weak",
+            Self::Lazy => "lazy",
+            Self::LazyOnce => "lazy-once",
+        }
+    }
+}
+
 #[turbo_tasks::value]
 #[derive(Debug)]
 pub(crate) enum DirListEntry {
@@ -203,6 +247,7 @@ pub struct RequireContextAssetReference {
     pub inner: Vc<RequireContextAsset>,
     pub dir: RcStr,
     pub include_subdirs: bool,
+    pub mode: RequireContextMode,
 
     pub path: Vc<AstPath>,
     pub issue_source: Option<Vc<IssueSource>>,
@@ -218,10 +263,12 @@ impl RequireContextAssetReference {
         dir: RcStr,
         include_subdirs: bool,
         filter: Vc<Regex>,
+        mode: Value<RequireContextMode>,
         path: Vc<AstPath>,
         issue_source: Option<Vc<IssueSource>>,
         in_try: bool,
     ) -> Vc<Self> {
+        let mode = mode.into_value();
         let map = RequireContextMap::generate(
             origin,
             origin.origin_path().parent().join(dir.clone()),
@@ -237,6 +284,7 @@ impl RequireContextAssetReference {
 
             dir: dir.clone(),
             include_subdirs,
+            mode,
         }
         .cell();
 
@@ -244,6 +292,7 @@ impl RequireContextAssetReference {
             inner,
             dir,
             include_subdirs,
+            mode,
             path,
             issue_source,
             in_try,
@@ -263,13 +312,10 @@ impl ModuleReference for RequireContextAssetReference {
 impl ValueToString for RequireContextAssetReference {
     #[turbo_tasks::function]
     async fn to_string(&self) -> Result<Vc<RcStr>> {
-        Ok(Vc::cell(
-            format!(
-                "require.context {}/{}",
-                self.dir,
-                if self.include_subdirs { "**" } else { "*" },
-            )
-            .into(),
+        Ok(modifier(
+            self.dir.clone(),
+            self.include_subdirs,
+            Value::new(self.mode),
         ))
     }
 }
@@ -325,6 +371,35 @@ impl ValueToString for ResolvedModuleReference {
 #[turbo_tasks::value_impl]
 impl ChunkableModuleReference for ResolvedModuleReference {}
 
+/// Like [ResolvedModuleReference], but places the referenced modules into a
+/// separate async chunk group. Used by the `lazy` and `lazy-once` modes.
+#[turbo_tasks::value(transparent)]
+pub struct AsyncResolvedModuleReference(Vc<ModuleResolveResult>);
+
+#[turbo_tasks::value_impl]
+impl ModuleReference for AsyncResolvedModuleReference {
+    #[turbo_tasks::function]
+    fn resolve_reference(&self) -> Vc<ModuleResolveResult> {
+        self.0
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ValueToString for AsyncResolvedModuleReference {
+    #[turbo_tasks::function]
+    async fn to_string(&self) -> Result<Vc<RcStr>> {
+        Ok(Vc::cell("resolved async reference".into()))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ChunkableModuleReference for AsyncResolvedModuleReference {
+    #[turbo_tasks::function]
+    fn chunking_type(&self) -> Vc<ChunkingTypeOption> {
+        Vc::cell(Some(ChunkingType::Async))
+    }
+}
+
 #[turbo_tasks::value]
 pub struct RequireContextAsset {
     source: Vc<Box<dyn Source>>,
@@ -334,38 +409,76 @@ pub struct RequireContextAsset {
 
     dir: RcStr,
     include_subdirs: bool,
+    mode: RequireContextMode,
+}
+
+#[turbo_tasks::value_impl]
+impl RequireContextAsset {
+    /// The module that bundles all matched modules of a `lazy-once` context,
+    /// so they can be loaded with a single async chunk group.
+    #[turbo_tasks::function]
+    fn lazy_once_group(&self) -> Vc<Self> {
+        RequireContextAsset {
+            source: self.source,
+            origin: self.origin,
+            map: self.map,
+            dir: self.dir.clone(),
+            include_subdirs: self.include_subdirs,
+            mode: RequireContextMode::Sync,
+        }
+        .cell()
+    }
 }
 
 #[turbo_tasks::function]
-fn modifier(dir: RcStr, include_subdirs: bool) -> Vc<RcStr> {
-    Vc::cell(
-        format!(
-            "require.context {}/{}",
-            dir,
-            if include_subdirs { "**" } else { "*" },
-        )
-        .into(),
-    )
+fn modifier(dir: RcStr, include_subdirs: bool, mode: Value<RequireContextMode>) -> Vc<RcStr> {
+    let mode = mode.into_value();
+    let mut modifier = format!(
+        "require.context {}/{}",
+        dir,
+        if include_subdirs { "**" } else { "*" },
+    );
+    if !matches!(mode, RequireContextMode::Sync) {
+        modifier.push_str(&format!(" ({})", mode.as_str()));
+    }
+    Vc::cell(modifier.into())
 }
 
 #[turbo_tasks::value_impl]
 impl Module for RequireContextAsset {
     #[turbo_tasks::function]
     fn ident(&self) -> Vc<AssetIdent> {
-        self.source
-            .ident()
-            .with_modifier(modifier(self.dir.clone(), self.include_subdirs))
+        self.source.ident().with_modifier(modifier(
+            self.dir.clone(),
+            self.include_subdirs,
+            Value::new(self.mode),
+        ))
     }
 
     #[turbo_tasks::function]
-    async fn references(&self) -> Result<Vc<ModuleReferences>> {
-        let map = &*self.map.await?;
+    async fn references(self: Vc<Self>) -> Result<Vc<ModuleReferences>> {
+        let this = self.await?;
+        let map = &*this.map.await?;
 
-        Ok(Vc::cell(
-            map.iter()
+        Ok(Vc::cell(match this.mode {
+            RequireContextMode::Sync | RequireContextMode::Eager => map
+                .iter()
                 .map(|(_, entry)| Vc::upcast(Vc::<ResolvedModuleReference>::cell(entry.result)))
                 .collect(),
-        ))
+            RequireContextMode::Lazy => map
+                .iter()
+                .map(|(_, entry)| {
+                    Vc::upcast(Vc::<AsyncResolvedModuleReference>::cell(entry.result))
+                })
+                .collect(),
+            RequireContextMode::LazyOnce => {
+                vec![Vc::upcast(Vc::<AsyncResolvedModuleReference>::cell(
+                    ModuleResolveResult::module(Vc::upcast(self.lazy_once_group())).cell(),
+                ))]
+            }
+            // weak modules are never loaded by the context itself
+            RequireContextMode::Weak => vec![],
+        }))
     }
 }
 
@@ -392,6 +505,7 @@ impl ChunkableModule for RequireContextAsset {
 
                 origin: this.origin,
                 map: this.map,
+                mode: this.mode,
             }
             .cell(),
         ))
@@ -413,6 +527,7 @@ pub struct RequireContextChunkItem {
 
     origin: Vc<Box<dyn ResolveOrigin>>,
     map: Vc<RequireContextMap>,
+    mode: RequireContextMode,
 }
 
 #[turbo_tasks::value_impl]
@@ -431,6 +546,19 @@ impl EcmascriptChunkItem for RequireContextChunkItem {
             props: vec![],
         };
 
+        let lazy_once_loader = if let RequireContextMode::LazyOnce = self.mode {
+            let loader_id = self
+                .chunking_context
+                .async_loader_chunk_item_id(Vc::upcast(self.inner.lazy_once_group()))
+                .await?;
+            Some(quote_expr!(
+                "__turbopack_require__($id)(__turbopack_import__)",
+                id: Expr = module_id_to_lit(&loader_id)
+            ))
+        } else {
+            None
+        };
+
         for (key, entry) in map {
             let pm = PatternMapping::resolve_request(
                 entry.request,
@@ -447,14 +575,47 @@ impl EcmascriptChunkItem for RequireContextChunkItem {
 
             let key_expr = Expr::Lit(Lit::Str(entry.origin_relative.as_str().into()));
 
+            let module = match self.mode {
+                RequireContextMode::Sync | RequireContextMode::Weak => {
+                    pm.create_require(Cow::Borrowed(&key_expr))
+                }
+                RequireContextMode::Eager => pm.create_import(Cow::Borrowed(&key_expr), false),
+                RequireContextMode::Lazy => {
+                    let loader_pm = PatternMapping::resolve_request(
+                        entry.request,
+                        self.origin,
+                        Vc::upcast(self.chunking_context),
+                        entry.result,
+                        Value::new(ResolveType::AsyncChunkLoader),
+                    )
+                    .await?;
+
+                    // The module itself resolved to a single chunk item, so its loader
+                    // does as well.
+                    let PatternMapping::Single(loader_pm) = &*loader_pm else {
+                        bail!(
+                            "invariant error: the async loader of {} in require.context() didn't \
+                             resolve to a single chunk item",
+                            entry.origin_relative
+                        );
+                    };
+
+                    loader_pm.create_import(Cow::Borrowed(&key_expr), false)
+                }
+                RequireContextMode::LazyOnce => quote!(
+                    "$loader.then(() => $module)" as Expr,
+                    loader: Expr = lazy_once_loader.as_deref().unwrap().clone(),
+                    module: Expr = pm.create_require(Cow::Borrowed(&key_expr)),
+                ),
+            };
+
             let prop = KeyValueProp {
                 key: PropName::Str(key.as_str().into()),
                 value: quote_expr!(
                     "{ id: () => $id, module: () => $module }",
                     id: Expr =
                         pm.create_id(Cow::Borrowed(&key_expr)),
-                    module: Expr =
-                        pm.create_require(Cow::Borrowed(&key_expr)),
+                    module: Expr = module,
                 ),
             };
 
@@ -0,0 +1,175 @@
+use anyhow::Result;
+use swc_core::{
+    ecma::ast::{Expr, ExprOrSpread, NewExpr},
+    quote,
+};
+use turbo_tasks::{RcStr, Value, ValueToString, Vc};
+use turbopack_core::{
+    chunk::{
+        ChunkItemExt, ChunkableModule, ChunkableModuleReference, ChunkingContext, ChunkingType,
+        ChunkingTypeOption,
+    },
+    issue::IssueSource,
+    reference::ModuleReference,
+    reference_type::EcmaScriptModulesReferenceSubType,
+    resolve::{
+        origin::ResolveOrigin, parse::Request, ModuleResolveResult, ModuleResolveResultItem,
+    },
+};
+use turbopack_resolve::ecmascript::{esm_resolve, try_to_severity};
+
+use super::esm::base::ReferencedAsset;
+use crate::{
+    code_gen::{CodeGenerateable, CodeGeneration},
+    create_visitor,
+    references::AstPath,
+    utils::module_id_to_lit,
+    worker_chunk::module::WorkerLoaderModule,
+};
+
+/// The constructor used to create a worker.
+#[turbo_tasks::value(serialization = "auto_for_input")]
+#[derive(Debug, Copy, Clone, Hash)]
+pub enum WorkerKind {
+    /// `new Worker(...)`
+    Worker,
+    /// `new SharedWorker(...)`
+    SharedWorker,
+}
+
+impl WorkerKind {
+    fn constructor_name(&self) -> &'static str {
+        match self {
+            WorkerKind::Worker => "Worker",
+            WorkerKind::SharedWorker => "SharedWorker",
+        }
+    }
+}
+
+/// Worker Asset References are injected during code analysis when we find a
+/// (staticly analyzable) `new Worker(new URL("path", import.meta.url))`.
+///
+/// The referenced module is bundled into a separate evaluated chunk group
+/// with its own runtime, and the constructor's first argument is rewritten to
+/// the URL of that chunk group's entry chunk.
+#[turbo_tasks::value]
+pub struct WorkerAssetReference {
+    origin: Vc<Box<dyn ResolveOrigin>>,
+    request: Vc<Request>,
+    kind: WorkerKind,
+    ast_path: Vc<AstPath>,
+    issue_source: Vc<IssueSource>,
+    in_try: bool,
+}
+
+#[turbo_tasks::value_impl]
+impl WorkerAssetReference {
+    #[turbo_tasks::function]
+    pub fn new(
+        origin: Vc<Box<dyn ResolveOrigin>>,
+        request: Vc<Request>,
+        kind: Value<WorkerKind>,
+        ast_path: Vc<AstPath>,
+        issue_source: Vc<IssueSource>,
+        in_try: bool,
+    ) -> Vc<Self> {
+        WorkerAssetReference {
+            origin,
+            request,
+            kind: kind.into_value(),
+            ast_path,
+            issue_source,
+            in_try,
+        }
+        .cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ModuleReference for WorkerAssetReference {
+    /// Resolves to a [WorkerLoaderModule] wrapping the worker module, so that
+    /// only the loader ends up in the chunk that creates the worker.
+    #[turbo_tasks::function]
+    async fn resolve_reference(&self) -> Result<Vc<ModuleResolveResult>> {
+        let result = esm_resolve(
+            self.origin,
+            self.request,
+            Value::new(EcmaScriptModulesReferenceSubType::Worker),
+            try_to_severity(self.in_try),
+            Some(self.issue_source),
+        );
+        let mut result = result.await?.clone_value();
+        for item in result.primary.values_mut() {
+            if let ModuleResolveResultItem::Module(module) = item {
+                if let Some(chunkable) =
+                    Vc::try_resolve_downcast::<Box<dyn ChunkableModule>>(*module).await?
+                {
+                    *module = Vc::upcast(WorkerLoaderModule::new(chunkable));
+                }
+            }
+        }
+        Ok(result.cell())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ValueToString for WorkerAssetReference {
+    #[turbo_tasks::function]
+    async fn to_string(&self) -> Result<Vc<RcStr>> {
+        Ok(Vc::cell(
+            format!(
+                "new {}(new URL({}))",
+                self.kind.constructor_name(),
+                self.request.to_string().await?,
+            )
+            .into(),
+        ))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ChunkableModuleReference for WorkerAssetReference {
+    #[turbo_tasks::function]
+    fn chunking_type(&self) -> Vc<ChunkingTypeOption> {
+        Vc::cell(Some(ChunkingType::Parallel))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl CodeGenerateable for WorkerAssetReference {
+    /// Rewrites `new Worker(new URL(url, import.meta.url), options)` to
+    /// `new Worker(__turbopack_require__(loaderId), options)`, where the loader
+    /// module exports the URL of the worker's entry chunk.
+    #[turbo_tasks::function]
+    async fn code_generation(
+        self: Vc<Self>,
+        chunking_context: Vc<Box<dyn ChunkingContext>>,
+    ) -> Result<Vc<CodeGeneration>> {
+        let this = self.await?;
+        let mut visitors = vec![];
+
+        let referenced_asset = ReferencedAsset::from_resolve_result(self.resolve_reference());
+        if let ReferencedAsset::Some(asset) = &*referenced_asset.await? {
+            let id = asset
+                .as_chunk_item(Vc::upcast(chunking_context))
+                .id()
+                .await?;
+            let ast_path = this.ast_path.await?;
+
+            visitors.push(
+                create_visitor!(ast_path, visit_mut_expr(new_expr: &mut Expr) {
+                    if let Expr::New(NewExpr { args: Some(args), .. }) = new_expr {
+                        if let Some(ExprOrSpread { box expr, spread: None }) = args.get_mut(0) {
+                            *expr = quote!(
+                                "__turbopack_require__($id)" as Expr,
+                                id: Expr = module_id_to_lit(&id),
+                            );
+                        }
+                    }
+                }),
+            );
+        }
+
+        Ok(CodeGeneration { visitors }.into())
+    }
+}
@@ -5,6 +5,7 @@ use turbopack_core::{
     asset::{Asset, AssetContent},
     ident::AssetIdent,
     source::Source,
+    source_transform::SourceTransform,
 };
 
 use crate::utils::StringifyJs;
@@ -29,6 +30,27 @@ impl TextContentFileSource {
     }
 }
 
+/// A source transform which turns sources into modules exporting their string
+/// content, see [TextContentFileSource].
+#[turbo_tasks::value]
+pub struct TextContentTransform;
+
+#[turbo_tasks::value_impl]
+impl TextContentTransform {
+    #[turbo_tasks::function]
+    pub fn new() -> Vc<Self> {
+        TextContentTransform.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl SourceTransform for TextContentTransform {
+    #[turbo_tasks::function]
+    fn transform(&self, source: Vc<Box<dyn Source>>) -> Vc<Box<dyn Source>> {
+        Vc::upcast(TextContentFileSource::new(source))
+    }
+}
+
 #[turbo_tasks::value_impl]
 impl Source for TextContentFileSource {
     #[turbo_tasks::function]
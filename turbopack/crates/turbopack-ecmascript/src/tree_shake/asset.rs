@@ -101,6 +101,38 @@ impl EcmascriptModulePartAsset {
         .cell()
     }
 
+    /// Like [EcmascriptModulePartAsset::new], but falls back to the facade of
+    /// the module when `part` is an export the module doesn't have. Reading an
+    /// unknown member of a namespace is valid and evaluates to `undefined`, so
+    /// it must not make the build fail.
+    #[turbo_tasks::function]
+    pub async fn select_part(
+        module: Vc<EcmascriptModuleAsset>,
+        part: Vc<ModulePart>,
+        import_externals: bool,
+    ) -> Result<Vc<Self>> {
+        if let ModulePart::Export(export) = &*part.await? {
+            if let SplitResult::Ok { entrypoints, .. } = &*split_module(module).await? {
+                let export = export.await?;
+                if !entrypoints.contains_key(&Key::Export(export.as_str().into()))
+                    && !entrypoints.contains_key(&Key::Exports)
+                {
+                    return Ok(EcmascriptModulePartAsset::new(
+                        module,
+                        ModulePart::facade(),
+                        import_externals,
+                    ));
+                }
+            }
+        }
+
+        Ok(EcmascriptModulePartAsset::new(
+            module,
+            part,
+            import_externals,
+        ))
+    }
+
     #[turbo_tasks::function]
     pub async fn is_async_module(self: Vc<Self>) -> Result<Vc<bool>> {
         let this = self.await?;
@@ -15,10 +15,10 @@ use swc_core::{
     ecma::{
         ast::{
             op, ClassDecl, Decl, DefaultDecl, ExportAll, ExportDecl, ExportNamedSpecifier,
-            ExportSpecifier, Expr, ExprStmt, FnDecl, Id, Ident, ImportDecl, ImportNamedSpecifier,
-            ImportSpecifier, ImportStarAsSpecifier, KeyValueProp, Lit, Module, ModuleDecl,
-            ModuleExportName, ModuleItem, NamedExport, ObjectLit, Prop, PropName, PropOrSpread,
-            Stmt, VarDecl, VarDeclKind, VarDeclarator,
+            ExportNamespaceSpecifier, ExportSpecifier, Expr, ExprStmt, FnDecl, Id, Ident,
+            ImportDecl, ImportNamedSpecifier, ImportSpecifier, ImportStarAsSpecifier, KeyValueProp,
+            Lit, Module, ModuleDecl, ModuleExportName, ModuleItem, NamedExport, ObjectLit, Prop,
+            PropName, PropOrSpread, Stmt, VarDecl, VarDeclKind, VarDeclarator,
         },
         atoms::JsWord,
         utils::{find_pat_ids, private_ident, quote_ident},
@@ -32,7 +32,7 @@ use super::{
     },
     Key, TURBOPACK_PART_IMPORT_SOURCE,
 };
-use crate::magic_identifier;
+use crate::{analyzer::imports::find_static_namespace_imports, magic_identifier};
 
 /// The id of an item
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -266,6 +266,18 @@ impl DepGraph {
 
         let mut declarator = FxHashMap::default();
 
+        let static_namespace_imports = data
+            .values()
+            .filter(|item| item.var_decls.is_empty())
+            .filter_map(|item| {
+                let import = item.content.as_module_decl()?.as_import()?;
+                match &*import.specifiers {
+                    [ImportSpecifier::Namespace(s)] => Some((s.local.to_id(), &item.content)),
+                    _ => None,
+                }
+            })
+            .collect::<FxHashMap<_, _>>();
+
         for (ix, group) in groups.graph_ix.iter().enumerate() {
             for id in group {
                 let item = data.get(id).unwrap();
@@ -318,7 +330,7 @@ impl DepGraph {
 
                             let s = ExportSpecifier::Named(ExportNamedSpecifier {
                                 span: DUMMY_SP,
-                                orig: ModuleExportName::Ident(Ident::new(export.clone(), DUMMY_SP)),
+                                orig: module_export_name(export.clone()),
                                 exported: None,
                                 is_type_only: false,
                             });
@@ -364,6 +376,11 @@ impl DepGraph {
 
             // Import variables
             for var in required_vars {
+                if let Some(&import) = static_namespace_imports.get(var) {
+                    chunk.body.push(import.clone());
+                    continue;
+                }
+
                 let Some(&dep) = declarator.get(var) else {
                     continue;
                 };
@@ -513,6 +530,19 @@ impl DepGraph {
         top_level_ctxt: SyntaxContext,
     ) -> (Vec<ItemId>, FxHashMap<ItemId, ItemData>) {
         let top_level_vars = collect_top_level_decls(module);
+        let static_namespaces = find_static_namespace_imports(
+            module,
+            module
+                .body
+                .iter()
+                .filter_map(|item| item.as_module_decl()?.as_import())
+                .flat_map(|import| import.specifiers.iter())
+                .filter_map(|s| match s {
+                    ImportSpecifier::Namespace(s) => Some(s.local.to_id()),
+                    _ => None,
+                })
+                .collect(),
+        );
         let mut exports = vec![];
         let mut items = FxHashMap::default();
         let mut ids = vec![];
@@ -564,10 +594,7 @@ impl DepGraph {
                             let (orig, mut local, exported) = match s {
                                 ExportSpecifier::Named(s) => (
                                     Some(s.orig.clone()),
-                                    match &s.orig {
-                                        ModuleExportName::Ident(i) => i.clone(),
-                                        ModuleExportName::Str(..) => quote_ident!("_tmp"),
-                                    },
+                                    export_local(&s.orig),
                                     Some(s.exported.clone().unwrap_or_else(|| s.orig.clone())),
                                 ),
                                 ExportSpecifier::Default(s) => (
@@ -578,17 +605,23 @@ impl DepGraph {
                                     quote_ident!("default"),
                                     Some(ModuleExportName::Ident(s.exported.clone())),
                                 ),
-                                ExportSpecifier::Namespace(s) => (
-                                    None,
-                                    match &s.name {
-                                        ModuleExportName::Ident(i) => i.clone(),
-                                        ModuleExportName::Str(..) => quote_ident!("_tmp"),
-                                    },
-                                    Some(s.name.clone()),
-                                ),
+                                ExportSpecifier::Namespace(s) => {
+                                    (None, export_local(&s.name), Some(s.name.clone()))
+                                }
                             };
 
-                            if item.src.is_some() {
+                            // String names already got a generated local
+                            let has_generated_local = matches!(
+                                s,
+                                ExportSpecifier::Named(ExportNamedSpecifier {
+                                    orig: ModuleExportName::Str(..),
+                                    ..
+                                }) | ExportSpecifier::Namespace(ExportNamespaceSpecifier {
+                                    name: ModuleExportName::Str(..),
+                                    ..
+                                })
+                            );
+                            if item.src.is_some() && !has_generated_local {
                                 local.sym =
                                     magic_identifier::mangle(&format!("reexport {}", local.sym))
                                         .into();
@@ -825,11 +858,19 @@ impl DepGraph {
                         };
                         ids.push(id.clone());
                         let local = s.local().to_id();
+                        // Namespaces which are only used for static property reads are not
+                        // declared by a part. Instead, each part using them imports them
+                        // directly, so that only the accessed exports are referenced.
+                        let var_decls = if static_namespaces.contains(&local) {
+                            Default::default()
+                        } else {
+                            [local].into_iter().collect()
+                        };
                         items.insert(
                             id,
                             ItemData {
                                 is_hoisted: true,
-                                var_decls: [local].into_iter().collect(),
+                                var_decls,
                                 pure: true,
                                 content: ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
                                     specifiers: vec![s.clone()],
@@ -1063,7 +1104,8 @@ impl DepGraph {
         for (local, export_name) in exports {
             let name = match &export_name {
                 Some(ModuleExportName::Ident(v)) => v.sym.clone(),
-                _ => local.0.clone(),
+                Some(ModuleExportName::Str(v)) => v.value.clone(),
+                None => local.0.clone(),
             };
             let id = ItemId::Group(ItemIdGroupKind::Export(local.clone(), name.clone()));
             ids.push(id.clone());
@@ -1150,6 +1192,18 @@ pub(crate) enum PartId {
     Internal(u32),
 }
 
+/// Returns the local binding for an exported name. String names (`export {
+/// "a-b" } from "x"`) are only valid for re-exports and usually aren't valid
+/// identifiers, so a local is generated for them.
+fn export_local(name: &ModuleExportName) -> Ident {
+    match name {
+        ModuleExportName::Ident(i) => i.clone(),
+        ModuleExportName::Str(s) => {
+            private_ident!(magic_identifier::mangle(&format!("reexport {}", s.value)))
+        }
+    }
+}
+
 pub(crate) fn create_turbopack_part_id_assert(dep: PartId) -> ObjectLit {
     // We can't use quote! as `with` is not standard yet
     ObjectLit {
@@ -1166,6 +1220,19 @@ pub(crate) fn create_turbopack_part_id_assert(dep: PartId) -> ObjectLit {
     }
 }
 
+/// Creates the name of an export, which might not be a valid identifier, e.g.
+/// for `export { a as "a-b" }`.
+pub(super) fn module_export_name(name: JsWord) -> ModuleExportName {
+    let mut chars = name.chars();
+    let is_ident =
+        chars.next().is_some_and(Ident::is_valid_start) && chars.all(Ident::is_valid_continue);
+    if is_ident {
+        ModuleExportName::Ident(Ident::new(name, DUMMY_SP))
+    } else {
+        ModuleExportName::Str(name.into())
+    }
+}
+
 pub(crate) fn find_turbopack_part_id_in_asserts(asserts: &ObjectLit) -> Option<PartId> {
     asserts.props.iter().find_map(|prop| match prop {
         PropOrSpread::Prop(box Prop::KeyValue(KeyValueProp {
@@ -19,7 +19,9 @@ use turbopack_core::{ident::AssetIdent, resolve::ModulePart, source::Source};
 pub(crate) use self::graph::{
     create_turbopack_part_id_assert, find_turbopack_part_id_in_asserts, PartId,
 };
-use self::graph::{DepGraph, ItemData, ItemId, ItemIdGroupKind, Mode, SplitModuleResult};
+use self::graph::{
+    module_export_name, DepGraph, ItemData, ItemId, ItemIdGroupKind, Mode, SplitModuleResult,
+};
 use crate::{analyzer::graph::EvalContext, parse::ParseResult, EcmascriptModuleAsset};
 
 pub mod asset;
@@ -326,17 +328,24 @@ pub(crate) enum Key {
 }
 
 /// Converts [Vc<ModulePart>] to the index.
+///
+/// [ModulePart::Facade] has no index, since it isn't one of the split modules.
+/// Its code is synthesized by [part_of_module] and its references by
+/// `EcmascriptModulePartAsset::references`, which are the only callers and
+/// handle the facade before calling this. Code outside of `tree_shake`
+/// creates parts through [asset::EcmascriptModulePartAsset::select_part], which
+/// may select the facade, or by importing a part by its id.
 async fn get_part_id(result: &SplitResult, part: Vc<ModulePart>) -> Result<u32> {
     let part = part.await?;
 
-    // TODO implement ModulePart::Facade
     let key = match &*part {
         ModulePart::Evaluation => Key::ModuleEvaluation,
         ModulePart::Export(export) => Key::Export(export.await?.as_str().into()),
         ModulePart::Exports => Key::Exports,
         ModulePart::Internal(part_id) => return Ok(*part_id),
+        // Handled by the callers, see above
+        ModulePart::Facade => bail!("the facade has no part id, it's synthesized by the caller"),
         ModulePart::Locals
-        | ModulePart::Facade
         | ModulePart::RenamedExport { .. }
         | ModulePart::RenamedNamespace { .. } => {
             bail!("invalid module part")
@@ -594,10 +603,7 @@ pub(super) async fn part_of_module(
                         .map(|export_name| {
                             swc_core::ecma::ast::ExportSpecifier::Named(ExportNamedSpecifier {
                                 span: DUMMY_SP,
-                                orig: ModuleExportName::Ident(Ident::new(
-                                    export_name.as_str().into(),
-                                    DUMMY_SP,
-                                )),
+                                orig: module_export_name(export_name.as_str().into()),
                                 exported: None,
                                 is_type_only: false,
                             })
@@ -674,10 +680,7 @@ pub(super) async fn part_of_module(
                         let specifier =
                             swc_core::ecma::ast::ExportSpecifier::Named(ExportNamedSpecifier {
                                 span: DUMMY_SP,
-                                orig: ModuleExportName::Ident(Ident::new(
-                                    export_name.as_str().into(),
-                                    DUMMY_SP,
-                                )),
+                                orig: module_export_name(export_name.as_str().into()),
                                 exported: None,
                                 is_type_only: false,
                             });
@@ -397,9 +397,7 @@ pub fn should_skip_tree_shaking(m: &Program) -> bool {
         for item in m.body.iter() {
             match item {
                 // Skip turbopack helpers.
-                ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
-                    with, specifiers, ..
-                })) => {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl { with, .. })) => {
                     if let Some(with) = with.as_deref().and_then(|v| v.as_import_with()) {
                         for item in with.values.iter() {
                             if item.key.sym == *TURBOPACK_HELPER {
@@ -408,32 +406,6 @@ pub fn should_skip_tree_shaking(m: &Program) -> bool {
                             }
                         }
                     }
-
-                    // TODO(PACK-3150): Tree shaking has a bug related to ModuleExportName::Str
-                    for s in specifiers.iter() {
-                        if let ImportSpecifier::Named(is) = s {
-                            if matches!(is.imported, Some(ModuleExportName::Str(..))) {
-                                return true;
-                            }
-                        }
-                    }
-                }
-
-                // Tree shaking has a bug related to ModuleExportName::Str
-                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
-                    src: Some(..),
-                    specifiers,
-                    ..
-                })) => {
-                    for s in specifiers {
-                        if let ExportSpecifier::Named(es) = s {
-                            if matches!(es.orig, ModuleExportName::Str(..))
-                                || matches!(es.exported, Some(ModuleExportName::Str(..)))
-                            {
-                                return true;
-                            }
-                        }
-                    }
                 }
 
                 _ => {}
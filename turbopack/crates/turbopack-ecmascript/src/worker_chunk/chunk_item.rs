@@ -0,0 +1,175 @@
+use anyhow::Result;
+use turbo_tasks::{RcStr, Value, Vc};
+use turbo_tasks_fs::FileSystemPath;
+use turbopack_core::{
+    chunk::{
+        availability_info::AvailabilityInfo, ChunkItem, ChunkType, ChunkingContext,
+        ChunkingContextExt, EvaluatableAsset, EvaluatableAssets,
+    },
+    ident::AssetIdent,
+    issue::{Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString},
+    module::Module,
+    output::OutputAssets,
+    reference::{ModuleReferences, SingleOutputAssetReference},
+};
+
+use crate::{
+    chunk::{EcmascriptChunkItem, EcmascriptChunkItemContent, EcmascriptChunkType},
+    utils::StringifyJs,
+    worker_chunk::module::WorkerLoaderModule,
+};
+
+#[turbo_tasks::value(shared)]
+pub struct WorkerLoaderChunkItem {
+    pub module: Vc<WorkerLoaderModule>,
+    pub chunking_context: Vc<Box<dyn ChunkingContext>>,
+}
+
+#[turbo_tasks::value_impl]
+impl WorkerLoaderChunkItem {
+    /// The chunks of the worker's evaluated chunk group. The last chunk is the
+    /// entry chunk, which contains the runtime and loads the other chunks.
+    #[turbo_tasks::function]
+    pub(super) async fn chunks(self: Vc<Self>) -> Result<Vc<OutputAssets>> {
+        let this = self.await?;
+        let module = this.module.await?;
+        let Some(evaluatable) =
+            Vc::try_resolve_sidecast::<Box<dyn EvaluatableAsset>>(module.inner).await?
+        else {
+            WorkerModuleNotEvaluatableIssue {
+                path: module.inner.ident().path(),
+            }
+            .cell()
+            .emit();
+            return Ok(OutputAssets::empty());
+        };
+        Ok(this.chunking_context.evaluated_chunk_group_assets(
+            this.module.ident(),
+            EvaluatableAssets::one(evaluatable),
+            Value::new(AvailabilityInfo::Root),
+        ))
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl EcmascriptChunkItem for WorkerLoaderChunkItem {
+    #[turbo_tasks::function]
+    fn chunking_context(&self) -> Vc<Box<dyn ChunkingContext>> {
+        self.chunking_context
+    }
+
+    #[turbo_tasks::function]
+    async fn content(self: Vc<Self>) -> Result<Vc<EcmascriptChunkItemContent>> {
+        let this = self.await?;
+        let chunks = self.chunks().await?;
+        let Some(&entry_chunk) = chunks.last() else {
+            // The worker module can't be loaded, which is reported by `chunks`.
+            return Ok(EcmascriptChunkItemContent {
+                inner_code: "throw new Error(\"worker module is not evaluatable\");\n".into(),
+                ..Default::default()
+            }
+            .into());
+        };
+        let url = this.chunking_context.chunk_url(entry_chunk.ident()).await?;
+
+        Ok(EcmascriptChunkItemContent {
+            inner_code: format!(
+                "__turbopack_export_value__({});\n",
+                StringifyJs(url.as_str())
+            )
+            .into(),
+            ..Default::default()
+        }
+        .into())
+    }
+}
+
+#[turbo_tasks::function]
+fn chunk_reference_description() -> Vc<RcStr> {
+    Vc::cell("worker chunk".into())
+}
+
+#[turbo_tasks::value_impl]
+impl ChunkItem for WorkerLoaderChunkItem {
+    #[turbo_tasks::function]
+    fn asset_ident(&self) -> Vc<AssetIdent> {
+        self.module.ident()
+    }
+
+    #[turbo_tasks::function]
+    async fn references(self: Vc<Self>) -> Result<Vc<ModuleReferences>> {
+        let chunks = self.chunks();
+
+        Ok(Vc::cell(
+            chunks
+                .await?
+                .iter()
+                .copied()
+                .map(|chunk| {
+                    Vc::upcast(SingleOutputAssetReference::new(
+                        chunk,
+                        chunk_reference_description(),
+                    ))
+                })
+                .collect(),
+        ))
+    }
+
+    #[turbo_tasks::function]
+    fn chunking_context(&self) -> Vc<Box<dyn ChunkingContext>> {
+        Vc::upcast(self.chunking_context)
+    }
+
+    #[turbo_tasks::function]
+    async fn ty(&self) -> Result<Vc<Box<dyn ChunkType>>> {
+        Ok(Vc::upcast(
+            Vc::<EcmascriptChunkType>::default().resolve().await?,
+        ))
+    }
+
+    #[turbo_tasks::function]
+    fn module(&self) -> Vc<Box<dyn Module>> {
+        Vc::upcast(self.module)
+    }
+}
+
+#[turbo_tasks::value(shared)]
+pub struct WorkerModuleNotEvaluatableIssue {
+    pub path: Vc<FileSystemPath>,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for WorkerModuleNotEvaluatableIssue {
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        self.path
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Text("Module can't be used as a worker".into()).cell()
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        Vc::cell(Some(
+            StyledString::Text(
+                "Workers are started from an evaluated chunk group, but this module can't be \
+                 evaluated. Only JavaScript modules can be loaded with `new Worker(new URL(..., \
+                 import.meta.url))`."
+                    .into(),
+            )
+            .cell(),
+        ))
+    }
+
+    #[turbo_tasks::function]
+    fn severity(&self) -> Vc<IssueSeverity> {
+        IssueSeverity::Error.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::CodeGen.into()
+    }
+}
@@ -0,0 +1,2 @@
+pub mod chunk_item;
+pub mod module;
@@ -0,0 +1,6 @@
+class A {
+  #x = 1;
+  static read = (a) => a.#x;
+  static has = (b) => #x in b;
+  static pick = (c) => (c.#x, "fallback");
+}
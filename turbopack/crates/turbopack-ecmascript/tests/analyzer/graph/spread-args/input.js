@@ -0,0 +1,4 @@
+const args = ["./b"];
+const a = require.resolve(...["./a"]);
+const b = require.resolve(...args);
+const c = require.resolve("./c", ...args);
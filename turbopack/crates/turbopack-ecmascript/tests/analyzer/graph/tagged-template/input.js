@@ -0,0 +1,4 @@
+const parts = (strings) => strings;
+const name = "world";
+const a = String.raw`a\n${name}`;
+const b = parts`hello ${name}!`;
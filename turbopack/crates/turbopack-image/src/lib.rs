@@ -1,6 +1,7 @@
 #![feature(arbitrary_self_types)]
 
 pub mod process;
+pub mod svg;
 
 pub fn register() {
     turbo_tasks::register();
@@ -17,7 +17,7 @@ use image::{
 use mime::Mime;
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
-use turbo_tasks::{debug::ValueDebugFormat, trace::TraceRawVcs, Vc};
+use turbo_tasks::{debug::ValueDebugFormat, trace::TraceRawVcs, TaskInput, Vc};
 use turbo_tasks_fs::{File, FileContent, FileSystemPath};
 use turbopack_core::{
     error::PrettyPrintError,
@@ -80,6 +80,46 @@ pub struct BlurPlaceholderOptions {
     pub size: u32,
 }
 
+/// A modern image format which images can be converted to by [optimize_as].
+#[turbo_tasks::value(serialization = "auto_for_input")]
+#[derive(Clone, Copy, Debug, PartialOrd, Ord, Hash, TaskInput)]
+pub enum ImageOutputFormat {
+    Webp,
+    Avif,
+}
+
+impl ImageOutputFormat {
+    /// Whether encoding to this format has been compiled into the current
+    /// build.
+    pub fn is_supported(self) -> bool {
+        match self {
+            ImageOutputFormat::Webp => cfg!(feature = "webp"),
+            ImageOutputFormat::Avif => cfg!(feature = "avif"),
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageOutputFormat::Webp => "webp",
+            ImageOutputFormat::Avif => "avif",
+        }
+    }
+
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            ImageOutputFormat::Webp => "image/webp",
+            ImageOutputFormat::Avif => "image/avif",
+        }
+    }
+
+    fn image_format(self) -> ImageFormat {
+        match self {
+            ImageOutputFormat::Webp => ImageFormat::WebP,
+            ImageOutputFormat::Avif => ImageFormat::Avif,
+        }
+    }
+}
+
 fn extension_to_image_format(extension: &str) -> Option<ImageFormat> {
     Some(match extension {
         "avif" => ImageFormat::Avif,
@@ -411,6 +451,8 @@ pub async fn get_meta_data(
     }
 }
 
+/// Resizes the image to fit into `max_width` and `max_height` and encodes it
+/// again in its original format.
 #[turbo_tasks::function]
 pub async fn optimize(
     ident: Vc<AssetIdent>,
@@ -418,6 +460,32 @@ pub async fn optimize(
     max_width: u32,
     max_height: u32,
     quality: u8,
+) -> Result<Vc<FileContent>> {
+    optimize_internal(ident, content, max_width, max_height, quality, None).await
+}
+
+/// Resizes the image to fit into `max_width` and `max_height` and converts it
+/// to `format`. Returns [FileContent::NotFound] when the image can't be
+/// decoded or encoded, after emitting an issue.
+#[turbo_tasks::function]
+pub async fn optimize_as(
+    ident: Vc<AssetIdent>,
+    content: Vc<FileContent>,
+    max_width: u32,
+    max_height: u32,
+    quality: u8,
+    format: ImageOutputFormat,
+) -> Result<Vc<FileContent>> {
+    optimize_internal(ident, content, max_width, max_height, quality, Some(format)).await
+}
+
+async fn optimize_internal(
+    ident: Vc<AssetIdent>,
+    content: Vc<FileContent>,
+    max_width: u32,
+    max_height: u32,
+    quality: u8,
+    output_format: Option<ImageOutputFormat>,
 ) -> Result<Vc<FileContent>> {
     let FileContent::Content(content) = &*content.await? else {
         return Ok(FileContent::NotFound.cell());
@@ -429,6 +497,22 @@ pub async fn optimize(
         return Ok(FileContent::NotFound.cell());
     };
     match image {
+        ImageBuffer::Raw(_) if output_format.is_some() => {
+            ImageProcessingIssue {
+                path: ident.path(),
+                message: StyledString::Text(
+                    "The image can't be decoded by this version of Turbopack, so it can't be \
+                     converted to another format"
+                        .into(),
+                )
+                .cell(),
+                title: Some(StyledString::Text("Image conversion not supported".into()).cell()),
+                issue_severity: Some(IssueSeverity::Warning.into()),
+            }
+            .cell()
+            .emit();
+            Ok(FileContent::NotFound.cell())
+        }
         ImageBuffer::Raw(buffer) => {
             #[cfg(not(feature = "avif"))]
             if matches!(format, Some(ImageFormat::Avif)) {
@@ -467,8 +551,19 @@ pub async fn optimize(
                 image
             };
 
-            let format = format.unwrap_or(ImageFormat::Jpeg);
-            let (data, mime_type) = encode_image(image, format, quality)?;
+            let Some(output_format) = output_format else {
+                let format = format.unwrap_or(ImageFormat::Jpeg);
+                let (data, mime_type) = encode_image(image, format, quality)?;
+                return Ok(
+                    FileContent::Content(File::from(data).with_content_type(mime_type)).cell(),
+                );
+            };
+            let Some((data, mime_type)) = result_to_issue(
+                ident,
+                encode_image(image, output_format.image_format(), quality),
+            ) else {
+                return Ok(FileContent::NotFound.cell());
+            };
 
             Ok(FileContent::Content(File::from(data).with_content_type(mime_type)).cell())
         }
@@ -476,11 +571,11 @@ pub async fn optimize(
 }
 
 #[turbo_tasks::value]
-struct ImageProcessingIssue {
-    path: Vc<FileSystemPath>,
-    message: Vc<StyledString>,
-    title: Option<Vc<StyledString>>,
-    issue_severity: Option<Vc<IssueSeverity>>,
+pub(crate) struct ImageProcessingIssue {
+    pub(crate) path: Vc<FileSystemPath>,
+    pub(crate) message: Vc<StyledString>,
+    pub(crate) title: Option<Vc<StyledString>>,
+    pub(crate) issue_severity: Option<Vc<IssueSeverity>>,
 }
 
 #[turbo_tasks::value_impl]
@@ -0,0 +1,303 @@
+use std::{borrow::Cow, fmt::Write};
+
+use anyhow::{bail, Context, Result};
+use turbo_tasks::{RcStr, Vc};
+use turbo_tasks_fs::{File, FileContent};
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    ident::AssetIdent,
+    source::Source,
+    source_transform::SourceTransform,
+};
+
+use super::parse::{preserves_whitespace, tokenize, SvgToken};
+
+fn js_string(value: &str) -> String {
+    serde_json::to_string(value).expect("strings can always be serialized")
+}
+
+fn camel_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '-' {
+            upper = true;
+        } else if upper {
+            result.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Converts a CSS property to the name React expects in style objects, e.g.
+/// `-webkit-transition` to `WebkitTransition` and `-ms-transform` to
+/// `msTransform`.
+fn style_property_name(property: &str) -> String {
+    if property.starts_with("--") {
+        return property.to_string();
+    }
+    let property = property.to_ascii_lowercase();
+    match property.strip_prefix("-ms-") {
+        Some(rest) => camel_case(&format!("ms-{rest}")),
+        None => camel_case(&property),
+    }
+}
+
+/// Converts an SVG attribute to the name React expects, e.g. `stroke-width` to
+/// `strokeWidth` and `xlink:href` to `xlinkHref`.
+fn jsx_attribute_name(name: &str) -> Cow<'_, str> {
+    match name {
+        "class" => Cow::Borrowed("className"),
+        "for" => Cow::Borrowed("htmlFor"),
+        _ if name.starts_with("data-") || name.starts_with("aria-") => Cow::Borrowed(name),
+        _ if name.contains([':', '-']) => Cow::Owned(camel_case(&name.replace(':', "-"))),
+        _ => Cow::Borrowed(name),
+    }
+}
+
+/// Splits a style attribute at semicolons which are not part of a string or
+/// of a function like `url(data:image/png;base64,...)`.
+fn split_declarations(style: &str) -> Vec<&str> {
+    let mut declarations = Vec::new();
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut start = 0;
+    for (i, c) in style.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, ';') if depth == 0 => {
+                declarations.push(&style[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    declarations.push(&style[start..]);
+    declarations
+}
+
+fn style_object(style: &str) -> String {
+    let properties = split_declarations(style)
+        .into_iter()
+        .filter_map(|declaration| {
+            let (property, value) = declaration.split_once(':')?;
+            let (property, value) = (property.trim(), value.trim());
+            if property.is_empty() || value.is_empty() {
+                return None;
+            }
+            Some(format!(
+                "{}: {}",
+                js_string(&style_property_name(property)),
+                js_string(value)
+            ))
+        })
+        .collect::<Vec<_>>();
+    format!("{{{}}}", properties.join(", "))
+}
+
+fn write_attribute(jsx: &mut String, name: &str, value: &str) -> Result<()> {
+    let name = jsx_attribute_name(name);
+    if name == "style" {
+        write!(jsx, " style={{{}}}", style_object(value))?;
+    } else if value.contains(['"', '&']) {
+        write!(jsx, " {name}={{{}}}", js_string(value))?;
+    } else {
+        write!(jsx, " {name}=\"{value}\"")?;
+    }
+    Ok(())
+}
+
+/// Converts an SVG document into a module which exports a React component
+/// rendering it. Props are spread onto the `<svg>` element, so they can
+/// override its attributes.
+///
+/// Everything which could run scripts, editor data and namespaced elements are
+/// removed, as React can't render them.
+pub fn svg_to_component(source: &str) -> Result<String> {
+    let mut jsx = String::with_capacity(source.len());
+    // Whether whitespace is significant in each open element
+    let mut open_elements: Vec<bool> = Vec::new();
+    // The depth of the removed element which is currently skipped
+    let mut skipped_depth = 0;
+    let mut has_root = false;
+    for token in tokenize(source, true)? {
+        if skipped_depth > 0 {
+            match token {
+                SvgToken::StartTag {
+                    self_closing: false,
+                    ..
+                } => skipped_depth += 1,
+                SvgToken::EndTag { .. } => skipped_depth -= 1,
+                _ => {}
+            }
+            continue;
+        }
+        match token {
+            SvgToken::StartTag {
+                name,
+                attributes,
+                self_closing,
+            } => {
+                if name.contains(':') {
+                    if !self_closing {
+                        skipped_depth = 1;
+                    }
+                    continue;
+                }
+                let is_root = open_elements.is_empty();
+                if is_root {
+                    if has_root || name != "svg" {
+                        bail!("expected a single `<svg>` root element, found `<{name}>`");
+                    }
+                    has_root = true;
+                }
+                write!(jsx, "<{name}")?;
+                for (attribute, value) in attributes {
+                    write_attribute(&mut jsx, &attribute, &value)?;
+                }
+                if is_root {
+                    jsx.push_str(" {...props}");
+                }
+                if self_closing {
+                    jsx.push_str(" />");
+                } else {
+                    jsx.push('>');
+                    let preserve = preserves_whitespace(name)
+                        || open_elements.last().copied().unwrap_or_default();
+                    open_elements.push(preserve);
+                }
+            }
+            SvgToken::EndTag { name } => {
+                open_elements.pop();
+                write!(jsx, "</{name}>")?;
+            }
+            SvgToken::Text(text) => {
+                let Some(&preserve) = open_elements.last() else {
+                    continue;
+                };
+                if !preserve && text.trim().is_empty() {
+                    continue;
+                }
+                write!(jsx, "{{{}}}", js_string(&text))?;
+            }
+        }
+    }
+    if !has_root {
+        bail!("expected an `<svg>` root element");
+    }
+    Ok(format!(
+        "import * as React from \"react\";\n\nconst SvgComponent = (props) => {jsx};\n\nexport \
+         default SvgComponent;\n"
+    ))
+}
+
+/// A source transform which turns SVG files into JSX modules exporting a React
+/// component, see [svg_to_component].
+#[turbo_tasks::value]
+pub struct SvgComponentTransform;
+
+#[turbo_tasks::value_impl]
+impl SvgComponentTransform {
+    #[turbo_tasks::function]
+    pub fn new() -> Vc<Self> {
+        SvgComponentTransform.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl SourceTransform for SvgComponentTransform {
+    #[turbo_tasks::function]
+    fn transform(&self, source: Vc<Box<dyn Source>>) -> Vc<Box<dyn Source>> {
+        Vc::upcast(SvgComponentSource { source }.cell())
+    }
+}
+
+#[turbo_tasks::function]
+fn modifier() -> Vc<RcStr> {
+    Vc::cell("svg component".into())
+}
+
+#[turbo_tasks::value]
+struct SvgComponentSource {
+    source: Vc<Box<dyn Source>>,
+}
+
+#[turbo_tasks::value_impl]
+impl Source for SvgComponentSource {
+    #[turbo_tasks::function]
+    fn ident(&self) -> Vc<AssetIdent> {
+        self.source
+            .ident()
+            .with_modifier(modifier())
+            .rename_as("*.jsx".into())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for SvgComponentSource {
+    #[turbo_tasks::function]
+    async fn content(&self) -> Result<Vc<AssetContent>> {
+        let content = self.source.content().file_content().await?;
+        let FileContent::Content(file) = &*content else {
+            bail!("SVG file not found");
+        };
+        let bytes = file.content().to_bytes()?;
+        let source = std::str::from_utf8(&bytes).context("SVG is not valid utf-8")?;
+        let component = svg_to_component(source).context("failed to convert SVG to JSX")?;
+        Ok(AssetContent::file(
+            FileContent::Content(File::from(component)).cell(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{style_property_name, svg_to_component};
+
+    #[test]
+    fn test_svg_to_component() {
+        let svg = r##"<?xml version="1.0"?>
+<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" xmlns:sodipodi="http://sodipodi.sourceforge.net" class="icon" style="fill-opacity: .5; background: url(data:image/png;base64,AA==)">
+  <sodipodi:namedview/>
+  <script>alert(1)</script>
+  <path stroke-width="2" d="M0 0" onclick="alert(2)"/>
+  <use xlink:href="#a" data-name="a &amp; b"/>
+  <text xml:space="preserve">A &lt; B</text>
+</svg>"##;
+        assert_eq!(
+            svg_to_component(svg).unwrap(),
+            r##"import * as React from "react";
+
+const SvgComponent = (props) => <svg xmlns="http://www.w3.org/2000/svg" xmlnsXlink="http://www.w3.org/1999/xlink" className="icon" style={{"fillOpacity": ".5", "background": "url(data:image/png;base64,AA==)"}} {...props}><path strokeWidth="2" d="M0 0" /><use xlinkHref="#a" data-name={"a & b"} /><text xmlSpace="preserve">{"A < B"}</text></svg>;
+
+export default SvgComponent;
+"##
+        );
+    }
+
+    #[test]
+    fn test_svg_to_component_invalid() {
+        assert!(svg_to_component("<div></div>").is_err());
+        assert!(svg_to_component("<svg></svg><svg></svg>").is_err());
+        assert!(svg_to_component("<svg><g></g>").is_err());
+        assert!(svg_to_component("<svg><g></svg></g>").is_err());
+    }
+
+    #[test]
+    fn test_style_property_name() {
+        assert_eq!(style_property_name("stroke-width"), "strokeWidth");
+        assert_eq!(
+            style_property_name("-webkit-transition"),
+            "WebkitTransition"
+        );
+        assert_eq!(style_property_name("-ms-transform"), "msTransform");
+        assert_eq!(style_property_name("--main-color"), "--main-color");
+    }
+}
@@ -0,0 +1,252 @@
+use anyhow::{Context, Result};
+use turbo_tasks::{RcStr, Vc};
+use turbo_tasks_fs::{File, FileContent};
+use turbopack_core::{
+    asset::{Asset, AssetContent},
+    ident::AssetIdent,
+    issue::{IssueExt, IssueSeverity, StyledString},
+    source::Source,
+    source_transform::SourceTransform,
+};
+
+use super::parse::{preserves_whitespace, tokenize, SvgToken};
+use crate::process::ImageProcessingIssue;
+
+/// Options for minifying SVGs.
+#[turbo_tasks::value(shared)]
+#[derive(Clone, Debug, Default)]
+pub struct SvgMinifyOptions {
+    /// Removes everything which could run scripts, like `<script>` and
+    /// `<foreignObject>` elements, event handler attributes and `javascript:`
+    /// links, so SVGs served as files can't run scripts.
+    pub remove_scripts: bool,
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn escape_attribute(value: &str) -> String {
+    escape_text(value).replace('"', "&quot;")
+}
+
+/// Removes comments, declarations, editor data and insignificant whitespace
+/// from an SVG document.
+pub fn minify(source: &str, options: &SvgMinifyOptions) -> Result<String> {
+    let mut result = String::with_capacity(source.len());
+    // Whether whitespace is significant in each open element
+    let mut open_elements: Vec<bool> = Vec::new();
+    for token in tokenize(source, options.remove_scripts)? {
+        match token {
+            SvgToken::StartTag {
+                name,
+                attributes,
+                self_closing,
+            } => {
+                result.push('<');
+                result.push_str(name);
+                for (attribute, value) in attributes {
+                    result.push(' ');
+                    result.push_str(&attribute);
+                    result.push_str("=\"");
+                    result.push_str(&escape_attribute(&value));
+                    result.push('"');
+                }
+                if self_closing {
+                    result.push_str("/>");
+                } else {
+                    result.push('>');
+                    let preserve = preserves_whitespace(name)
+                        || open_elements.last().copied().unwrap_or_default();
+                    open_elements.push(preserve);
+                }
+            }
+            SvgToken::EndTag { name } => {
+                open_elements.pop();
+                result.push_str("</");
+                result.push_str(name);
+                result.push('>');
+            }
+            SvgToken::Text(text) => {
+                let preserve = open_elements.last().copied().unwrap_or_default();
+                if text.trim().is_empty() && !preserve {
+                    continue;
+                }
+                result.push_str(&escape_text(&text));
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// A source transform which minifies SVG files.
+#[turbo_tasks::value]
+pub struct SvgMinifyTransform {
+    options: Vc<SvgMinifyOptions>,
+}
+
+#[turbo_tasks::value_impl]
+impl SvgMinifyTransform {
+    #[turbo_tasks::function]
+    pub fn new(options: Vc<SvgMinifyOptions>) -> Vc<Self> {
+        SvgMinifyTransform { options }.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl SourceTransform for SvgMinifyTransform {
+    #[turbo_tasks::function]
+    fn transform(&self, source: Vc<Box<dyn Source>>) -> Vc<Box<dyn Source>> {
+        Vc::upcast(
+            MinifiedSvgSource {
+                source,
+                options: self.options,
+            }
+            .cell(),
+        )
+    }
+}
+
+#[turbo_tasks::function]
+fn modifier() -> Vc<RcStr> {
+    Vc::cell("minified svg".into())
+}
+
+#[turbo_tasks::value]
+struct MinifiedSvgSource {
+    source: Vc<Box<dyn Source>>,
+    options: Vc<SvgMinifyOptions>,
+}
+
+#[turbo_tasks::value_impl]
+impl Source for MinifiedSvgSource {
+    #[turbo_tasks::function]
+    fn ident(&self) -> Vc<AssetIdent> {
+        self.source.ident().with_modifier(modifier())
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl Asset for MinifiedSvgSource {
+    #[turbo_tasks::function]
+    async fn content(&self) -> Result<Vc<AssetContent>> {
+        let content = self.source.content().file_content();
+        let FileContent::Content(file) = &*content.await? else {
+            return Ok(AssetContent::file(content));
+        };
+        let minified = std::str::from_utf8(&file.content().to_bytes()?)
+            .context("SVG is not valid utf-8")
+            .and_then(|source| minify(source, &*self.options.await?));
+        match minified {
+            Ok(minified) => Ok(AssetContent::file(
+                FileContent::Content(File::from(minified).with_content_type(mime::IMAGE_SVG))
+                    .cell(),
+            )),
+            Err(err) => {
+                // Emitting the original SVG could serve scripts, so nothing is
+                // emitted when scripts have to be removed.
+                let options = self.options.await?;
+                let (message, severity) = if options.remove_scripts {
+                    (
+                        format!(
+                            "{err:#}. The SVG is not emitted, as scripts can't be removed from it."
+                        ),
+                        IssueSeverity::Error,
+                    )
+                } else {
+                    (
+                        format!("{err:#}. The SVG is emitted without minification."),
+                        IssueSeverity::Warning,
+                    )
+                };
+                ImageProcessingIssue {
+                    path: self.source.ident().path(),
+                    message: StyledString::Text(message.into()).cell(),
+                    title: Some(StyledString::Text("Minifying SVG failed".into()).cell()),
+                    issue_severity: Some(severity.into()),
+                }
+                .cell()
+                .emit();
+                if options.remove_scripts {
+                    Ok(AssetContent::file(FileContent::NotFound.cell()))
+                } else {
+                    Ok(AssetContent::file(content))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{minify, SvgMinifyOptions};
+
+    #[test]
+    fn test_minify() {
+        let svg = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- Generator: Editor -->
+<!DOCTYPE svg PUBLIC "-//W3C//DTD SVG 1.1//EN" "http://www.w3.org/Graphics/SVG/1.1/DTD/svg11.dtd">
+<svg xmlns="http://www.w3.org/2000/svg" xmlns:inkscape="http://www.inkscape.org" xmlns:sodipodi="http://sodipodi.sourceforge.net" viewBox='0 0 10 10'>
+  <metadata><rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"></rdf:RDF></metadata>
+  <sodipodi:namedview inkscape:zoom="1"/>
+  <g inkscape:label="Layer">
+    <path d="M0 0h10v10z"/>
+  </g>
+  <text x="1"> Hello <tspan>world</tspan></text>
+</svg>
+"#;
+        assert_eq!(
+            minify(svg, &SvgMinifyOptions::default()).unwrap(),
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 10 10"><g><path d="M0 0h10v10z"/></g><text x="1"> Hello <tspan>world</tspan></text></svg>"#
+        );
+    }
+
+    #[test]
+    fn test_minify_remove_scripts() {
+        let svg = r#"<svg onload="alert(1)"><script>alert(2)</script><a href=" JavaScript:alert(3)"><rect/></a></svg>"#;
+        let options = SvgMinifyOptions {
+            remove_scripts: true,
+        };
+        assert_eq!(
+            minify(svg, &options).unwrap(),
+            r#"<svg><a><rect/></a></svg>"#
+        );
+        assert_eq!(minify(svg, &SvgMinifyOptions::default()).unwrap(), svg);
+    }
+
+    #[test]
+    fn test_minify_remove_scripts_bypasses() {
+        let svg = r##"<svg xmlns="http://www.w3.org/2000/svg" xmlns:svg="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink">
+  <svg:script>alert(1)</svg:script>
+  <SCRIPT>alert(2)</SCRIPT>
+  <a xlink:href="java&#x9;script:alert(3)">
+    <animate attributeName="href" values="javascript:alert(4)"/>
+    <set attributeName=" xlink:href" to="javascript:alert(5)"/>
+    <rect ONCLICK="alert(6)" fill="red"/>
+  </a>
+  <foreignObject>
+    <iframe xmlns="http://www.w3.org/1999/xhtml" src="javascript:alert(7)"/>
+    <img xmlns="http://www.w3.org/1999/xhtml" src="x" onerror="alert(8)"/>
+  </foreignObject>
+  <set attributeName="fill" to="blue"/>
+  <use href="#a"/>
+</svg>"##;
+        let options = SvgMinifyOptions {
+            remove_scripts: true,
+        };
+        assert_eq!(
+            minify(svg, &options).unwrap(),
+            r##"<svg xmlns="http://www.w3.org/2000/svg" xmlns:svg="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink"><a><rect fill="red"/></a><set attributeName="fill" to="blue"/><use href="#a"/></svg>"##
+        );
+    }
+
+    #[test]
+    fn test_minify_invalid() {
+        assert!(minify("<svg><g></svg>", &SvgMinifyOptions::default()).is_err());
+        assert!(minify("<svg><g></svg></g>", &SvgMinifyOptions::default()).is_err());
+        assert!(minify("<svg></g></svg>", &SvgMinifyOptions::default()).is_err());
+        assert!(minify("<svg width=\"1></svg>", &SvgMinifyOptions::default()).is_err());
+    }
+}
@@ -0,0 +1,6 @@
+mod component;
+mod minify;
+mod parse;
+
+pub use component::{svg_to_component, SvgComponentTransform};
+pub use minify::{minify, SvgMinifyOptions, SvgMinifyTransform};
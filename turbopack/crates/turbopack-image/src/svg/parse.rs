@@ -0,0 +1,298 @@
+use std::borrow::Cow;
+
+use anyhow::{Context, Result};
+use roxmltree::{Attribute, Children, Document, Node, ParsingOptions, StringStorage};
+
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+const XLINK_NAMESPACE: &str = "http://www.w3.org/1999/xlink";
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// A token of an SVG document. Comments, processing instructions, the document
+/// type declaration and editor data are not part of the tokens.
+#[derive(Debug, PartialEq)]
+pub(crate) enum SvgToken<'a> {
+    StartTag {
+        /// The qualified name, as written in the source.
+        name: &'a str,
+        /// The namespace declarations and attributes, with their qualified
+        /// names and decoded values.
+        attributes: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+        self_closing: bool,
+    },
+    EndTag {
+        name: &'a str,
+    },
+    /// Decoded text, including the content of CDATA sections.
+    Text(Cow<'a, str>),
+}
+
+/// Parses an SVG document and flattens it into tokens. When `remove_scripts`
+/// is set, everything which isn't known to be safe is removed, see
+/// [is_allowed_element] and [is_allowed_attribute].
+pub(crate) fn tokenize(source: &str, remove_scripts: bool) -> Result<Vec<SvgToken<'_>>> {
+    let document = Document::parse_with_options(
+        source,
+        ParsingOptions {
+            allow_dtd: true,
+            ..Default::default()
+        },
+    )
+    .context("failed to parse SVG")?;
+
+    let mut tokens = Vec::new();
+    // The children which are left to visit of each open element, with the name
+    // and the index of its start tag
+    let mut stack: Vec<(Children, Option<(&str, usize)>)> =
+        vec![(document.root().children(), None)];
+    while let Some((children, _)) = stack.last_mut() {
+        let Some(node) = children.next() else {
+            let Some((_, Some((name, start)))) = stack.pop() else {
+                continue;
+            };
+            if start == tokens.len() - 1 {
+                if let SvgToken::StartTag { self_closing, .. } = &mut tokens[start] {
+                    *self_closing = true;
+                }
+            } else {
+                tokens.push(SvgToken::EndTag { name });
+            }
+            continue;
+        };
+        if node.is_text() {
+            // Text outside of the root element is insignificant whitespace
+            if stack.len() > 1 {
+                let text = node.text_storage().context("text node without text")?;
+                tokens.push(SvgToken::Text(to_cow(text)));
+            }
+            continue;
+        }
+        if !node.is_element() {
+            continue;
+        }
+        let name = qualified_name(source, node.range().start + 1);
+        if is_editor_data(name) || (remove_scripts && !is_allowed_element(node)) {
+            continue;
+        }
+        let mut attributes = Vec::new();
+        let parent_namespaces = node
+            .parent_element()
+            .map(|parent| parent.namespaces().collect::<Vec<_>>())
+            .unwrap_or_default();
+        for namespace in node.namespaces() {
+            if namespace.name() == Some("xml") || parent_namespaces.contains(&namespace) {
+                continue;
+            }
+            let name = match namespace.name() {
+                Some(prefix) => Cow::Owned(format!("xmlns:{prefix}")),
+                None => Cow::Borrowed("xmlns"),
+            };
+            if !is_editor_data(&name) {
+                attributes.push((name, Cow::Owned(namespace.uri().to_string())));
+            }
+        }
+        for attribute in node.attributes() {
+            let name = qualified_name(source, attribute.position());
+            if is_editor_data(name) || (remove_scripts && !is_allowed_attribute(&attribute)) {
+                continue;
+            }
+            attributes.push((Cow::Borrowed(name), to_cow(attribute.value_storage())));
+        }
+        stack.push((node.children(), Some((name, tokens.len()))));
+        tokens.push(SvgToken::StartTag {
+            name,
+            attributes,
+            self_closing: false,
+        });
+    }
+    Ok(tokens)
+}
+
+fn to_cow<'a>(storage: &StringStorage<'a>) -> Cow<'a, str> {
+    match storage {
+        StringStorage::Borrowed(value) => Cow::Borrowed(value),
+        StringStorage::Owned(value) => Cow::Owned(value.to_string()),
+    }
+}
+
+/// Reads the qualified name of an element or attribute which starts at
+/// `position`.
+fn qualified_name(source: &str, position: usize) -> &str {
+    let name = &source[position..];
+    let end = name
+        .find(|c: char| c.is_whitespace() || matches!(c, '/' | '>' | '='))
+        .unwrap_or(name.len());
+    &name[..end]
+}
+
+/// Elements in which whitespace is significant.
+pub(crate) fn preserves_whitespace(name: &str) -> bool {
+    matches!(
+        name,
+        "text" | "tspan" | "textPath" | "title" | "desc" | "style" | "script" | "foreignObject"
+    )
+}
+
+/// Elements and attributes added by editors, which don't affect rendering.
+pub(crate) fn is_editor_data(name: &str) -> bool {
+    name == "metadata"
+        || ["sodipodi:", "inkscape:", "xmlns:sodipodi", "xmlns:inkscape"]
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+}
+
+/// SVG elements which can't run scripts or embed other documents. Notably
+/// `<script>`, `<foreignObject>` and `<iframe>` are not part of this list.
+const ALLOWED_ELEMENTS: &[&str] = &[
+    "a",
+    "animate",
+    "animateColor",
+    "animateMotion",
+    "animateTransform",
+    "circle",
+    "clipPath",
+    "defs",
+    "desc",
+    "ellipse",
+    "feBlend",
+    "feColorMatrix",
+    "feComponentTransfer",
+    "feComposite",
+    "feConvolveMatrix",
+    "feDiffuseLighting",
+    "feDisplacementMap",
+    "feDistantLight",
+    "feDropShadow",
+    "feFlood",
+    "feFuncA",
+    "feFuncB",
+    "feFuncG",
+    "feFuncR",
+    "feGaussianBlur",
+    "feImage",
+    "feMerge",
+    "feMergeNode",
+    "feMorphology",
+    "feOffset",
+    "fePointLight",
+    "feSpecularLighting",
+    "feSpotLight",
+    "feTile",
+    "feTurbulence",
+    "filter",
+    "g",
+    "image",
+    "line",
+    "linearGradient",
+    "marker",
+    "mask",
+    "mpath",
+    "path",
+    "pattern",
+    "polygon",
+    "polyline",
+    "radialGradient",
+    "rect",
+    "set",
+    "stop",
+    "style",
+    "svg",
+    "switch",
+    "symbol",
+    "text",
+    "textPath",
+    "title",
+    "tspan",
+    "use",
+    "view",
+];
+
+/// Whether an element is a known SVG element which can't run scripts. Elements
+/// in other namespaces, like `<html:script>`, are not allowed. Animations are
+/// only allowed if they can't set links or event handlers, as
+/// `<set attributeName="href" to="javascript:...">` would add a script link.
+fn is_allowed_element(node: Node) -> bool {
+    let name = node.tag_name();
+    if !matches!(name.namespace(), None | Some(SVG_NAMESPACE))
+        || !ALLOWED_ELEMENTS.contains(&name.name())
+    {
+        return false;
+    }
+    match name.name() {
+        "animate" | "animateColor" | "animateTransform" | "set" => {
+            node.attribute("attributeName").is_some_and(|target| {
+                let target = target.trim();
+                let target = target.rsplit_once(':').map_or(target, |(_, name)| name);
+                !target.eq_ignore_ascii_case("href") && !is_event_handler(target)
+            })
+        }
+        _ => true,
+    }
+}
+
+/// Whether an attribute can't run scripts. Event handlers are removed, and
+/// links are only allowed if they are relative or use a safe scheme.
+fn is_allowed_attribute(attribute: &Attribute) -> bool {
+    match attribute.namespace() {
+        None if is_event_handler(attribute.name()) => false,
+        None | Some(XLINK_NAMESPACE) if attribute.name() == "href" => {
+            is_safe_url(attribute.value())
+        }
+        None | Some(XLINK_NAMESPACE | XML_NAMESPACE) => true,
+        Some(_) => false,
+    }
+}
+
+fn is_event_handler(name: &str) -> bool {
+    name.len() > 2
+        && name
+            .get(..2)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("on"))
+}
+
+/// Whether a link is relative or uses a scheme which can't run scripts.
+/// Browsers ignore whitespace and control characters in URLs, so they are
+/// removed before the scheme is checked, e.g. `java&#x9;script:` is a
+/// `javascript:` link.
+fn is_safe_url(url: &str) -> bool {
+    let url = url
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_ascii_control())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    let Some((scheme, rest)) = url.split_once(':') else {
+        return true;
+    };
+    let is_scheme = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    if !is_scheme {
+        // A relative link like `./a:b` or `#a:b`
+        return true;
+    }
+    match scheme {
+        "http" | "https" | "mailto" => true,
+        "data" => ["image/png", "image/jpeg", "image/gif", "image/webp"]
+            .iter()
+            .any(|mime| rest.starts_with(mime)),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_safe_url;
+
+    #[test]
+    fn test_is_safe_url() {
+        assert!(is_safe_url("#icon"));
+        assert!(is_safe_url("./a:b.svg"));
+        assert!(is_safe_url("https://example.com"));
+        assert!(is_safe_url("data:image/png;base64,AA=="));
+        assert!(!is_safe_url("data:image/svg+xml;base64,AA=="));
+        assert!(!is_safe_url(" JavaScript:alert(1)"));
+        assert!(!is_safe_url("java\tscript:alert(1)"));
+        assert!(!is_safe_url("\u{1}javascript:alert(1)"));
+        assert!(!is_safe_url("vbscript:alert(1)"));
+    }
+}
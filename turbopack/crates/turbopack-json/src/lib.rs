@@ -3,7 +3,8 @@
 //! JSON assets are parsed to ensure they contain valid JSON.
 //!
 //! When imported from ES modules, they produce a module that exports the
-//! JSON value as an object.
+//! JSON value as an object. Named imports of top-level properties only include
+//! the imported properties, so unused properties can be tree shaken.
 
 #![feature(min_specialization)]
 #![feature(arbitrary_self_types)]
@@ -11,7 +12,9 @@
 use std::fmt::Write;
 
 use anyhow::{bail, Error, Result};
-use turbo_tasks::{RcStr, ValueToString, Vc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use turbo_tasks::{trace::TraceRawVcs, RcStr, TaskInput, ValueToString, Vc};
 use turbo_tasks_fs::{FileContent, FileJsonContent};
 use turbopack_core::{
     asset::{Asset, AssetContent},
@@ -19,6 +22,7 @@ use turbopack_core::{
     ident::AssetIdent,
     module::Module,
     reference::ModuleReferences,
+    resolve::ModulePart,
     source::Source,
 };
 use turbopack_ecmascript::chunk::{
@@ -31,16 +35,117 @@ fn modifier() -> Vc<RcStr> {
     Vc::cell("json".into())
 }
 
+/// A threshold for [JsonModuleAsset::new] below which values are faster to
+/// parse as object literals than as `JSON.parse` calls.
+pub const DEFAULT_OBJECT_LITERAL_THRESHOLD: usize = 10 * 1024;
+
+#[derive(
+    PartialOrd,
+    Ord,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    Serialize,
+    Deserialize,
+    TaskInput,
+    TraceRawVcs,
+)]
+pub enum JsonSourceType {
+    /// Plain JSON files (.json).
+    #[default]
+    Json,
+    /// JSON with comments and trailing commas (.jsonc).
+    Jsonc,
+    /// JSON5 files (.json5).
+    Json5,
+}
+
 #[turbo_tasks::value]
 pub struct JsonModuleAsset {
     source: Vc<Box<dyn Source>>,
+    source_ty: JsonSourceType,
+    object_literal_threshold: Option<usize>,
+    /// The only top-level property which is exported, see
+    /// [JsonModuleAsset::with_export].
+    export: Option<RcStr>,
 }
 
 #[turbo_tasks::value_impl]
 impl JsonModuleAsset {
+    /// Creates a module exporting the value of a JSON file.
+    ///
+    /// Values whose JSON is shorter than `object_literal_threshold` bytes are
+    /// emitted as object literals, which minifiers can optimize. Other values
+    /// are emitted as `JSON.parse` calls, which are faster to parse for large
+    /// values.
+    #[turbo_tasks::function]
+    pub fn new(
+        source: Vc<Box<dyn Source>>,
+        source_ty: JsonSourceType,
+        object_literal_threshold: Option<usize>,
+    ) -> Vc<Self> {
+        Self::cell(JsonModuleAsset {
+            source,
+            source_ty,
+            object_literal_threshold,
+            export: None,
+        })
+    }
+
+    /// Returns a module which only contains the top-level property `export`
+    /// of the JSON object, so importing one property doesn't include the
+    /// whole file. Values are copied into each of these modules, so they
+    /// aren't identical to the properties of the whole value, and a property
+    /// is included twice when the whole value is imported as well.
+    ///
+    /// Returns the module itself when the value is not an object with that
+    /// property.
+    #[turbo_tasks::function]
+    pub async fn with_export(self: Vc<Self>, export: RcStr) -> Result<Vc<Self>> {
+        let this = self.await?;
+        if this.export.is_some() || export == "default" {
+            return Ok(self);
+        }
+        let FileJsonContent::Content(Value::Object(object)) = &*self.json().await? else {
+            return Ok(self);
+        };
+        if !object.contains_key(export.as_str()) {
+            return Ok(self);
+        }
+        Ok(Self::cell(JsonModuleAsset {
+            source: this.source,
+            source_ty: this.source_ty,
+            object_literal_threshold: this.object_literal_threshold,
+            export: Some(export),
+        }))
+    }
+
+    /// The parsed value of the module, which only contains the exported
+    /// property when there is one.
     #[turbo_tasks::function]
-    pub fn new(source: Vc<Box<dyn Source>>) -> Vc<Self> {
-        Self::cell(JsonModuleAsset { source })
+    async fn json(&self) -> Result<Vc<FileJsonContent>> {
+        let content = self.source.content().file_content();
+        let json = match self.source_ty {
+            JsonSourceType::Json => content.parse_json(),
+            JsonSourceType::Jsonc => content.parse_json_with_comments(),
+            JsonSourceType::Json5 => content.parse_json5(),
+        };
+        let Some(export) = &self.export else {
+            return Ok(json);
+        };
+        // The value might have changed since the export was selected.
+        let FileJsonContent::Content(Value::Object(object)) = &*json.await? else {
+            return Ok(json);
+        };
+        let value = object.get(export.as_str()).cloned().unwrap_or(Value::Null);
+        Ok(
+            FileJsonContent::Content(Value::Object(Map::from_iter([(export.to_string(), value)])))
+                .cell(),
+        )
     }
 }
 
@@ -48,7 +153,11 @@ impl JsonModuleAsset {
 impl Module for JsonModuleAsset {
     #[turbo_tasks::function]
     fn ident(&self) -> Vc<AssetIdent> {
-        self.source.ident().with_modifier(modifier())
+        let ident = self.source.ident().with_modifier(modifier());
+        match &self.export {
+            Some(export) => ident.with_part(ModulePart::export(export.clone())),
+            None => ident,
+        }
     }
 }
 
@@ -130,12 +239,21 @@ impl EcmascriptChunkItem for JsonChunkItem {
         // We parse to JSON and then stringify again to ensure that the
         // JSON is valid.
         let content = self.module.content().file_content();
-        let data = content.parse_json().await?;
+        let data = self.module.json().await?;
         match &*data {
             FileJsonContent::Content(data) => {
-                let js_str_content = serde_json::to_string(&data.to_string())?;
-                let inner_code =
-                    format!("__turbopack_export_value__(JSON.parse({js_str_content}));");
+                let json = data.to_string();
+                let object_literal_threshold = self.module.await?.object_literal_threshold;
+                // `__proto__` properties would set the prototype in object literals.
+                let inner_code = if object_literal_threshold
+                    .is_some_and(|threshold| json.len() < threshold)
+                    && !has_proto_property(data)
+                {
+                    format!("__turbopack_export_value__({json});")
+                } else {
+                    let js_str_content = serde_json::to_string(&json)?;
+                    format!("__turbopack_export_value__(JSON.parse({js_str_content}));")
+                };
 
                 Ok(EcmascriptChunkItemContent {
                     inner_code: inner_code.into(),
@@ -164,6 +282,16 @@ impl EcmascriptChunkItem for JsonChunkItem {
     }
 }
 
+fn has_proto_property(value: &Value) -> bool {
+    match value {
+        Value::Object(object) => object
+            .iter()
+            .any(|(key, value)| key == "__proto__" || has_proto_property(value)),
+        Value::Array(array) => array.iter().any(has_proto_property),
+        _ => false,
+    }
+}
+
 pub fn register() {
     turbo_tasks::register();
     turbo_tasks_fs::register();
@@ -72,7 +72,8 @@ export const init = async (ipc: Ipc<IpcInfoMessage, IpcRequestMessage>) => {
 export default async function transform(
   ipc: Ipc<IpcInfoMessage, IpcRequestMessage>,
   cssContent: string,
-  name: string
+  name: string,
+  prevMap: string | null
 ) {
   const { css, map, messages } = await processor.process(cssContent, {
     from: name,
@@ -80,6 +81,7 @@ export default async function transform(
     map: {
       inline: false,
       annotation: false,
+      prev: prevMap ?? undefined,
     },
   });
 
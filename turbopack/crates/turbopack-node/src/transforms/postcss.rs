@@ -21,6 +21,7 @@ use turbopack_core::{
     reference_type::{EntryReferenceSubType, InnerAssets, ReferenceType},
     resolve::{find_context_file, options::ImportMapping, FindContextFileResult},
     source::Source,
+    source_map::{GenerateSourceMap, OptionSourceMap, SourceMap},
     source_transform::SourceTransform,
     virtual_source::VirtualSource,
 };
@@ -160,12 +161,35 @@ impl Asset for PostCssTransformedAsset {
     }
 }
 
+#[turbo_tasks::value_impl]
+impl GenerateSourceMap for PostCssTransformedAsset {
+    #[turbo_tasks::function]
+    async fn generate_source_map(self: Vc<Self>) -> Result<Vc<OptionSourceMap>> {
+        Ok(Vc::cell(self.process().await?.source_map))
+    }
+}
+
 #[turbo_tasks::value]
 struct ProcessPostCssResult {
     content: Vc<AssetContent>,
+    source_map: Option<Vc<SourceMap>>,
     assets: Vec<Vc<VirtualSource>>,
 }
 
+/// Returns the source map of `source`, if it has been transformed before, e.g.
+/// from Sass.
+async fn input_source_map(source: Vc<Box<dyn Source>>) -> Result<Option<Vc<SourceMap>>> {
+    Ok(
+        if let Some(generate_source_map) =
+            Vc::try_resolve_sidecast::<Box<dyn GenerateSourceMap>>(source).await?
+        {
+            *generate_source_map.generate_source_map().await?
+        } else {
+            None
+        },
+    )
+}
+
 #[turbo_tasks::function]
 async fn config_changed(
     asset_context: Vc<Box<dyn AssetContext>>,
@@ -342,6 +366,7 @@ impl PostCssTransformedAsset {
         else {
             return Ok(ProcessPostCssResult {
                 content: this.source.content(),
+                source_map: input_source_map(this.source).await?,
                 assets: Vec::new(),
             }
             .cell());
@@ -354,6 +379,7 @@ impl PostCssTransformedAsset {
         let FileContent::Content(content) = &*file.await? else {
             return Ok(ProcessPostCssResult {
                 content: AssetContent::File(FileContent::NotFound.cell()).cell(),
+                source_map: None,
                 assets: Vec::new(),
             }
             .cell());
@@ -380,6 +406,13 @@ impl PostCssTransformedAsset {
             "".into()
         };
 
+        // PostCSS combines its source map with the one of the input, so the result
+        // points to the original sources.
+        let prev_map = match input_source_map(this.source).await? {
+            Some(source_map) => Some(source_map.to_rope().await?.to_str()?.into_owned()),
+            None => None,
+        };
+
         let config_value = evaluate_webpack_loader(WebpackLoaderContext {
             module_asset: postcss_executor,
             cwd: project_path,
@@ -388,7 +421,11 @@ impl PostCssTransformedAsset {
             asset_context: evaluate_context,
             chunking_context,
             resolve_options_context: None,
-            args: vec![Vc::cell(content.into()), Vc::cell(css_path.into())],
+            args: vec![
+                Vc::cell(content.into()),
+                Vc::cell(css_path.into()),
+                Vc::cell(prev_map.into()),
+            ],
             additional_invalidation: config_changed,
         })
         .await?;
@@ -397,6 +434,7 @@ impl PostCssTransformedAsset {
             // An error happened, which has already been converted into an issue.
             return Ok(ProcessPostCssResult {
                 content: AssetContent::File(FileContent::NotFound.cell()).cell(),
+                source_map: None,
                 assets: Vec::new(),
             }
             .cell());
@@ -404,11 +442,22 @@ impl PostCssTransformedAsset {
         let processed_css: PostCssProcessingResult = parse_json_with_source_context(val.to_str()?)
             .context("Unable to deserializate response from PostCSS transform operation")?;
 
-        // TODO handle SourceMap
+        let source_map = if let Some(source_map) = processed_css.map {
+            SourceMap::new_from_file_content(FileContent::Content(File::from(source_map)).cell())
+                .await?
+                .map(|source_map| source_map.cell())
+        } else {
+            None
+        };
         let file = File::from(processed_css.css);
         let assets = emitted_assets_to_virtual_sources(processed_css.assets);
         let content = AssetContent::File(FileContent::Content(file).cell()).cell();
-        Ok(ProcessPostCssResult { content, assets }.cell())
+        Ok(ProcessPostCssResult {
+            content,
+            source_map,
+            assets,
+        }
+        .cell())
     }
 }
 
@@ -53,6 +53,11 @@ impl NodeJsChunkingContextBuilder {
         self
     }
 
+    pub fn single_ordered_chunk(mut self, single_ordered_chunk: bool) -> Self {
+        self.chunking_context.single_ordered_chunk = single_ordered_chunk;
+        self
+    }
+
     /// Builds the chunking context.
     pub fn build(self) -> Vc<NodeJsChunkingContext> {
         NodeJsChunkingContext::new(Value::new(self.chunking_context))
@@ -84,6 +89,8 @@ pub struct NodeJsChunkingContext {
     minify_type: MinifyType,
     /// Whether to use manifest chunks for lazy compilation
     manifest_chunks: bool,
+    /// Whether to put all CSS of a chunk group into a single chunk
+    single_ordered_chunk: bool,
 }
 
 impl NodeJsChunkingContext {
@@ -109,6 +116,7 @@ impl NodeJsChunkingContext {
                 runtime_type,
                 minify_type: MinifyType::NoMinify,
                 manifest_chunks: false,
+                single_ordered_chunk: false,
             },
         }
     }
@@ -222,6 +230,11 @@ impl ChunkingContext for NodeJsChunkingContext {
         Vc::cell(true)
     }
 
+    #[turbo_tasks::function]
+    fn single_ordered_chunk(&self) -> Vc<bool> {
+        Vc::cell(self.single_ordered_chunk)
+    }
+
     #[turbo_tasks::function]
     async fn asset_path(
         &self,
@@ -0,0 +1,396 @@
+use anyhow::Result;
+use serde_json::{Map, Value as JsonValue};
+use turbo_tasks::{RcStr, Vc};
+use turbo_tasks_fs::{File, FileJsonContent, FileSystemPath};
+use turbopack_core::{
+    asset::AssetContent,
+    issue::{Issue, IssueExt, IssueSeverity, IssueStage, OptionStyledString, StyledString},
+    output::OutputAsset,
+    resolve::{
+        options::{ImportMap, ImportMapping},
+        ExternalType,
+    },
+    virtual_output::VirtualOutputAsset,
+};
+
+#[turbo_tasks::value(shared)]
+pub struct BrowserImportMapIssue {
+    pub path: Vc<FileSystemPath>,
+    pub severity: Vc<IssueSeverity>,
+    pub message: RcStr,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for BrowserImportMapIssue {
+    #[turbo_tasks::function]
+    fn severity(&self) -> Vc<IssueSeverity> {
+        self.severity
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Text("An issue occurred while parsing an import map.".into()).cell()
+    }
+
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        self.path
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        Vc::cell(Some(StyledString::Text(self.message.clone()).cell()))
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Resolve.cell()
+    }
+}
+
+fn emit_issue(path: Vc<FileSystemPath>, severity: IssueSeverity, message: impl Into<RcStr>) {
+    BrowserImportMapIssue {
+        path,
+        severity: severity.cell(),
+        message: message.into(),
+    }
+    .cell()
+    .emit();
+}
+
+/// Returns true when the address of an import map entry is an absolute URL,
+/// e.g. `https://cdn.example.com/lib.js` or `data:text/javascript,...`, or a
+/// scheme-relative one, e.g. `//cdn.example.com/lib.js`.
+fn is_url(address: &str) -> bool {
+    if address.starts_with("//") {
+        return true;
+    }
+    let Some((scheme, _)) = address.split_once(':') else {
+        return false;
+    };
+    let mut chars = scheme.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+/// Converts an import map address into an [ImportMapping]. URLs become URL
+/// externals and `/`, `./` and `../` addresses are resolved relative to `root`.
+///
+/// Returns `None` for any other address, as import maps only accept URL-like
+/// addresses. E.g. `vendor/lib.js` is not resolved as a module request.
+fn address_to_mapping(address: &str, root: Vc<FileSystemPath>) -> Option<Vc<ImportMapping>> {
+    if is_url(address) {
+        Some(ImportMapping::External(Some(address.into()), ExternalType::Url).cell())
+    } else if let Some(path) = address.strip_prefix('/') {
+        Some(ImportMapping::PrimaryAlternative(format!("./{path}").into(), Some(root)).cell())
+    } else if address.starts_with("./") || address.starts_with("../") {
+        Some(ImportMapping::PrimaryAlternative(address.into(), Some(root)).cell())
+    } else {
+        None
+    }
+}
+
+/// Adds the entries of an `imports` object or of a single scope to
+/// `import_map`. Specifiers ending with `/` map whole prefixes.
+///
+/// Invalid entries are reported as warnings and skipped, like browsers do.
+fn insert_specifier_map(
+    import_map: &mut ImportMap,
+    import_map_path: Vc<FileSystemPath>,
+    root: Vc<FileSystemPath>,
+    specifier_map: &Map<String, JsonValue>,
+) {
+    for (specifier, address) in specifier_map {
+        let Some(address) = address.as_str() else {
+            emit_issue(
+                import_map_path,
+                IssueSeverity::Warning,
+                format!("the address of \"{specifier}\" must be a string"),
+            );
+            continue;
+        };
+        let is_prefix = specifier.ends_with('/');
+        if is_prefix && !address.ends_with('/') {
+            emit_issue(
+                import_map_path,
+                IssueSeverity::Warning,
+                format!("the address of \"{specifier}\" must end with a `/` as the specifier does"),
+            );
+            continue;
+        }
+        let Some(mapping) = address_to_mapping(
+            &if is_prefix {
+                format!("{address}*")
+            } else {
+                address.to_string()
+            },
+            root,
+        ) else {
+            emit_issue(
+                import_map_path,
+                IssueSeverity::Warning,
+                format!(
+                    "the address \"{address}\" of \"{specifier}\" is invalid, it must be a URL or \
+                     start with `/`, `./` or `../`"
+                ),
+            );
+            continue;
+        };
+        if is_prefix {
+            import_map.insert_wildcard_alias(specifier.as_str(), mapping);
+        } else {
+            import_map.insert_exact_alias(specifier.as_str(), mapping);
+        }
+    }
+}
+
+/// Returns the normalized prefix of a scope key, relative to the directory
+/// containing the import map, e.g. `/vendor/`, `./vendor/` and `vendor/` all
+/// become `vendor/`.
+///
+/// Returns `None` for scopes which can't contain bundled modules: scopes that
+/// are URLs, like `https://cdn.example.com/`, only apply to modules loaded
+/// from there, and scopes starting with `../` are outside of the root. Scopes
+/// not ending with `/` only apply to the module at exactly that URL, so they
+/// aren't prefixes either.
+fn scope_prefix(scope: &str) -> Option<&str> {
+    if is_url(scope) {
+        return None;
+    }
+    let scope = scope
+        .strip_prefix("./")
+        .or_else(|| scope.strip_prefix('/'))
+        .unwrap_or(scope);
+    if scope == "." {
+        return Some("");
+    }
+    if scope == ".." || scope.starts_with("../") {
+        return None;
+    }
+    if !scope.is_empty() && !scope.ends_with('/') {
+        return None;
+    }
+    Some(scope)
+}
+
+/// An import map file, parsed into the [ImportMap]s of its top-level
+/// `imports` and of each of its `scopes`.
+#[turbo_tasks::value]
+struct ParsedBrowserImportMap {
+    imports: Vc<ImportMap>,
+    /// The prefixes of the scopes containing bundled modules, see
+    /// [scope_prefix], ordered from the least to the most specific one.
+    scopes: Vec<(RcStr, Vc<ImportMap>)>,
+}
+
+/// Parses the import map at `import_map_path` once, independent of the
+/// requests it's used for. This also makes sure issues are only reported once
+/// per file.
+#[turbo_tasks::function]
+async fn parse_browser_import_map(
+    import_map_path: Vc<FileSystemPath>,
+) -> Result<Vc<ParsedBrowserImportMap>> {
+    let mut parsed = ParsedBrowserImportMap {
+        imports: ImportMap::empty().cell(),
+        scopes: Vec::new(),
+    };
+
+    let content = import_map_path.read_json().await?;
+    let json = match &*content {
+        FileJsonContent::Content(json) => json,
+        FileJsonContent::NotFound => {
+            emit_issue(
+                import_map_path,
+                IssueSeverity::Error,
+                "import map not found",
+            );
+            return Ok(parsed.cell());
+        }
+        FileJsonContent::Unparseable(e) => {
+            emit_issue(
+                import_map_path,
+                IssueSeverity::Error,
+                format!("import map is not parseable: invalid JSON: {e}"),
+            );
+            return Ok(parsed.cell());
+        }
+    };
+
+    let root = import_map_path.parent().resolve().await?;
+
+    match json.get("imports") {
+        Some(JsonValue::Object(imports)) => {
+            let mut import_map = ImportMap::empty();
+            insert_specifier_map(&mut import_map, import_map_path, root, imports);
+            parsed.imports = import_map.cell();
+        }
+        Some(_) => emit_issue(
+            import_map_path,
+            IssueSeverity::Error,
+            "\"imports\" must be an object",
+        ),
+        None => {}
+    }
+
+    match json.get("scopes") {
+        Some(JsonValue::Object(scopes)) => {
+            for (scope, map) in scopes {
+                let Some(map) = map.as_object() else {
+                    emit_issue(
+                        import_map_path,
+                        IssueSeverity::Error,
+                        format!("the scope \"{scope}\" must be an object"),
+                    );
+                    continue;
+                };
+                let Some(prefix) = scope_prefix(scope) else {
+                    if !is_url(scope) && !scope.ends_with('/') && scope != ".." {
+                        emit_issue(
+                            import_map_path,
+                            IssueSeverity::Warning,
+                            format!(
+                                "the scope \"{scope}\" is ignored, scopes only apply to bundled \
+                                 modules when they end with a `/`"
+                            ),
+                        );
+                    }
+                    continue;
+                };
+                let mut import_map = ImportMap::empty();
+                insert_specifier_map(&mut import_map, import_map_path, root, map);
+                parsed.scopes.push((prefix.into(), import_map.cell()));
+            }
+            // Later scopes override earlier ones, so the most specific scope needs to
+            // come last.
+            parsed.scopes.sort_by_key(|(prefix, _)| prefix.len());
+        }
+        Some(_) => emit_issue(
+            import_map_path,
+            IssueSeverity::Error,
+            "\"scopes\" must be an object",
+        ),
+        None => {}
+    }
+
+    Ok(parsed.cell())
+}
+
+/// Translates a [W3C import map] JSON file, as used in
+/// `<script type="importmap">`, into an [ImportMap] for requests made from
+/// `lookup_path`.
+///
+/// The top-level `imports` always apply, while `scopes` only apply when
+/// `lookup_path` is inside of the scope, with more specific scopes taking
+/// precedence. Scopes and path addresses are relative to the directory
+/// containing the import map, which is treated as the root of the served site.
+/// Addresses that are URLs become URL externals.
+///
+/// [W3C import map]: https://html.spec.whatwg.org/multipage/webappapis.html#import-maps
+#[turbo_tasks::function]
+pub async fn browser_import_map(
+    import_map_path: Vc<FileSystemPath>,
+    lookup_path: Vc<FileSystemPath>,
+) -> Result<Vc<ImportMap>> {
+    let parsed = parse_browser_import_map(import_map_path).await?;
+    if parsed.scopes.is_empty() {
+        return Ok(parsed.imports);
+    }
+
+    let root = import_map_path.parent().await?;
+    let lookup_path = lookup_path.await?;
+    let lookup_dir = if *lookup_path == *root {
+        String::new()
+    } else if let Some(relative_lookup_path) = root.get_path_to(&lookup_path) {
+        format!("{relative_lookup_path}/")
+    } else {
+        return Ok(parsed.imports);
+    };
+
+    let mut matching_scopes = parsed
+        .scopes
+        .iter()
+        .filter(|(prefix, _)| lookup_dir.starts_with(&**prefix))
+        .peekable();
+    if matching_scopes.peek().is_none() {
+        return Ok(parsed.imports);
+    }
+
+    let mut import_map = parsed.imports.await?.clone_value();
+    for (_, scope_import_map) in matching_scopes {
+        import_map.extend_ref(&*scope_import_map.await?);
+    }
+    Ok(import_map.cell())
+}
+
+/// Creates an import map containing only the entries of the import map at
+/// `import_map_path` that map to URLs, i. e. the ones that are kept as
+/// externals in the output. It can be served in a `<script type="importmap">`
+/// next to the bundled code.
+#[turbo_tasks::function]
+pub async fn browser_import_map_for_externals(
+    import_map_path: Vc<FileSystemPath>,
+    output_path: Vc<FileSystemPath>,
+) -> Result<Vc<Box<dyn OutputAsset>>> {
+    fn filter_urls(specifier_map: &Map<String, JsonValue>) -> Map<String, JsonValue> {
+        specifier_map
+            .iter()
+            .filter(|(_, address)| address.as_str().is_some_and(is_url))
+            .map(|(specifier, address)| (specifier.clone(), address.clone()))
+            .collect()
+    }
+
+    let mut output = Map::new();
+    if let FileJsonContent::Content(json) = &*import_map_path.read_json().await? {
+        if let Some(imports) = json.get("imports").and_then(JsonValue::as_object) {
+            output.insert("imports".to_string(), filter_urls(imports).into());
+        }
+        if let Some(scopes) = json.get("scopes").and_then(JsonValue::as_object) {
+            let scopes = scopes
+                .iter()
+                .filter_map(|(scope, map)| {
+                    let map = filter_urls(map.as_object()?);
+                    (!map.is_empty()).then(|| (scope.clone(), JsonValue::from(map)))
+                })
+                .collect::<Map<_, _>>();
+            if !scopes.is_empty() {
+                output.insert("scopes".to_string(), scopes.into());
+            }
+        }
+    }
+
+    Ok(Vc::upcast(VirtualOutputAsset::new(
+        output_path,
+        AssetContent::file(File::from(serde_json::to_string_pretty(&output)?).into()),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_url, scope_prefix};
+
+    #[test]
+    fn url_addresses() {
+        assert!(is_url("https://cdn.example.com/react.js"));
+        assert!(is_url("//cdn.example.com/react.js"));
+        assert!(!is_url("/vendor/react.js"));
+        assert!(!is_url("./vendor/react.js"));
+        assert!(is_url("data:text/javascript,export default 1"));
+        assert!(!is_url("react"));
+        assert!(!is_url("vendor/react.js"));
+    }
+
+    #[test]
+    fn scope_prefixes() {
+        assert_eq!(scope_prefix("/vendor/"), Some("vendor/"));
+        assert_eq!(scope_prefix("./vendor/"), Some("vendor/"));
+        assert_eq!(scope_prefix("vendor/"), Some("vendor/"));
+        assert_eq!(scope_prefix(".well-known/"), Some(".well-known/"));
+        assert_eq!(scope_prefix("/"), Some(""));
+        assert_eq!(scope_prefix("./"), Some(""));
+        assert_eq!(scope_prefix("https://cdn.example.com/vendor/"), None);
+        assert_eq!(scope_prefix("//cdn.example.com/vendor/"), None);
+        assert_eq!(scope_prefix("../vendor/"), None);
+        assert_eq!(scope_prefix("/vendor"), None);
+        assert_eq!(scope_prefix("/vendor/index.js"), None);
+    }
+}
@@ -1,5 +1,6 @@
 #![feature(arbitrary_self_types)]
 
+pub mod browser_import_map;
 pub mod ecmascript;
 pub mod node_native_binding;
 pub mod resolve;
@@ -3,19 +3,24 @@ use indexmap::IndexMap;
 use lazy_static::lazy_static;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use turbo_tasks::{RcStr, TryFlatJoinIterExt, ValueToString, Vc};
+use turbo_tasks::{RcStr, TryFlatJoinIterExt, Value, ValueToString, Vc};
 use turbo_tasks_fs::{
     glob::Glob, json::parse_json_rope_with_source_context, DirectoryEntry, FileContent,
-    FileSystemEntryType, FileSystemPath,
+    FileJsonContent, FileSystemEntryType, FileSystemPath,
 };
 use turbopack_core::{
     asset::{Asset, AssetContent},
     file_source::FileSource,
     raw_module::RawModule,
     reference::ModuleReference,
-    resolve::{pattern::Pattern, resolve_raw, ModuleResolveResult, RequestKey, ResolveResultItem},
+    reference_type::ReferenceType,
+    resolve::{
+        find_context_file, node::node_cjs_resolve_options, package_json, parse::Request,
+        pattern::Pattern, resolve, resolve_raw, FindContextFileResult, ModuleResolveResult,
+        RequestKey, ResolveResultItem,
+    },
     source::Source,
-    target::{CompileTarget, Platform},
+    target::{Arch, CompileTarget, Libc, Platform},
 };
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -249,17 +254,95 @@ impl ValueToString for NodeGypBuildReference {
     }
 }
 
-#[turbo_tasks::function]
-pub async fn resolve_node_gyp_build_files(
-    context_dir: Vc<FileSystemPath>,
-    compile_target: Vc<CompileTarget>,
-) -> Result<Vc<ModuleResolveResult>> {
+/// Converts the Python literal syntax of `.gyp` files into JSON: comments are
+/// removed, single quoted strings are converted into double quoted strings
+/// and trailing commas are dropped.
+fn gyp_to_json(source: &str) -> String {
+    let mut json = String::with_capacity(source.len());
+    let mut chars = source.chars();
+    let mut pending_comma = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '#' => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        json.push('\n');
+                        break;
+                    }
+                }
+                continue;
+            }
+            c if c.is_whitespace() => {
+                json.push(c);
+                continue;
+            }
+            ',' => {
+                pending_comma = true;
+                continue;
+            }
+            _ => {}
+        }
+        if pending_comma && c != ']' && c != '}' {
+            json.push(',');
+        }
+        pending_comma = false;
+        match c {
+            '\'' | '"' => {
+                let quote = c;
+                json.push('"');
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => {
+                            if let Some(escaped) = chars.next() {
+                                if escaped != '\'' {
+                                    json.push('\\');
+                                }
+                                json.push(escaped);
+                            }
+                        }
+                        c if c == quote => break,
+                        '"' => json.push_str("\\\""),
+                        c => json.push(c),
+                    }
+                }
+                json.push('"');
+            }
+            c => json.push(c),
+        }
+    }
+    json
+}
+
+/// Returns the names of all targets declared in a `binding.gyp` file.
+fn gyp_target_names(source: &str) -> Vec<String> {
     lazy_static! {
-        // TODO Proper parser
         static ref GYP_BUILD_TARGET_NAME: Regex =
             Regex::new(r#"['"]target_name['"]\s*:\s*(?:"(.*?)"|'(.*?)')"#)
                 .expect("create napi_build_version regex failed");
     }
+    match serde_json::from_str::<serde_json::Value>(&gyp_to_json(source)) {
+        Ok(gyp) => gyp["targets"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|target| target["target_name"].as_str())
+            .map(|name| name.to_string())
+            .collect(),
+        // Fall back to searching for the target names when the file uses
+        // Python syntax that isn't supported by the conversion.
+        Err(_) => GYP_BUILD_TARGET_NAME
+            .captures_iter(source)
+            .filter_map(|captured| captured.iter().skip(1).flatten().next())
+            .map(|name| name.as_str().to_string())
+            .collect(),
+    }
+}
+
+#[turbo_tasks::function]
+pub async fn resolve_node_gyp_build_files(
+    context_dir: Vc<FileSystemPath>,
+    compile_target: Vc<CompileTarget>,
+) -> Result<Vc<ModuleResolveResult>> {
     let binding_gyp_pat = Pattern::new(Pattern::Constant("binding.gyp".into()));
     let gyp_file = resolve_raw(context_dir, binding_gyp_pat, true);
     if let [binding_gyp] = &gyp_file.primary_sources().await?[..] {
@@ -267,13 +350,11 @@ pub async fn resolve_node_gyp_build_files(
             gyp_file.await?.get_affecting_sources().collect::<Vec<_>>();
         if let AssetContent::File(file) = &*binding_gyp.content().await? {
             if let FileContent::Content(config_file) = &*file.await? {
-                if let Some(captured) =
-                    GYP_BUILD_TARGET_NAME.captures(&config_file.content().to_str()?)
-                {
+                let target_names = gyp_target_names(&config_file.content().to_str()?);
+                if !target_names.is_empty() {
                     let mut resolved: IndexMap<RcStr, Vc<Box<dyn Source>>> =
-                        IndexMap::with_capacity(captured.len());
-                    for found in captured.iter().skip(1).flatten() {
-                        let name = found.as_str();
+                        IndexMap::with_capacity(target_names.len());
+                    for name in target_names {
                         let target_path = context_dir.join("build/Release".into());
                         let resolved_prebuilt_file = resolve_raw(
                             target_path,
@@ -417,3 +498,275 @@ pub async fn resolve_node_bindings_files(
         .await?;
     Ok(ModuleResolveResult::modules(modules).cell())
 }
+
+/// Detects the native addons of a package that are loaded in a way the code
+/// analysis can't follow, e.g. from platform specific packages or folders.
+#[turbo_tasks::value_trait]
+pub trait NativeBindingDetector {
+    /// Returns the native addons of the package in `package_dir` that are
+    /// needed on `compile_target`.
+    fn detect(
+        self: Vc<Self>,
+        package_dir: Vc<FileSystemPath>,
+        compile_target: Vc<CompileTarget>,
+    ) -> Vc<ModuleResolveResult>;
+}
+
+#[turbo_tasks::value(transparent)]
+pub struct NativeBindingDetectors(Vec<Vc<Box<dyn NativeBindingDetector>>>);
+
+/// The built-in detectors: napi-rs platform packages and prebuildify
+/// `prebuilds` folders.
+#[turbo_tasks::function]
+pub fn default_native_binding_detectors() -> Vc<NativeBindingDetectors> {
+    Vc::cell(vec![
+        Vc::upcast(NapiRsDetector::new()),
+        Vc::upcast(PrebuildifyDetector::new()),
+    ])
+}
+
+#[turbo_tasks::value]
+#[derive(Hash, Clone, Debug)]
+pub struct NativeBindingsReference {
+    pub context_dir: Vc<FileSystemPath>,
+    pub compile_target: Vc<CompileTarget>,
+    pub detectors: Vc<NativeBindingDetectors>,
+}
+
+#[turbo_tasks::value_impl]
+impl NativeBindingsReference {
+    #[turbo_tasks::function]
+    pub fn new(
+        context_dir: Vc<FileSystemPath>,
+        compile_target: Vc<CompileTarget>,
+        detectors: Vc<NativeBindingDetectors>,
+    ) -> Vc<Self> {
+        Self::cell(NativeBindingsReference {
+            context_dir,
+            compile_target,
+            detectors,
+        })
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ModuleReference for NativeBindingsReference {
+    #[turbo_tasks::function]
+    fn resolve_reference(&self) -> Vc<ModuleResolveResult> {
+        resolve_native_bindings(self.context_dir, self.compile_target, self.detectors)
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl ValueToString for NativeBindingsReference {
+    #[turbo_tasks::function]
+    async fn to_string(&self) -> Result<Vc<RcStr>> {
+        let context_dir = self.context_dir.to_string().await?;
+        let compile_target = self.compile_target.await?;
+        Ok(Vc::cell(
+            format!("native bindings in {} for {}", context_dir, compile_target).into(),
+        ))
+    }
+}
+
+/// Runs all `detectors` on the package containing `context_dir`.
+#[turbo_tasks::function]
+pub async fn resolve_native_bindings(
+    context_dir: Vc<FileSystemPath>,
+    compile_target: Vc<CompileTarget>,
+    detectors: Vc<NativeBindingDetectors>,
+) -> Result<Vc<ModuleResolveResult>> {
+    let FindContextFileResult::Found(package_json, _) =
+        *find_context_file(context_dir, package_json()).await?
+    else {
+        return Ok(ModuleResolveResult::unresolveable().cell());
+    };
+    let package_dir = package_json.parent();
+    let results = detectors
+        .await?
+        .iter()
+        .map(|detector| detector.detect(package_dir, compile_target))
+        .collect();
+    Ok(ModuleResolveResult::alternatives(results)
+        .with_affecting_sources(vec![Vc::upcast(FileSource::new(package_json))]))
+}
+
+/// Whether any of the `detectors` finds native addons in the package
+/// containing `context_dir`.
+#[turbo_tasks::function]
+pub async fn has_native_bindings(
+    context_dir: Vc<FileSystemPath>,
+    compile_target: Vc<CompileTarget>,
+    detectors: Vc<NativeBindingDetectors>,
+) -> Result<Vc<bool>> {
+    let result = resolve_native_bindings(context_dir, compile_target, detectors);
+    Ok(Vc::cell(!result.primary_modules().await?.is_empty()))
+}
+
+/// Returns the platform triple napi-rs uses to name the platform specific
+/// binaries and packages, e.g. `linux-x64-gnu` or `darwin-arm64`.
+fn napi_rs_triple(compile_target: &CompileTarget) -> String {
+    let platform = compile_target.platform;
+    let arch = compile_target.arch;
+    let abi = match (platform, arch, compile_target.libc) {
+        (Platform::Linux, Arch::Arm, Libc::Musl) => Some("musleabihf"),
+        (Platform::Linux, Arch::Arm, _) => Some("gnueabihf"),
+        (Platform::Linux, _, Libc::Musl) => Some("musl"),
+        (Platform::Linux, _, _) => Some("gnu"),
+        (Platform::Win32, _, _) => Some("msvc"),
+        (Platform::Android, Arch::Arm, _) => Some("eabi"),
+        _ => None,
+    };
+    match abi {
+        Some(abi) => format!("{}-{}-{}", platform.as_str(), arch.as_str(), abi),
+        None => format!("{}-{}", platform.as_str(), arch.as_str()),
+    }
+}
+
+/// Detects the binaries of packages built with [napi-rs](https://napi.rs).
+///
+/// They are either shipped next to the loader as `<binaryName>.<triple>.node`
+/// or as an `optionalDependencies` entry named `<package>-<triple>`, which is
+/// only installed on the matching platform.
+#[turbo_tasks::value]
+pub struct NapiRsDetector;
+
+#[turbo_tasks::value_impl]
+impl NapiRsDetector {
+    #[turbo_tasks::function]
+    pub fn new() -> Vc<Self> {
+        NapiRsDetector.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl NativeBindingDetector for NapiRsDetector {
+    #[turbo_tasks::function]
+    async fn detect(
+        &self,
+        package_dir: Vc<FileSystemPath>,
+        compile_target: Vc<CompileTarget>,
+    ) -> Result<Vc<ModuleResolveResult>> {
+        let FileJsonContent::Content(package_json) =
+            &*package_dir.join("package.json".into()).read_json().await?
+        else {
+            return Ok(ModuleResolveResult::unresolveable().cell());
+        };
+        let napi = &package_json["napi"];
+        if !napi.is_object() {
+            return Ok(ModuleResolveResult::unresolveable().cell());
+        }
+        let compile_target = compile_target.await?;
+        let mut triples = vec![napi_rs_triple(&compile_target)];
+        if compile_target.platform == Platform::Darwin {
+            triples.push("darwin-universal".to_string());
+        }
+
+        let binary_name = napi["binaryName"]
+            .as_str()
+            .or_else(|| napi["name"].as_str())
+            .or_else(|| {
+                package_json["name"]
+                    .as_str()
+                    .map(|name| name.rsplit('/').next().unwrap_or(name))
+            });
+
+        let mut results = Vec::new();
+        if let Some(binary_name) = binary_name {
+            for triple in triples.iter() {
+                results.push(
+                    resolve_raw(
+                        package_dir,
+                        Pattern::new(Pattern::Constant(
+                            format!("{binary_name}.{triple}.node").into(),
+                        )),
+                        true,
+                    )
+                    .as_raw_module_result(),
+                );
+            }
+        }
+
+        if let Some(optional_dependencies) = package_json["optionalDependencies"].as_object() {
+            let resolve_options = node_cjs_resolve_options(package_dir.root());
+            for name in optional_dependencies.keys() {
+                if !triples
+                    .iter()
+                    .any(|triple| name.ends_with(&format!("-{triple}")))
+                {
+                    continue;
+                }
+                results.push(
+                    resolve(
+                        package_dir,
+                        Value::new(ReferenceType::Undefined),
+                        Request::parse_string(name.as_str().into()),
+                        resolve_options,
+                    )
+                    .as_raw_module_result(),
+                );
+            }
+        }
+
+        Ok(ModuleResolveResult::alternatives(results))
+    }
+}
+
+/// Detects the binaries of packages built with
+/// [prebuildify](https://github.com/prebuild/prebuildify), which are stored in
+/// `prebuilds/<platform>-<arch>/` and tagged with e.g. `napi`, `glibc` or
+/// `musl`.
+#[turbo_tasks::value]
+pub struct PrebuildifyDetector;
+
+#[turbo_tasks::value_impl]
+impl PrebuildifyDetector {
+    #[turbo_tasks::function]
+    pub fn new() -> Vc<Self> {
+        PrebuildifyDetector.cell()
+    }
+}
+
+#[turbo_tasks::value_impl]
+impl NativeBindingDetector for PrebuildifyDetector {
+    #[turbo_tasks::function]
+    async fn detect(
+        &self,
+        package_dir: Vc<FileSystemPath>,
+        compile_target: Vc<CompileTarget>,
+    ) -> Result<Vc<ModuleResolveResult>> {
+        let compile_target = compile_target.await?;
+        let prebuilds_dir: RcStr = format!(
+            "prebuilds/{}-{}",
+            compile_target.platform.as_str(),
+            compile_target.arch.as_str()
+        )
+        .into();
+        // Binaries for the other libc on linux are never loaded.
+        let excluded_tag = match (compile_target.platform, compile_target.libc) {
+            (Platform::Linux, Libc::Musl) => Some(".glibc."),
+            (Platform::Linux, _) => Some(".musl."),
+            _ => None,
+        };
+
+        let mut modules = Vec::new();
+        for (key, entry) in package_dir
+            .join(prebuilds_dir.clone())
+            .read_glob(Glob::new("*.node".into()), false)
+            .await?
+            .results
+            .iter()
+        {
+            if excluded_tag.is_some_and(|tag| key.contains(tag)) {
+                continue;
+            }
+            if let &DirectoryEntry::File(binary) | &DirectoryEntry::Symlink(binary) = entry {
+                modules.push((
+                    RequestKey::new(format!("{prebuilds_dir}/{key}").into()),
+                    Vc::upcast(RawModule::new(Vc::upcast(FileSource::new(binary)))),
+                ));
+            }
+        }
+        Ok(ModuleResolveResult::modules(modules).cell())
+    }
+}
@@ -11,8 +11,11 @@ use turbopack_core::resolve::{
 };
 
 use crate::{
+    browser_import_map::browser_import_map,
     resolve_options_context::ResolveOptionsContext,
-    typescript::{apply_tsconfig_resolve_options, tsconfig, tsconfig_resolve_options},
+    typescript::{
+        apply_tsconfig_resolve_options, referenced_tsconfig, tsconfig, tsconfig_resolve_options,
+    },
 };
 
 const NODE_EXTERNALS: [&str; 51] = [
@@ -272,15 +275,24 @@ pub async fn resolve_options(
     let resolve_options = if options_context_value.enable_typescript {
         let tsconfig = find_context_file(resolve_path, tsconfig()).await?;
         match *tsconfig {
-            FindContextFileResult::Found(path, _) => {
-                apply_tsconfig_resolve_options(resolve_options, tsconfig_resolve_options(path))
-            }
+            FindContextFileResult::Found(path, _) => apply_tsconfig_resolve_options(
+                resolve_options,
+                tsconfig_resolve_options(referenced_tsconfig(path, resolve_path)),
+            ),
             FindContextFileResult::NotFound(_) => resolve_options,
         }
     } else {
         resolve_options
     };
 
+    let resolve_options = options_context_value
+        .browser_import_map
+        .map(|import_map_path| {
+            resolve_options
+                .with_extended_import_map(browser_import_map(import_map_path, resolve_path))
+        })
+        .unwrap_or(resolve_options);
+
     // Make sure to always apply `options_context.import_map` last, so it properly
     // overwrites any other mappings.
     let resolve_options = options_context_value
@@ -55,6 +55,14 @@ pub struct ResolveOptionsContext {
     /// precedence over any other (e.g. tsconfig.json `compilerOptions.paths`).
     pub import_map: Option<Vc<ImportMap>>,
     #[serde(default)]
+    /// A [W3C import map](https://html.spec.whatwg.org/multipage/webappapis.html#import-maps)
+    /// JSON file, as used in `<script type="importmap">`.
+    ///
+    /// Its `imports` and `scopes` are translated into import map entries, see
+    /// [crate::browser_import_map::browser_import_map]. They are applied
+    /// before `import_map`.
+    pub browser_import_map: Option<Vc<FileSystemPath>>,
+    #[serde(default)]
     /// An import map to fall back to when a request could not be resolved.
     ///
     /// If set, this import map will be applied to
@@ -1,9 +1,9 @@
-use std::{collections::HashMap, fmt::Write, mem::take};
+use std::{borrow::Cow, collections::HashMap, fmt::Write, mem::take};
 
 use anyhow::Result;
 use serde_json::Value as JsonValue;
-use turbo_tasks::{RcStr, Value, ValueDefault, Vc};
-use turbo_tasks_fs::{FileContent, FileJsonContent, FileSystemPath};
+use turbo_tasks::{RcStr, TryJoinIterExt, Value, ValueDefault, Vc};
+use turbo_tasks_fs::{glob::Glob, FileContent, FileJsonContent, FileSystemPath};
 use turbopack_core::{
     asset::Asset,
     context::AssetContext,
@@ -15,8 +15,8 @@ use turbopack_core::{
         handle_resolve_error,
         node::node_cjs_resolve_options,
         options::{
-            ConditionValue, ImportMap, ImportMapping, ResolveIntoPackage, ResolveModules,
-            ResolveOptions,
+            ConditionValue, ImportMap, ImportMapResult, ImportMapping, ImportMappingReplacement,
+            ResolveIntoPackage, ResolveModules, ResolveOptions,
         },
         origin::{ResolveOrigin, ResolveOriginExt},
         parse::Request,
@@ -216,6 +216,12 @@ pub struct TsConfigResolveOptions {
     base_url: Option<Vc<FileSystemPath>>,
     import_map: Option<Vc<ImportMap>>,
     is_module_resolution_nodenext: bool,
+    /// `compilerOptions.rootDirs`, which are merged into a single virtual
+    /// directory for relative requests.
+    root_dirs: Vec<Vc<FileSystemPath>>,
+    /// `compilerOptions.moduleSuffixes`, which are tried in order in front of
+    /// every extension.
+    module_suffixes: Vec<RcStr>,
 }
 
 #[turbo_tasks::value_impl]
@@ -327,14 +333,272 @@ pub async fn tsconfig_resolve_options(
     .await?
     .unwrap_or_default();
 
+    let root_dirs = if let Some(root_dirs) = read_from_tsconfigs(&configs, |json, source| {
+        json["compilerOptions"]["rootDirs"]
+            .as_array()
+            .map(|root_dirs| {
+                let context_dir = source.ident().path().parent();
+                root_dirs
+                    .iter()
+                    .filter_map(|root_dir| root_dir.as_str())
+                    .map(|root_dir| context_dir.try_join(root_dir.into()))
+                    .collect::<Vec<_>>()
+            })
+    })
+    .await?
+    {
+        root_dirs
+            .into_iter()
+            .try_join()
+            .await?
+            .into_iter()
+            .filter_map(|root_dir| *root_dir)
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let module_suffixes = read_from_tsconfigs(&configs, |json, _| {
+        json["compilerOptions"]["moduleSuffixes"]
+            .as_array()
+            .map(|suffixes| {
+                suffixes
+                    .iter()
+                    .filter_map(|suffix| suffix.as_str())
+                    .map(RcStr::from)
+                    .collect::<Vec<_>>()
+            })
+    })
+    .await?
+    .unwrap_or_default();
+
     Ok(TsConfigResolveOptions {
         base_url,
         import_map,
         is_module_resolution_nodenext,
+        root_dirs,
+        module_suffixes,
     }
     .cell())
 }
 
+/// Returns the tsconfig that applies to `lookup_path` when `tsconfig` uses
+/// project references.
+///
+/// A solution-style tsconfig usually only lists `references`, while the
+/// `baseUrl` and `paths` live in the referenced projects, e.g.
+/// `tsconfig.app.json`. The referenced project containing `lookup_path` (in its
+/// directory and its `include`s) is used instead, preferring the most specific
+/// project directory. Otherwise `tsconfig` itself is returned.
+#[turbo_tasks::function]
+pub async fn referenced_tsconfig(
+    tsconfig: Vc<FileSystemPath>,
+    lookup_path: Vc<FileSystemPath>,
+) -> Result<Vc<FileSystemPath>> {
+    let resolve_options = node_cjs_resolve_options(tsconfig.root());
+    let configs = read_tsconfigs(
+        tsconfig.read(),
+        Vc::upcast(FileSource::new(tsconfig)),
+        resolve_options,
+    )
+    .await?;
+
+    // `references` are not inherited via `extends`.
+    let Some((content, source)) = configs.first() else {
+        return Ok(tsconfig);
+    };
+    let FileJsonContent::Content(json) = &*content.await? else {
+        return Ok(tsconfig);
+    };
+    let JsonValue::Array(references) = &json["references"] else {
+        return Ok(tsconfig);
+    };
+
+    let tsconfig = tsconfig.resolve().await?;
+    let lookup_path_value = lookup_path.await?;
+    let mut best_match: Option<(usize, Vc<FileSystemPath>)> = None;
+    for reference in references {
+        let Some(path) = reference["path"].as_str() else {
+            TsConfigIssue {
+                severity: IssueSeverity::Warning.cell(),
+                source_ident: source.ident(),
+                message: format!("references: {reference} doesn't contain a \"path\" string")
+                    .into(),
+            }
+            .cell()
+            .emit();
+            continue;
+        };
+        let Some(referenced) = *tsconfig.parent().try_join(path.into()).await? else {
+            continue;
+        };
+        // A reference may either point to a tsconfig file or to a directory
+        // containing a `tsconfig.json`.
+        let referenced = if path.ends_with(".json") {
+            referenced
+        } else {
+            referenced.join("tsconfig.json".into())
+        }
+        .resolve()
+        .await?;
+        if referenced == tsconfig {
+            continue;
+        }
+
+        let project_dir = referenced.parent();
+        let project_dir_value = project_dir.await?;
+        if !lookup_path_value.is_inside_or_equal_ref(&project_dir_value) {
+            continue;
+        }
+        if best_match.is_some_and(|(len, _)| len >= project_dir_value.path.len()) {
+            continue;
+        }
+
+        let referenced_configs = read_tsconfigs(
+            referenced.read(),
+            Vc::upcast(FileSource::new(referenced)),
+            resolve_options,
+        )
+        .await?;
+        if referenced_configs.is_empty() {
+            continue;
+        }
+        let includes = read_from_tsconfigs(&referenced_configs, |json, source| {
+            json["include"].as_array().map(|includes| {
+                let includes = includes
+                    .iter()
+                    .filter_map(|include| include.as_str())
+                    .map(|include| include.to_string())
+                    .collect::<Vec<_>>();
+                (source.ident().path().parent(), includes)
+            })
+        })
+        .await?;
+        let is_included = if let Some((context_dir, includes)) = includes {
+            let context_dir = context_dir.await?;
+            let dir = if *context_dir == *lookup_path_value {
+                Some("")
+            } else {
+                context_dir.get_path_to(&lookup_path_value)
+            };
+            match dir {
+                Some(dir) => {
+                    let mut is_included = false;
+                    for include in includes.iter() {
+                        if include_matches_dir(include, dir)? {
+                            is_included = true;
+                            break;
+                        }
+                    }
+                    is_included
+                }
+                None => false,
+            }
+        } else {
+            true
+        };
+        if is_included {
+            best_match = Some((project_dir_value.path.len(), referenced));
+        }
+    }
+
+    Ok(best_match.map_or(tsconfig, |(_, referenced)| referenced))
+}
+
+/// Returns whether the tsconfig `include` pattern might include files in the
+/// directory `dir`. Both are relative to the directory of the tsconfig.
+///
+/// Patterns without a wildcard or extension in their last segment, e.g.
+/// `src`, include the whole directory.
+fn include_matches_dir(include: &str, dir: &str) -> Result<bool> {
+    let include = include.strip_prefix("./").unwrap_or(include);
+    let include = include.trim_end_matches('/');
+    // Character classes are not supported by `Glob`.
+    if include.contains('[') {
+        return Ok(true);
+    }
+    let last_segment = include.rsplit('/').next().unwrap_or(include);
+    let include = if last_segment.contains('*') || last_segment.contains('.') {
+        Cow::Borrowed(include)
+    } else {
+        Cow::Owned(format!("{include}/**"))
+    };
+    if dir.is_empty() {
+        // Files in the tsconfig's directory are only matched by patterns
+        // without a directory, e.g. `vite.config.ts` or `**/*.ts`.
+        let first_segment = include.split('/').next().unwrap_or_default();
+        return Ok(!include.contains('/') || first_segment.starts_with("**"));
+    }
+    Ok(Glob::parse(&include)?.execute(&format!("{dir}/")))
+}
+
+/// Treats all `compilerOptions.rootDirs` as a single virtual directory: a
+/// relative request that can't be resolved in one of the root dirs is looked
+/// up at the same relative location in the other root dirs.
+#[turbo_tasks::value(shared)]
+struct RootDirsReplacement {
+    root_dirs: Vec<Vc<FileSystemPath>>,
+    /// The resolve options to use in the other root dirs. They don't contain
+    /// this replacement, which avoids resolving in cycles.
+    resolve_options: Vc<ResolveOptions>,
+}
+
+#[turbo_tasks::value_impl]
+impl ImportMappingReplacement for RootDirsReplacement {
+    #[turbo_tasks::function]
+    fn replace(self: Vc<Self>, _capture: RcStr) -> Vc<ImportMapping> {
+        ImportMapping::Dynamic(Vc::upcast(self)).cell()
+    }
+
+    #[turbo_tasks::function]
+    async fn result(
+        &self,
+        lookup_path: Vc<FileSystemPath>,
+        request: Vc<Request>,
+    ) -> Result<Vc<ImportMapResult>> {
+        if !matches!(&*request.await?, Request::Relative { .. }) {
+            return Ok(ImportMapResult::NoEntry.cell());
+        }
+
+        let lookup_path_value = lookup_path.await?;
+        let root_dirs = self.root_dirs.iter().copied().try_join().await?;
+        // Find the most specific root dir containing the lookup path.
+        let Some((index, relative_path)) = root_dirs
+            .iter()
+            .enumerate()
+            .filter(|(_, root_dir)| lookup_path_value.is_inside_or_equal_ref(root_dir))
+            .max_by_key(|(_, root_dir)| root_dir.path.len())
+            .map(|(index, root_dir)| {
+                let relative_path = root_dir.get_path_to(&lookup_path_value).unwrap_or("");
+                (index, RcStr::from(relative_path))
+            })
+        else {
+            return Ok(ImportMapResult::NoEntry.cell());
+        };
+
+        let results = self
+            .root_dirs
+            .iter()
+            .enumerate()
+            .filter(|(other_index, _)| *other_index != index)
+            .map(|(_, root_dir)| {
+                let lookup_path = if relative_path.is_empty() {
+                    *root_dir
+                } else {
+                    root_dir.join(relative_path.clone())
+                };
+                ImportMapResult::Result(resolve(
+                    lookup_path,
+                    Value::new(ReferenceType::Undefined),
+                    request,
+                    self.resolve_options,
+                ))
+            })
+            .collect();
+        Ok(ImportMapResult::Alternatives(results).cell())
+    }
+}
+
 #[turbo_tasks::function]
 pub fn tsconfig() -> Vc<Vec<RcStr>> {
     Vc::cell(vec!["tsconfig.json".into(), "jsconfig.json".into()])
@@ -364,10 +628,63 @@ pub async fn apply_tsconfig_resolve_options(
     }
     resolve_options.enable_typescript_with_output_extension =
         tsconfig_resolve_options.is_module_resolution_nodenext;
+    if !tsconfig_resolve_options.module_suffixes.is_empty() {
+        resolve_options.extensions = with_module_suffixes(
+            &resolve_options.extensions,
+            &tsconfig_resolve_options.module_suffixes,
+        );
+    }
+    if tsconfig_resolve_options.root_dirs.len() > 1 {
+        let replacement: Vc<Box<dyn ImportMappingReplacement>> = Vc::upcast(
+            RootDirsReplacement {
+                root_dirs: tsconfig_resolve_options.root_dirs.clone(),
+                resolve_options: resolve_options.clone().cell(),
+            }
+            .cell(),
+        );
+        let mut root_dirs_import_map = ImportMap::empty();
+        root_dirs_import_map
+            .insert_wildcard_alias("./", ImportMapping::Dynamic(replacement).cell());
+        root_dirs_import_map
+            .insert_wildcard_alias("../", ImportMapping::Dynamic(replacement).cell());
+        let root_dirs_import_map = root_dirs_import_map.cell();
+        // Files in the actual directory take precedence, so the other root dirs
+        // are only used as a fallback.
+        resolve_options.fallback_import_map = Some(
+            resolve_options
+                .fallback_import_map
+                .map(|import_map| import_map.extend(root_dirs_import_map))
+                .unwrap_or(root_dirs_import_map),
+        );
+    }
 
     Ok(resolve_options.cell())
 }
 
+/// Applies `compilerOptions.moduleSuffixes` to the TypeScript and JavaScript
+/// `extensions`. TypeScript tries all suffixes for an extension before moving
+/// on to the next extension. An empty suffix needs to be listed to try the
+/// plain extension. Other extensions, like `.json` or `.css`, are kept as they
+/// are, since TypeScript doesn't apply the suffixes to them either.
+fn with_module_suffixes(extensions: &[RcStr], module_suffixes: &[RcStr]) -> Vec<RcStr> {
+    extensions
+        .iter()
+        .flat_map(|extension| {
+            let is_script = [".ts", ".tsx", ".mts", ".cts", ".js", ".jsx", ".mjs", ".cjs"]
+                .iter()
+                .any(|script_extension| extension.ends_with(script_extension));
+            if is_script {
+                module_suffixes
+                    .iter()
+                    .map(|suffix| format!("{suffix}{extension}").into())
+                    .collect::<Vec<_>>()
+            } else {
+                vec![extension.clone()]
+            }
+        })
+        .collect()
+}
+
 #[turbo_tasks::function]
 pub async fn type_resolve(
     origin: Vc<Box<dyn ResolveOrigin>>,
@@ -528,3 +845,54 @@ impl Issue for TsConfigIssue {
         IssueStage::Analysis.cell()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use turbo_tasks::RcStr;
+
+    use super::{include_matches_dir, with_module_suffixes};
+
+    #[test]
+    fn include_patterns() {
+        let matches = |include: &str, dir: &str| include_matches_dir(include, dir).unwrap();
+
+        assert!(matches("src", "src"));
+        assert!(matches("./src/", "src/components"));
+        assert!(matches("src/**/*.ts", "src/components"));
+        assert!(matches("packages/*/src", "packages/app/src/utils"));
+        assert!(!matches("src/**/*.ts", "scripts"));
+        assert!(!matches("packages/*/src", "packages/app/test"));
+        assert!(!matches("src", "srcs"));
+    }
+
+    #[test]
+    fn include_patterns_in_the_tsconfig_directory() {
+        let matches = |include: &str| include_matches_dir(include, "").unwrap();
+
+        assert!(matches("vite.config.ts"));
+        assert!(matches("*.ts"));
+        assert!(matches("**/*.ts"));
+        assert!(!matches("src"));
+        assert!(!matches("src/**/*.ts"));
+    }
+
+    #[test]
+    fn module_suffixes() {
+        let extensions = [".ts", ".d.ts", ".js", ".json", ".css"].map(RcStr::from);
+        let suffixes = [".ios", ""].map(RcStr::from);
+        assert_eq!(
+            with_module_suffixes(&extensions, &suffixes),
+            [
+                ".ios.ts",
+                ".ts",
+                ".ios.d.ts",
+                ".d.ts",
+                ".ios.js",
+                ".js",
+                ".json",
+                ".css"
+            ]
+            .map(RcStr::from)
+        );
+    }
+}
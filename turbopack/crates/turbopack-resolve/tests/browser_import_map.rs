@@ -0,0 +1,156 @@
+#![feature(arbitrary_self_types)]
+
+use anyhow::Result;
+use turbo_tasks::{RcStr, ValueToString, Vc};
+use turbo_tasks_fs::{DiskFileSystem, FileContent, FileSystem, FileSystemPath};
+use turbo_tasks_testing::{register, run, Registration};
+use turbopack_core::{
+    asset::Asset,
+    output::OutputAsset,
+    resolve::{options::ImportMapResult, parse::Request, ResolveResultItem},
+};
+use turbopack_resolve::browser_import_map::{browser_import_map, browser_import_map_for_externals};
+
+static REGISTRATION: Registration = register!(turbopack_resolve::register);
+
+fn fixture_root() -> Vc<FileSystemPath> {
+    let root: RcStr = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/browser_import_map").into();
+    DiskFileSystem::new("fixture".into(), root, vec![]).root()
+}
+
+/// Looks up `request` in the import map for requests from `lookup_dir`.
+async fn lookup(lookup_dir: &str, request: &str) -> Result<String> {
+    let root = fixture_root();
+    let lookup_path = root.join(lookup_dir.into());
+    let import_map = browser_import_map(root.join("importmap.json".into()), lookup_path);
+    let result = import_map
+        .await?
+        .lookup(lookup_path, Request::parse_string(request.into()))
+        .await?;
+    Ok(match result {
+        ImportMapResult::Result(result) => {
+            let mut items = Vec::new();
+            for item in result.await?.primary.values() {
+                items.push(match item {
+                    ResolveResultItem::External(name, ty) => format!("external {name} ({ty})"),
+                    item => format!("{item:?}"),
+                });
+            }
+            items.join(", ")
+        }
+        result => result.cell().to_string().await?.to_string(),
+    })
+}
+
+#[tokio::test]
+async fn maps_urls_to_externals() {
+    run(&REGISTRATION, || async {
+        assert_eq!(
+            lookup("src", "react").await?,
+            "external https://cdn.example.com/react.js (url)"
+        );
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn maps_paths_relative_to_the_import_map() {
+    run(&REGISTRATION, || async {
+        let utils = lookup("src", "utils").await?;
+        assert!(utils.contains("./src/utils.js"), "{utils}");
+
+        let nested = lookup("src", "lib/nested").await?;
+        assert!(nested.contains("./vendor/lib/nested"), "{nested}");
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn applies_matching_scopes() {
+    run(&REGISTRATION, || async {
+        let react = lookup("vendor/nested", "react").await?;
+        assert!(react.contains("./vendor/react.js"), "{react}");
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn ignores_url_and_outside_scopes() {
+    run(&REGISTRATION, || async {
+        assert_eq!(lookup("src", "react-dom").await?, "No import map entry");
+        let utils = lookup("src", "utils").await?;
+        assert!(!utils.contains("external"), "{utils}");
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn ignores_scopes_without_trailing_slash() {
+    run(&REGISTRATION, || async {
+        assert_eq!(
+            lookup("src", "react").await?,
+            "external https://cdn.example.com/react.js (url)"
+        );
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn ignores_bare_addresses() {
+    run(&REGISTRATION, || async {
+        assert_eq!(lookup("src", "lodash").await?, "No import map entry");
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn emits_an_import_map_for_externals() {
+    run(&REGISTRATION, || async {
+        let root = fixture_root();
+        let asset = browser_import_map_for_externals(
+            root.join("importmap.json".into()),
+            root.join("dist/importmap.json".into()),
+        );
+        assert_eq!(
+            &*asset.ident().path().to_string().await?,
+            "[fixture]/dist/importmap.json"
+        );
+
+        let content = asset.content().file_content().await?;
+        let FileContent::Content(file) = &*content else {
+            panic!("the import map has no content");
+        };
+        let json: serde_json::Value = serde_json::from_str(&file.content().to_str()?)?;
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "imports": {
+                    "react": "https://cdn.example.com/react.js"
+                },
+                "scopes": {
+                    "https://cdn.example.com/": {
+                        "react-dom": "https://cdn.example.com/react-dom.js"
+                    },
+                    "../outside/": {
+                        "utils": "https://cdn.example.com/utils.js"
+                    }
+                }
+            })
+        );
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
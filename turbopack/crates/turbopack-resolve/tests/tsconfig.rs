@@ -0,0 +1,130 @@
+#![feature(arbitrary_self_types)]
+
+use anyhow::Result;
+use turbo_tasks::{RcStr, Value, ValueToString, Vc};
+use turbo_tasks_fs::{DiskFileSystem, FileSystem, FileSystemPath};
+use turbo_tasks_testing::{register, run, Registration};
+use turbopack_core::{
+    reference_type::ReferenceType,
+    resolve::{parse::Request, resolve},
+    source::Source,
+};
+use turbopack_resolve::{
+    resolve::resolve_options, resolve_options_context::ResolveOptionsContext,
+    typescript::referenced_tsconfig,
+};
+
+static REGISTRATION: Registration = register!(turbopack_resolve::register);
+
+fn fixture_root() -> Vc<FileSystemPath> {
+    let root: RcStr = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/tsconfig").into();
+    DiskFileSystem::new("fixture".into(), root, vec![]).root()
+}
+
+/// Returns the tsconfig which applies to `lookup_dir` in the `references`
+/// fixture.
+async fn referenced(lookup_dir: &str) -> Result<String> {
+    let root = fixture_root().join("references".into());
+    let tsconfig = referenced_tsconfig(
+        root.join("tsconfig.json".into()),
+        root.join(lookup_dir.into()),
+    );
+    Ok(tsconfig.to_string().await?.to_string())
+}
+
+/// Resolves `request` from `lookup_dir` with TypeScript enabled.
+async fn resolve_ts(lookup_dir: &str, request: &str) -> Result<Vec<String>> {
+    let lookup_path = fixture_root().join(lookup_dir.into());
+    let options = resolve_options(
+        lookup_path,
+        ResolveOptionsContext {
+            enable_typescript: true,
+            ..Default::default()
+        }
+        .cell(),
+    );
+    let result = resolve(
+        lookup_path,
+        Value::new(ReferenceType::Undefined),
+        Request::parse_string(request.into()),
+        options,
+    );
+    let mut paths = Vec::new();
+    for source in result.primary_sources().await?.iter() {
+        paths.push(source.ident().path().to_string().await?.to_string());
+    }
+    Ok(paths)
+}
+
+#[tokio::test]
+async fn uses_the_referenced_project_including_the_lookup_path() {
+    run(&REGISTRATION, || async {
+        assert_eq!(
+            referenced("src/app").await?,
+            "[fixture]/references/tsconfig.app.json"
+        );
+        assert_eq!(
+            referenced("").await?,
+            "[fixture]/references/tsconfig.node.json"
+        );
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn falls_back_to_the_solution_tsconfig() {
+    run(&REGISTRATION, || async {
+        assert_eq!(
+            referenced("scripts").await?,
+            "[fixture]/references/tsconfig.json"
+        );
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn resolves_paths_of_referenced_projects() {
+    run(&REGISTRATION, || async {
+        assert_eq!(
+            resolve_ts("references/src/app", "@/utils").await?,
+            ["[fixture]/references/src/utils.ts"]
+        );
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn resolves_relative_requests_in_other_root_dirs() {
+    run(&REGISTRATION, || async {
+        assert_eq!(
+            resolve_ts("root-dirs/src", "./messages").await?,
+            ["[fixture]/root-dirs/generated/messages.ts"]
+        );
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn prefers_module_suffixes_in_order() {
+    run(&REGISTRATION, || async {
+        assert_eq!(
+            resolve_ts("module-suffixes/src", "./button").await?,
+            ["[fixture]/module-suffixes/src/button.ios.ts"]
+        );
+        assert_eq!(
+            resolve_ts("module-suffixes/src", "./icon").await?,
+            ["[fixture]/module-suffixes/src/icon.ts"]
+        );
+        anyhow::Ok(())
+    })
+    .await
+    .unwrap()
+}
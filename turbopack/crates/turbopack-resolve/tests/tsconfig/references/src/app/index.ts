@@ -0,0 +1 @@
+export { utils } from "@/utils";
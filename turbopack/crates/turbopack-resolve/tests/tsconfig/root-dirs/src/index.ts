@@ -0,0 +1 @@
+export { messages } from "./messages";
@@ -3,7 +3,7 @@
 //! Static assets are copied directly to the output folder.
 //!
 //! When imported from ES modules, they produce a thin module that simply
-//! exports the asset's path.
+//! exports the asset's path, or a data URL of assets below the inline limit.
 //!
 //! When referred to from CSS assets, the reference is replaced with the asset's
 //! path.
@@ -15,7 +15,9 @@ pub mod fixed;
 pub mod output_asset;
 
 use anyhow::Result;
+use base64::{display::Base64Display, engine::general_purpose::STANDARD};
 use turbo_tasks::{RcStr, ValueToString, Vc};
+use turbo_tasks_fs::FileContent;
 use turbopack_core::{
     asset::{Asset, AssetContent},
     chunk::{ChunkItem, ChunkType, ChunkableModule, ChunkingContext},
@@ -42,11 +44,17 @@ fn modifier() -> Vc<RcStr> {
     Vc::cell("static".into())
 }
 
+#[turbo_tasks::value(transparent)]
+pub struct OptionDataUrl(Option<RcStr>);
+
 #[turbo_tasks::value]
 #[derive(Clone)]
 pub struct StaticModuleAsset {
     pub source: Vc<Box<dyn Source>>,
     pub asset_context: Vc<Box<dyn AssetContext>>,
+    /// Assets smaller than this many bytes are exported as data URLs instead
+    /// of being emitted.
+    pub inline_limit: Option<usize>,
 }
 
 #[turbo_tasks::value_impl]
@@ -56,9 +64,47 @@ impl StaticModuleAsset {
         Self::cell(StaticModuleAsset {
             source,
             asset_context,
+            inline_limit: None,
         })
     }
 
+    #[turbo_tasks::function]
+    pub fn with_inline_limit(&self, inline_limit: usize) -> Vc<Self> {
+        Self::cell(StaticModuleAsset {
+            inline_limit: Some(inline_limit),
+            ..self.clone()
+        })
+    }
+
+    /// The data URL of the asset when it is inlined.
+    #[turbo_tasks::function]
+    pub async fn data_url(&self) -> Result<Vc<OptionDataUrl>> {
+        let Some(inline_limit) = self.inline_limit else {
+            return Ok(Vc::cell(None));
+        };
+        let content = self.source.content().file_content().await?;
+        let FileContent::Content(file) = &*content else {
+            return Ok(Vc::cell(None));
+        };
+        let bytes = file.content().to_bytes()?;
+        if bytes.len() >= inline_limit {
+            return Ok(Vc::cell(None));
+        }
+        let mime = match file.content_type() {
+            Some(content_type) => content_type.clone(),
+            None => mime_guess::from_path(&*self.source.ident().path().await?.path)
+                .first_or_octet_stream(),
+        };
+        Ok(Vc::cell(Some(
+            format!(
+                "data:{};base64,{}",
+                mime.essence_str(),
+                Base64Display::new(&bytes, &STANDARD)
+            )
+            .into(),
+        )))
+    }
+
     #[turbo_tasks::function]
     async fn static_asset(
         self: Vc<Self>,
@@ -72,10 +118,17 @@ impl StaticModuleAsset {
 impl Module for StaticModuleAsset {
     #[turbo_tasks::function]
     fn ident(&self) -> Vc<AssetIdent> {
-        self.source
+        let ident = self
+            .source
             .ident()
             .with_modifier(modifier())
-            .with_layer(self.asset_context.layer())
+            .with_layer(self.asset_context.layer());
+        match self.inline_limit {
+            Some(inline_limit) => {
+                ident.with_modifier(Vc::cell(format!("inline limit {inline_limit}").into()))
+            }
+            None => ident,
+        }
     }
 }
 
@@ -126,6 +179,9 @@ impl ChunkItem for ModuleChunkItem {
 
     #[turbo_tasks::function]
     async fn references(&self) -> Result<Vc<ModuleReferences>> {
+        if self.module.data_url().await?.is_some() {
+            return Ok(ModuleReferences::empty());
+        }
         Ok(Vc::cell(vec![Vc::upcast(SingleOutputAssetReference::new(
             Vc::upcast(self.static_asset),
             Vc::cell(
@@ -165,17 +221,16 @@ impl EcmascriptChunkItem for ModuleChunkItem {
 
     #[turbo_tasks::function]
     async fn content(&self) -> Result<Vc<EcmascriptChunkItemContent>> {
+        let url = match &*self.module.data_url().await? {
+            Some(data_url) => data_url.clone(),
+            None => self
+                .chunking_context
+                .asset_url(self.static_asset.ident())
+                .await?
+                .clone_value(),
+        };
         Ok(EcmascriptChunkItemContent {
-            inner_code: format!(
-                "__turbopack_export_value__({path});",
-                path = StringifyJs(
-                    &self
-                        .chunking_context
-                        .asset_url(self.static_asset.ident())
-                        .await?
-                )
-            )
-            .into(),
+            inner_code: format!("__turbopack_export_value__({});", StringifyJs(&url)).into(),
             ..Default::default()
         }
         .into())
@@ -22,7 +22,9 @@ use turbo_tasks_fs::{
 use turbo_tasks_memory::MemoryBackend;
 use turbopack::{
     ecmascript::TreeShakingMode,
-    module_options::{EcmascriptOptionsContext, ModuleOptionsContext},
+    module_options::{
+        CssOptionsContext, EcmascriptOptionsContext, JsxTransformOptions, ModuleOptionsContext,
+    },
     ModuleAssetContext,
 };
 use turbopack_core::{
@@ -45,6 +47,7 @@ use turbopack_node::{debug::should_debug, evaluate::evaluate};
 use turbopack_nodejs::NodeJsChunkingContext;
 use turbopack_resolve::resolve_options_context::ResolveOptionsContext;
 use turbopack_test_utils::jest::JestRunResult;
+use turbopack_wasm::wasi::WasiOptions;
 
 use crate::util::REPO_ROOT;
 
@@ -89,7 +92,7 @@ fn register() {
 // skip.
 #[testing::fixture("tests/execution/*/*/*", exclude("node_modules|__skipped__"))]
 fn test(resource: PathBuf) {
-    let messages = get_messages(run(resource, IssueSnapshotMode::Snapshots).unwrap());
+    let messages = get_messages(run(resource, IssueSnapshotMode::Snapshots, true).unwrap());
     if !messages.is_empty() {
         panic!(
             "Failed with error(s) in the following test(s):\n\n{}",
@@ -98,6 +101,22 @@ fn test(resource: PathBuf) {
     }
 }
 
+// Runs the tree shaking tests again with tree shaking disabled, so that the
+// tree shaken output (checked by `test`) is compared against the unshaken
+// behavior of the same code.
+#[testing::fixture("tests/execution/*/tree-shaking/*/input")]
+fn test_tree_shaking_differential(resource: PathBuf) {
+    let resource = resource.parent().unwrap().to_path_buf();
+
+    let messages = get_messages(run(resource, IssueSnapshotMode::NoSnapshots, false).unwrap());
+    if !messages.is_empty() {
+        panic!(
+            "Failed with error(s) in the following test(s) without tree shaking:\n\n{}",
+            messages.join("\n\n--\n")
+        )
+    }
+}
+
 #[testing::fixture("tests/execution/*/*/__skipped__/*/input")]
 #[should_panic]
 fn test_skipped_fails(resource: PathBuf) {
@@ -108,7 +127,7 @@ fn test_skipped_fails(resource: PathBuf) {
         uncaught_exceptions: _,
         unhandled_rejections: _,
         jest_result,
-    } = run(resource, IssueSnapshotMode::NoSnapshots).unwrap();
+    } = run(resource, IssueSnapshotMode::NoSnapshots, true).unwrap();
 
     // Assert that this skipped test itself has at least one browser test which
     // fails.
@@ -155,11 +174,15 @@ fn get_messages(js_results: JsResult) -> Vec<String> {
 }
 
 #[tokio::main(flavor = "current_thread")]
-async fn run(resource: PathBuf, snapshot_mode: IssueSnapshotMode) -> Result<JsResult> {
+async fn run(
+    resource: PathBuf,
+    snapshot_mode: IssueSnapshotMode,
+    tree_shaking: bool,
+) -> Result<JsResult> {
     register();
 
     // Clean up old output files.
-    let output_path = resource.join("output");
+    let output_path = resource.join(output_dir(tree_shaking));
     if output_path.exists() {
         std::fs::remove_dir_all(&output_path)?;
     }
@@ -167,7 +190,7 @@ async fn run(resource: PathBuf, snapshot_mode: IssueSnapshotMode) -> Result<JsRe
     let tt = TurboTasks::new(MemoryBackend::default());
     tt.run_once(async move {
         let resource_str = resource.to_str().unwrap();
-        let prepared_test = prepare_test(resource_str.into());
+        let prepared_test = prepare_test(resource_str.into(), tree_shaking);
         let run_result = run_test(prepared_test);
         if matches!(snapshot_mode, IssueSnapshotMode::Snapshots) {
             snapshot_issues(prepared_test, run_result).await?;
@@ -182,6 +205,24 @@ async fn run(resource: PathBuf, snapshot_mode: IssueSnapshotMode) -> Result<JsRe
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 struct TestOptions {
     tree_shaking_mode: Option<TreeShakingMode>,
+    #[serde(default)]
+    enable_jsx: bool,
+    #[serde(default)]
+    enable_sass: bool,
+    #[serde(default)]
+    enable_wasi: bool,
+    #[serde(default)]
+    enable_svg_components: bool,
+}
+
+/// The directory name for the output of a test run. Runs with tree shaking
+/// disabled use a separate directory so they don't overwrite each other.
+fn output_dir(tree_shaking: bool) -> &'static str {
+    if tree_shaking {
+        "output"
+    } else {
+        "output-unshaken"
+    }
 }
 
 #[turbo_tasks::value]
@@ -191,10 +232,11 @@ struct PreparedTest {
     tests_path: Vc<FileSystemPath>,
     project_root: Vc<FileSystemPath>,
     options: TestOptions,
+    tree_shaking: bool,
 }
 
 #[turbo_tasks::function]
-async fn prepare_test(resource: RcStr) -> Result<Vc<PreparedTest>> {
+async fn prepare_test(resource: RcStr, tree_shaking: bool) -> Result<Vc<PreparedTest>> {
     let resource_path = canonicalize(&resource)?;
     assert!(resource_path.exists(), "{} does not exist", resource);
     assert!(
@@ -235,6 +277,7 @@ async fn prepare_test(resource: RcStr) -> Result<Vc<PreparedTest>> {
         tests_path,
         project_root,
         options,
+        tree_shaking,
     }
     .cell())
 }
@@ -247,12 +290,18 @@ async fn run_test(prepared_test: Vc<PreparedTest>) -> Result<Vc<RunTestResult>>
         tests_path,
         project_root,
         ref options,
+        tree_shaking,
     } = *prepared_test.await?;
+    let tree_shaking_mode = if tree_shaking {
+        options.tree_shaking_mode
+    } else {
+        None
+    };
 
     let jest_entry_path = tests_path.join("js/jest-entry.ts".into());
     let test_path = project_path.join("input/index.js".into());
 
-    let chunk_root_path = path.join("output".into());
+    let chunk_root_path = path.join(output_dir(tree_shaking).into());
     let static_root_path = path.join("static".into());
 
     let env = Environment::new(Value::new(ExecutionEnvironment::NodeJsBuildTime(
@@ -281,16 +330,28 @@ async fn run_test(prepared_test: Vc<PreparedTest>) -> Result<Vc<RunTestResult>>
         compile_time_info,
         ModuleOptionsContext {
             ecmascript: EcmascriptOptionsContext {
+                enable_jsx: options.enable_jsx.then(|| {
+                    JsxTransformOptions::cell(JsxTransformOptions {
+                        development: true,
+                        ..Default::default()
+                    })
+                }),
                 enable_typescript_transform: Some(Default::default()),
                 import_externals: true,
                 ..Default::default()
             },
             preset_env_versions: Some(env),
-            tree_shaking_mode: options.tree_shaking_mode,
+            css: CssOptionsContext {
+                enable_sass: options.enable_sass,
+                ..Default::default()
+            },
+            tree_shaking_mode,
+            enable_wasi: options.enable_wasi.then(|| WasiOptions::default().cell()),
+            enable_svg_components: options.enable_svg_components,
             rules: vec![(
                 ContextCondition::InDirectory("node_modules".into()),
                 ModuleOptionsContext {
-                    tree_shaking_mode: options.tree_shaking_mode,
+                    tree_shaking_mode,
                     ..Default::default()
                 }
                 .cell(),
@@ -0,0 +1,26 @@
+import text from "./hello.txt" with { type: "text" };
+import bytes from "./data.bin" with { type: "bytes" };
+import raw from "./hello.txt?raw";
+import url from "./hello.txt?url";
+import inline from "./hello.txt?inline";
+
+it("imports text with the text type attribute", () => {
+  expect(text).toBe("Hello world\n");
+});
+
+it("imports bytes with the bytes type attribute", () => {
+  expect(bytes).toBeInstanceOf(Uint8Array);
+  expect(Array.from(bytes)).toEqual([0, 1, 2, 255]);
+});
+
+it("imports text with the raw query", () => {
+  expect(raw).toBe("Hello world\n");
+});
+
+it("imports the url with the url query", () => {
+  expect(url).toMatch(/\.txt$/);
+});
+
+it("inlines assets with the inline query", () => {
+  expect(inline).toBe("data:text/plain;base64,SGVsbG8gd29ybGQK");
+});
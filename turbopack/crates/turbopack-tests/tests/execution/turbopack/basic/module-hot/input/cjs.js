@@ -0,0 +1,15 @@
+let accepted = false;
+
+if (module.hot) {
+  module.hot.accept();
+  accepted = true;
+}
+
+module.hot.dispose((data) => {
+  data.value = require("./unused.js");
+});
+
+module.exports = {
+  hot: module.hot,
+  accepted,
+};
@@ -0,0 +1,27 @@
+import cjs from "./cjs.js";
+
+it("should compile import.meta.hot to undefined", () => {
+  expect(import.meta.hot).toBeUndefined();
+  expect(typeof import.meta.hot).toBe("undefined");
+});
+
+it("should skip code guarded by import.meta.hot", () => {
+  let accepted = false;
+  if (import.meta.hot) {
+    import.meta.hot.accept();
+    accepted = true;
+  }
+  expect(accepted).toBe(false);
+});
+
+it("should compile calls on import.meta.hot to no-ops", () => {
+  expect(() => import.meta.hot.accept()).not.toThrow();
+  expect(() => import.meta.hot.dispose(() => {})).not.toThrow();
+  expect(() => import.meta.hot.decline()).not.toThrow();
+  expect(() => import.meta.hot.invalidate()).not.toThrow();
+});
+
+it("should compile module.hot to undefined in CommonJS modules", () => {
+  expect(cjs.hot).toBeUndefined();
+  expect(cjs.accepted).toBe(false);
+});
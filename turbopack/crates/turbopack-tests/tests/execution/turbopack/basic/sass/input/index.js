@@ -0,0 +1,14 @@
+import scss from "./styles.module.scss";
+import sass from "./indented.module.sass";
+
+it("should compile SCSS modules with @use", () => {
+  expect(scss).toHaveProperty("button");
+  // Declared in the used partial
+  expect(scss).toHaveProperty("themed");
+});
+
+it("should compile Sass modules with unquoted @import", () => {
+  expect(sass).toHaveProperty("card");
+  // Declared in the imported partial
+  expect(sass).toHaveProperty("from-partial");
+});
@@ -0,0 +1,23 @@
+import * as React from "react";
+import Icon from "./icon.svg?react";
+
+it("should import an SVG as a React component", () => {
+  expect(typeof Icon).toBe("function");
+
+  const svg = Icon({});
+  expect(svg.type).toBe("svg");
+  expect(svg.props.viewBox).toBe("0 0 16 16");
+  expect(svg.props.className).toBe("icon");
+
+  const path = React.Children.only(svg.props.children);
+  expect(path.type).toBe("path");
+  expect(path.props.d).toBe("M0 8h16");
+  expect(path.props.strokeWidth).toBe("2");
+});
+
+it("should pass props to the svg element", () => {
+  const svg = Icon({ width: 32, "aria-label": "arrow" });
+  expect(svg.props.width).toBe(32);
+  expect(svg.props.height).toBe("16");
+  expect(svg.props["aria-label"]).toBe("arrow");
+});
@@ -0,0 +1,33 @@
+it("should require modules synchronously by default", () => {
+  const ctx = require.context("./dir", false, /\.js$/, "sync");
+  expect(ctx.keys()).toEqual(["./a.js", "./b.js"]);
+  expect(ctx("./a.js").default).toBe("a");
+});
+
+it("should return promises in eager mode", async () => {
+  const ctx = require.context("./dir", false, /\.js$/, "eager");
+  const result = ctx("./a.js");
+  expect(result).toBeInstanceOf(Promise);
+  expect((await result).default).toBe("a");
+});
+
+it("should load each module on demand in lazy mode", async () => {
+  const ctx = require.context("./dir", false, /\.js$/, "lazy");
+  expect(ctx.keys()).toEqual(["./a.js", "./b.js"]);
+  const [a, b] = await Promise.all(ctx.keys().map((key) => ctx(key)));
+  expect(a.default).toBe("a");
+  expect(b.default).toBe("b");
+});
+
+it("should load all modules at once in lazy-once mode", async () => {
+  const ctx = require.context("./dir", false, /\.js$/, "lazy-once");
+  expect((await ctx("./b.js")).default).toBe("b");
+  expect((await ctx.import("./a.js")).default).toBe("a");
+});
+
+it("should only return already loaded modules in weak mode", () => {
+  const ctx = require.context("./weak", false, /\.js$/, "weak");
+  expect(() => ctx("./d.js")).toThrowError(/not available/);
+  expect(ctx("./c.js")).toBe("c");
+  require("./weak/c.js");
+});
@@ -0,0 +1 @@
+export const BASE = "base";
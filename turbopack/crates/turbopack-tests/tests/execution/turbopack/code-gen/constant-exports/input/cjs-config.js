@@ -0,0 +1,4 @@
+Object.defineProperty(exports, "__esModule", { value: true });
+
+exports.dir = "./features";
+exports.ENABLED = false;
@@ -0,0 +1,11 @@
+import { BASE } from "./base";
+
+export const MODE = "production";
+export const FEATURE = false;
+export const DERIVED = BASE;
+
+export let counter = 0;
+
+export function increment() {
+  counter++;
+}
@@ -0,0 +1,42 @@
+import { MODE, FEATURE, DERIVED, counter, increment } from "./config";
+
+it("should evaluate conditions on constant exports", () => {
+  let result;
+  if (MODE === "production") {
+    result = "production";
+  } else {
+    result = "development";
+  }
+  expect(result).toBe("production");
+});
+
+it("should remove branches which depend on constant exports", () => {
+  // The missing module would be reported as an issue if the branch wasn't
+  // removed
+  const value = FEATURE ? require("./does-not-exist") : "disabled";
+  expect(value).toBe("disabled");
+});
+
+it("should follow constant exports through imports", () => {
+  expect(DERIVED === "base" ? "followed" : "not followed").toBe("followed");
+});
+
+it("should not inline exports which can change", () => {
+  let result = counter === 0 ? "initial" : "changed";
+  expect(result).toBe("initial");
+
+  increment();
+
+  result = counter === 0 ? "initial" : "changed";
+  expect(result).toBe("changed");
+});
+
+it("should follow constant exports of required CommonJS modules", () => {
+  const base = require("./cjs-config").dir;
+  expect(require(base + "/a.js")).toBe("a");
+
+  const value = require("./cjs-config").ENABLED
+    ? require("./does-not-exist")
+    : "disabled";
+  expect(value).toBe("disabled");
+});
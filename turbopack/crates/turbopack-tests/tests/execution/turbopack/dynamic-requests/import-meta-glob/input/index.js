@@ -0,0 +1,36 @@
+import * as a from "./dir/a.js";
+import * as b from "./dir/b.js";
+
+it("should import matched modules lazily by default", async () => {
+  const modules = import.meta.glob(["./dir/*.js", "!./dir/ignored.js"]);
+
+  expect(Object.keys(modules)).toEqual(["./dir/a.js", "./dir/b.js"]);
+  expect(typeof modules["./dir/a.js"]).toBe("function");
+  await expect(modules["./dir/a.js"]()).resolves.toBe(a);
+  await expect(modules["./dir/b.js"]()).resolves.toBe(b);
+});
+
+it("should import matched modules eagerly", () => {
+  const modules = import.meta.glob("./dir/**/*.js", { eager: true });
+
+  expect(Object.keys(modules)).toEqual([
+    "./dir/a.js",
+    "./dir/b.js",
+    "./dir/ignored.js",
+    "./dir/nested/c.js",
+  ]);
+  expect(modules["./dir/a.js"]).toBe(a);
+  expect(modules["./dir/nested/c.js"].name).toBe("c");
+});
+
+it("should only import the selected export", async () => {
+  const lazy = import.meta.glob("./dir/*.js", { import: "name" });
+  await expect(lazy["./dir/b.js"]()).resolves.toBe("b");
+
+  const eager = import.meta.glob("./dir/*.js", {
+    import: "default",
+    eager: true,
+  });
+  expect(eager["./dir/a.js"]).toBe("default a");
+  expect(eager["./dir/ignored.js"]).toBeUndefined();
+});
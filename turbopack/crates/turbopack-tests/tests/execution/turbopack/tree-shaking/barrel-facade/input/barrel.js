@@ -0,0 +1,3 @@
+export { a } from "./a";
+export { b as renamedB } from "./b";
+export * as ns from "./c";
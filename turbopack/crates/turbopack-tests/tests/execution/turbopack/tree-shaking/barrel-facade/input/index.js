@@ -0,0 +1,12 @@
+import { a, renamedB, ns } from "./barrel";
+import * as barrel from "./barrel";
+
+it("should import through a barrel file", () => {
+  expect(a).toBe("a");
+  expect(renamedB).toBe("b");
+  expect(ns.c).toBe("c");
+});
+
+it("should expose all exports on the barrel namespace", () => {
+  expect(Object.keys(barrel).sort()).toEqual(["a", "ns", "renamedB"]);
+});
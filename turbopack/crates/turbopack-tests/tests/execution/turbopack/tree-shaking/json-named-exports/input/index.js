@@ -0,0 +1,21 @@
+import { en } from "./translations.json";
+import translations from "./translations.json";
+import * as translationsNamespace from "./translations.json";
+import jsonc from "./config.jsonc";
+import { name as json5Name, hex } from "./config.json5";
+
+it("should support named imports of top-level properties", () => {
+  expect(en).toEqual({ hello: "Hello" });
+  expect(translations.de).toEqual({ hello: "Hallo" });
+  expect(translationsNamespace["key-with-dashes"]).toBe(true);
+  expect(translationsNamespace.default).toBe(translations);
+});
+
+it("should support JSON with comments", () => {
+  expect(jsonc).toEqual({ name: "config", features: ["a", "b"] });
+});
+
+it("should support JSON5", () => {
+  expect(json5Name).toBe("config");
+  expect(hex).toBe(16);
+});
@@ -0,0 +1,42 @@
+import * as lib from "./lib";
+import * as escaped from "./escaped";
+import * as deleted from "./deleted";
+import * as stringNames from "./string-names";
+
+it("should read exports from a namespace import", () => {
+  expect(lib.a).toBe("a");
+  expect(lib["b"]).toBe("b");
+  expect(lib.getCounter()).toBe(0);
+});
+
+it("should read unknown exports as undefined", () => {
+  expect(lib.optional).toBeUndefined();
+  expect(lib.optional ?? "fallback").toBe("fallback");
+});
+
+it("should keep live bindings through a namespace import", () => {
+  lib.increment();
+  expect(lib.getCounter()).toBe(1);
+  expect(lib.counter).toBe(1);
+});
+
+it("should keep the whole namespace when it escapes", () => {
+  const ns = escaped;
+  expect(Object.keys(ns).sort()).toEqual(["x", "y"]);
+  expect(escaped.x).toBe("x");
+});
+
+it("should treat deleting from a namespace as escaping", () => {
+  try {
+    delete deleted.z;
+  } catch {
+    // namespace properties are not configurable
+  }
+  expect(deleted.z).toBe("z");
+});
+
+it("should re-export string names", () => {
+  expect(stringNames["a-b"]).toBe("a");
+  expect(stringNames["b c"]).toBe("b");
+  expect(stringNames["lib-ns"].a).toBe("a");
+});
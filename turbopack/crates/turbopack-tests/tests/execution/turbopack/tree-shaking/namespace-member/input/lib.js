@@ -0,0 +1,11 @@
+export const a = "a";
+export const b = "b";
+export let counter = 0;
+
+export function increment() {
+  counter++;
+}
+
+export function getCounter() {
+  return counter;
+}
@@ -0,0 +1,2 @@
+export { a as "a-b", b as "b c" } from "./lib";
+export * as "lib-ns" from "./lib";
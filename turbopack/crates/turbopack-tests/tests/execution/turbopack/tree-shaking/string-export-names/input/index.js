@@ -0,0 +1,14 @@
+import { "a-b" as ab, "c d" as cd, plain } from "./reexports";
+import * as reexports from "./reexports";
+
+it("should import string export names", () => {
+  expect(ab).toBe("a-b");
+  expect(cd).toBe("c d");
+  expect(plain).toBe("plain");
+});
+
+it("should expose string export names on the namespace", () => {
+  expect(reexports["a-b"]).toBe("a-b");
+  expect(reexports["c d"]).toBe("c d");
+  expect(reexports["ns-name"].plain).toBe("plain");
+});
@@ -0,0 +1,5 @@
+const ab = "a-b";
+const cd = "c d";
+
+export { ab as "a-b", cd as "c d" };
+export const plain = "plain";
@@ -0,0 +1,3 @@
+export { "a-b", "c d" as "c d" } from "./module";
+export { plain } from "./module";
+export * as "ns-name" from "./module";
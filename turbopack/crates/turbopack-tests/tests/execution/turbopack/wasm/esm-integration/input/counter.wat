@@ -0,0 +1,18 @@
+(module
+  (import "./env.js" "memory" (memory 1))
+  (import "./env.js" "log-value" (func $log (param i32)))
+  (import "./step.js" "memory" (global $step i32))
+  (global $counter (export "counter") (mut i32) (i32.const 0))
+  (global $max (export "max") i32 (i32.const 100))
+  (func (export "increment") (result i32)
+    (global.set $counter
+      (i32.add
+        (global.get $counter)
+        (global.get $step)))
+    (i32.store
+      (i32.const 0)
+      (global.get $counter))
+    (call $log
+      (global.get $counter))
+    (global.get $counter))
+)
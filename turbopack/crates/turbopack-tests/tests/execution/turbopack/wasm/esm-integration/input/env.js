@@ -0,0 +1,9 @@
+export const memory = new WebAssembly.Memory({ initial: 1 });
+
+export const logged = [];
+
+function logValue(value) {
+  logged.push(value);
+}
+
+export { logValue as "log-value" };
@@ -0,0 +1,22 @@
+import { logged, memory } from "./env.js";
+
+const counterAsyncModule = import("./counter.wat");
+
+describe("wasm esm integration", () => {
+  it("should expose globals as live bindings", async () => {
+    const counter = await counterAsyncModule;
+
+    expect(counter.max).toEqual(100);
+    expect(counter.counter).toEqual(0);
+    expect(counter.increment()).toEqual(2);
+    expect(counter.counter).toEqual(2);
+  });
+
+  it("should pass imported memory and functions", async () => {
+    const counter = await counterAsyncModule;
+
+    const value = counter.increment();
+    expect(new Int32Array(memory.buffer)[0]).toEqual(value);
+    expect(logged[logged.length - 1]).toEqual(value);
+  });
+});
@@ -0,0 +1,2 @@
+// has the same name as the memory import of env.js
+export const memory = 2;
@@ -0,0 +1,14 @@
+(module
+  (import "wasi_snapshot_preview1" "args_sizes_get"
+    (func $args_sizes_get (param i32 i32) (result i32)))
+  (memory (export "memory") 1)
+  (global $argc (export "argc") (mut i32) (i32.const -1))
+  (func (export "_start")
+    (drop
+      (call $args_sizes_get
+        (i32.const 0)
+        (i32.const 4)))
+    (global.set $argc
+      (i32.load
+        (i32.const 0))))
+)
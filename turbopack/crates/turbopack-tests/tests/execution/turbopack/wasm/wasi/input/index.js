@@ -0,0 +1,19 @@
+const commandAsyncModule = import("./command.wat");
+const reactorAsyncModule = import("./reactor.wat");
+
+describe("wasi", () => {
+  it("should run the start function of commands", async () => {
+    const command = await commandAsyncModule;
+
+    // The arguments of the process are not passed by default.
+    expect(command.argc).toEqual(0);
+  });
+
+  it("should initialize reactors", async () => {
+    const reactor = await reactorAsyncModule;
+
+    expect(reactor.initialized).toEqual(1);
+    // The environment of the process is not passed by default.
+    expect(reactor.environCount()).toEqual(0);
+  });
+});
@@ -92,6 +92,8 @@ struct SnapshotOptions {
     use_swc_css: bool,
     #[serde(default)]
     tree_shaking_mode: Option<TreeShakingMode>,
+    #[serde(default)]
+    hot_module_replacement: bool,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -119,6 +121,7 @@ impl Default for SnapshotOptions {
             environment: Default::default(),
             use_swc_css: Default::default(),
             tree_shaking_mode: Default::default(),
+            hot_module_replacement: Default::default(),
         }
     }
 }
@@ -268,6 +271,7 @@ async fn run_test(resource: RcStr) -> Result<Vc<FileSystemPath>> {
                     ..Default::default()
                 })),
                 ignore_dynamic_requests: true,
+                enable_hot_module_replacement: options.hot_module_replacement,
                 ..Default::default()
             },
             css: CssOptionsContext {
@@ -319,8 +323,8 @@ async fn run_test(resource: RcStr) -> Result<Vc<FileSystemPath>> {
     let static_root_path = path.join("static".into());
 
     let chunking_context: Vc<Box<dyn ChunkingContext>> = match options.runtime {
-        Runtime::Browser => Vc::upcast(
-            BrowserChunkingContext::builder(
+        Runtime::Browser => {
+            let mut builder = BrowserChunkingContext::builder(
                 project_root,
                 path,
                 path,
@@ -328,9 +332,12 @@ async fn run_test(resource: RcStr) -> Result<Vc<FileSystemPath>> {
                 static_root_path,
                 env,
                 options.runtime_type,
-            )
-            .build(),
-        ),
+            );
+            if options.hot_module_replacement {
+                builder = builder.hot_module_replacement();
+            }
+            Vc::upcast(builder.build())
+        }
         Runtime::NodeJs => Vc::upcast(
             NodeJsChunkingContext::builder(
                 project_root,
@@ -0,0 +1,5 @@
+if (module.hot) {
+  module.hot.accept();
+}
+
+module.exports = typeof module.hot;
@@ -0,0 +1,10 @@
+import "./cjs.js";
+
+if (import.meta.hot) {
+  import.meta.hot.accept();
+  import.meta.hot.dispose(() => {
+    console.log("dispose");
+  });
+}
+
+console.log(import.meta.hot.data);
@@ -0,0 +1,6 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/4e721_crates_turbopack-tests_tests_snapshot_import-meta_hot_input_index_94df42.js",
+    {},
+    {"otherChunks":["output/turbopack_crates_turbopack-tests_tests_snapshot_import-meta_hot_input_855d06._.js"],"runtimeModuleIds":["[project]/turbopack/crates/turbopack-tests/tests/snapshot/import-meta/hot/input/index.js [test] (ecmascript)"]}
+]);
+// Dummy runtime
\ No newline at end of file
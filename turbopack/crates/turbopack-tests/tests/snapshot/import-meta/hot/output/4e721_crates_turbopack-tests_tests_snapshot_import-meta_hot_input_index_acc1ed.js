@@ -0,0 +1,11 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/4e721_crates_turbopack-tests_tests_snapshot_import-meta_hot_input_index_acc1ed.js",
+    {},
+]);
+(globalThis.TURBOPACK_CHUNK_LISTS = globalThis.TURBOPACK_CHUNK_LISTS || []).push({
+  "path": "output/4e721_crates_turbopack-tests_tests_snapshot_import-meta_hot_input_index_acc1ed.js",
+  "chunks": [
+    "output/turbopack_crates_turbopack-tests_tests_snapshot_import-meta_hot_input_855d06._.js"
+  ],
+  "source": "entry"
+});
@@ -0,0 +1,28 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/turbopack_crates_turbopack-tests_tests_snapshot_import-meta_hot_input_855d06._.js", {
+
+"[project]/turbopack/crates/turbopack-tests/tests/snapshot/import-meta/hot/input/cjs.js [test] (ecmascript)": (function({ r: __turbopack_require__, f: __turbopack_module_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, M: __turbopack_modules__, l: __turbopack_load__, j: __turbopack_dynamic__, P: __turbopack_resolve_absolute_path__, U: __turbopack_relative_url__, R: __turbopack_resolve_module_id_path__, g: global, __dirname, h: __turbopack_hot__, m: module, e: exports, t: require }) { !function() {
+
+if ("TURBOPACK compile-time truthy", 1) {
+    __turbopack_hot__.accept();
+}
+module.exports = typeof __turbopack_hot__;
+
+}.call(this) }),
+"[project]/turbopack/crates/turbopack-tests/tests/snapshot/import-meta/hot/input/index.js [test] (ecmascript)": (function({ r: __turbopack_require__, f: __turbopack_module_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, M: __turbopack_modules__, l: __turbopack_load__, j: __turbopack_dynamic__, P: __turbopack_resolve_absolute_path__, U: __turbopack_relative_url__, R: __turbopack_resolve_module_id_path__, g: global, __dirname, h: __turbopack_hot__, m: module, e: exports, t: require }) { !function() {
+
+__turbopack_esm__({});
+var __TURBOPACK__imported__module__$5b$project$5d2f$turbopack$2f$crates$2f$turbopack$2d$tests$2f$tests$2f$snapshot$2f$import$2d$meta$2f$hot$2f$input$2f$cjs$2e$js__$5b$test$5d$__$28$ecmascript$29$__ = __turbopack_import__("[project]/turbopack/crates/turbopack-tests/tests/snapshot/import-meta/hot/input/cjs.js [test] (ecmascript)");
+"__TURBOPACK__ecmascript__hoisting__location__";
+;
+if ("TURBOPACK compile-time truthy", 1) {
+    __turbopack_hot__.accept();
+    __turbopack_hot__.dispose(()=>{
+        console.log("dispose");
+    });
+}
+console.log(__turbopack_hot__.data);
+
+}.call(this) }),
+}]);
+
+//# sourceMappingURL=turbopack_crates_turbopack-tests_tests_snapshot_import-meta_hot_input_855d06._.js.map
\ No newline at end of file
@@ -0,0 +1,2 @@
+import styles from "./style.css" with { type: "css" };
+console.log(styles);
@@ -0,0 +1,6 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/b1abf_turbopack-tests_tests_snapshot_imports_unknown-import-type_input_index_564a62.js",
+    {},
+    {"otherChunks":["output/b1abf_turbopack-tests_tests_snapshot_imports_unknown-import-type_input_index_62f10b.js"],"runtimeModuleIds":["[project]/turbopack/crates/turbopack-tests/tests/snapshot/imports/unknown-import-type/input/index.js [test] (ecmascript)"]}
+]);
+// Dummy runtime
\ No newline at end of file
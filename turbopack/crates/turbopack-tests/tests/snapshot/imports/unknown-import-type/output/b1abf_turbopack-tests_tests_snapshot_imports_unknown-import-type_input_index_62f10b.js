@@ -0,0 +1,19 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/b1abf_turbopack-tests_tests_snapshot_imports_unknown-import-type_input_index_62f10b.js", {
+
+"[project]/turbopack/crates/turbopack-tests/tests/snapshot/imports/unknown-import-type/input/index.js [test] (ecmascript)": (({ r: __turbopack_require__, f: __turbopack_module_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, M: __turbopack_modules__, l: __turbopack_load__, j: __turbopack_dynamic__, P: __turbopack_resolve_absolute_path__, U: __turbopack_relative_url__, R: __turbopack_resolve_module_id_path__, g: global, __dirname }) => (() => {
+"use strict";
+
+__turbopack_esm__({});
+(()=>{
+    const e = new Error("Cannot find module './style.css'");
+    e.code = 'MODULE_NOT_FOUND';
+    throw e;
+})();
+"__TURBOPACK__ecmascript__hoisting__location__";
+;
+console.log(styles);
+
+})()),
+}]);
+
+//# sourceMappingURL=b1abf_turbopack-tests_tests_snapshot_imports_unknown-import-type_input_index_62f10b.js.map
\ No newline at end of file
@@ -0,0 +1,10 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/4c35f_tests_snapshot_runtime_default_browser_build_runtime_input_index_8ccdd3.js", {
+
+"[project]/turbopack/crates/turbopack-tests/tests/snapshot/runtime/default_browser_build_runtime/input/index.js [test] (ecmascript)": (function({ r: __turbopack_require__, f: __turbopack_module_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, M: __turbopack_modules__, l: __turbopack_load__, j: __turbopack_dynamic__, P: __turbopack_resolve_absolute_path__, U: __turbopack_relative_url__, R: __turbopack_resolve_module_id_path__, g: global, __dirname, m: module, e: exports, t: require }) { !function() {
+
+console.log("Hello, world!");
+
+}.call(this) }),
+}]);
+
+//# sourceMappingURL=4c35f_tests_snapshot_runtime_default_browser_build_runtime_input_index_8ccdd3.js.map
\ No newline at end of file
@@ -342,17 +342,14 @@ relativeURL.prototype = URL.prototype;
 }
 /**
  * This file contains runtime types and functions that are shared between all
- * Turbopack *development* ECMAScript runtimes.
+ * Turbopack ECMAScript runtimes for the browser, both in development and in
+ * production.
  *
  * It will be appended to the runtime code of each runtime right after the
- * shared runtime utils.
- */ /* eslint-disable @typescript-eslint/no-unused-vars */ /// <reference path="../../../../shared/runtime-utils.ts" />
+ * shared runtime utils, and is followed by either the development or the
+ * production base.
+ */ /* eslint-disable @typescript-eslint/no-unused-vars */ /// <reference path="../../../shared/runtime-utils.ts" />
 /// <reference path="./globals.d.ts" />
-/// <reference path="./protocol.d.ts" />
-/// <reference path="./extensions.d.ts" />
-// This file must not use `import` and `export` statements. Otherwise, it
-// becomes impossible to augment interfaces declared in `<reference>`d files
-// (e.g. `Module`). Hence, the need for `import()` here.
 let SourceType;
 (function(SourceType) {
     /**
@@ -364,53 +361,11 @@ let SourceType;
    */ SourceType[SourceType["Parent"] = 1] = "Parent";
     /**
    * The module was instantiated because it was included in a chunk's hot module
-   * update.
+   * update. Only used by the development runtime.
    */ SourceType[SourceType["Update"] = 2] = "Update";
 })(SourceType || (SourceType = {}));
-class UpdateApplyError extends Error {
-    name = "UpdateApplyError";
-    dependencyChain;
-    constructor(message, dependencyChain){
-        super(message);
-        this.dependencyChain = dependencyChain;
-    }
-}
 const moduleFactories = Object.create(null);
 const moduleCache = Object.create(null);
-/**
- * Maps module IDs to persisted data between executions of their hot module
- * implementation (`hot.data`).
- */ const moduleHotData = new Map();
-/**
- * Maps module instances to their hot module state.
- */ const moduleHotState = new Map();
-/**
- * Modules that call `module.hot.invalidate()` (while being updated).
- */ const queuedInvalidatedModules = new Set();
-/**
- * Module IDs that are instantiated as part of the runtime of a chunk.
- */ const runtimeModules = new Set();
-/**
- * Map from module ID to the chunks that contain this module.
- *
- * In HMR, we need to keep track of which modules are contained in which so
- * chunks. This is so we don't eagerly dispose of a module when it is removed
- * from chunk A, but still exists in chunk B.
- */ const moduleChunksMap = new Map();
-/**
- * Map from a chunk path to all modules it contains.
- */ const chunkModulesMap = new Map();
-/**
- * Chunk lists that contain a runtime. When these chunk lists receive an update
- * that can't be reconciled with the current state of the page, we need to
- * reload the runtime entirely.
- */ const runtimeChunkLists = new Set();
-/**
- * Map from a chunk list to the chunk paths it contains.
- */ const chunkListChunksMap = new Map();
-/**
- * Map from a chunk path to the chunk lists it belongs to.
- */ const chunkChunkListsMap = new Map();
 const availableModules = new Map();
 const availableModuleChunks = new Map();
 async function loadChunk(source, chunkData) {
@@ -500,6 +455,88 @@ async function loadChunkPath(source, chunkPath) {
         return exported?.default ?? exported;
     };
 }
+/**
+ * no-op for browser
+ * @param modulePath
+ */ function resolveAbsolutePath(modulePath) {
+    return `/ROOT/${modulePath ?? ""}`;
+}
+/**
+ * Gets or instantiates a runtime module.
+ */ function getOrInstantiateRuntimeModule(moduleId, chunkPath) {
+    const module = moduleCache[moduleId];
+    if (module) {
+        if (module.error) {
+            throw module.error;
+        }
+        return module;
+    }
+    return instantiateModule(moduleId, {
+        type: 0,
+        chunkPath
+    });
+}
+/**
+ * Returns the URL relative to the origin where a chunk can be fetched from.
+ */ function getChunkRelativeUrl(chunkPath) {
+    return `${CHUNK_BASE_PATH}${chunkPath.split("/").map((p)=>encodeURIComponent(p)).join("/")}`;
+}
+/**
+ * This file contains runtime types and functions that are only used by the
+ * Turbopack *development* ECMAScript runtimes for the browser: tracking module
+ * parents and chunk membership, and applying hot module updates.
+ *
+ * It will be appended to the runtime code of each runtime right after the
+ * shared browser runtime base (`browser/runtime/base/runtime-base.ts`).
+ */ /* eslint-disable @typescript-eslint/no-unused-vars */ /// <reference path="../../../runtime/base/runtime-base.ts" />
+/// <reference path="./globals.d.ts" />
+/// <reference path="./protocol.d.ts" />
+/// <reference path="./extensions.d.ts" />
+// This file must not use `import` and `export` statements. Otherwise, it
+// becomes impossible to augment interfaces declared in `<reference>`d files
+// (e.g. `Module`). Hence, the need for `import()` here.
+class UpdateApplyError extends Error {
+    name = "UpdateApplyError";
+    dependencyChain;
+    constructor(message, dependencyChain){
+        super(message);
+        this.dependencyChain = dependencyChain;
+    }
+}
+/**
+ * Maps module IDs to persisted data between executions of their hot module
+ * implementation (`hot.data`).
+ */ const moduleHotData = new Map();
+/**
+ * Maps module instances to their hot module state.
+ */ const moduleHotState = new Map();
+/**
+ * Modules that call `module.hot.invalidate()` (while being updated).
+ */ const queuedInvalidatedModules = new Set();
+/**
+ * Module IDs that are instantiated as part of the runtime of a chunk.
+ */ const runtimeModules = new Set();
+/**
+ * Map from module ID to the chunks that contain this module.
+ *
+ * In HMR, we need to keep track of which modules are contained in which so
+ * chunks. This is so we don't eagerly dispose of a module when it is removed
+ * from chunk A, but still exists in chunk B.
+ */ const moduleChunksMap = new Map();
+/**
+ * Map from a chunk path to all modules it contains.
+ */ const chunkModulesMap = new Map();
+/**
+ * Chunk lists that contain a runtime. When these chunk lists receive an update
+ * that can't be reconciled with the current state of the page, we need to
+ * reload the runtime entirely.
+ */ const runtimeChunkLists = new Set();
+/**
+ * Map from a chunk list to the chunk paths it contains.
+ */ const chunkListChunksMap = new Map();
+/**
+ * Map from a chunk path to the chunk lists it belongs to.
+ */ const chunkChunkListsMap = new Map();
 function instantiateModule(id, source) {
     const moduleFactory = moduleFactories[id];
     if (typeof moduleFactory !== "function") {
@@ -508,13 +545,13 @@ function instantiateModule(id, source) {
         // and contains e.g. a `require("something")` call.
         let instantiationReason;
         switch(source.type){
-            case 0:
+            case SourceType.Runtime:
                 instantiationReason = `as a runtime entry of chunk ${source.chunkPath}`;
                 break;
-            case 1:
+            case SourceType.Parent:
                 instantiationReason = `because it was required from module ${source.parentId}`;
                 break;
-            case 2:
+            case SourceType.Update:
                 instantiationReason = "because of an HMR update";
                 break;
             default:
@@ -526,18 +563,18 @@ function instantiateModule(id, source) {
     const { hot, hotState } = createModuleHot(id, hotData);
     let parents;
     switch(source.type){
-        case 0:
+        case SourceType.Runtime:
             runtimeModules.add(id);
             parents = [];
             break;
-        case 1:
+        case SourceType.Parent:
             // No need to add this module as a child of the parent module here, this
             // has already been taken care of in `getOrInstantiateModuleFromParent`.
             parents = [
                 source.parentId
             ];
             break;
-        case 2:
+        case SourceType.Update:
             parents = source.parents || [];
             break;
         default:
@@ -558,7 +595,7 @@ function instantiateModule(id, source) {
     // NOTE(alexkirsz) This can fail when the module encounters a runtime error.
     try {
         const sourceInfo = {
-            type: 1,
+            type: SourceType.Parent,
             parentId: id
         };
         runModuleExecutionHooks(module, (refresh)=>{
@@ -584,6 +621,7 @@ function instantiateModule(id, source) {
                 P: resolveAbsolutePath,
                 U: relativeURL,
                 k: refresh,
+                h: hot,
                 R: createResolvePathFromModule(r),
                 __dirname: module.id.replace(/(^|\/)\/+$/, "")
             }));
@@ -599,12 +637,6 @@ function instantiateModule(id, source) {
     }
     return module;
 }
-/**
- * no-op for browser
- * @param modulePath
- */ function resolveAbsolutePath(modulePath) {
-    return `/ROOT/${modulePath ?? ""}`;
-}
 /**
  * NOTE(alexkirsz) Webpack has a "module execution" interception hook that
  * Next.js' React Refresh runtime hooks into to add module context to the
@@ -641,7 +673,7 @@ function instantiateModule(id, source) {
         return module;
     }
     return instantiateModule(id, {
-        type: 1,
+        type: SourceType.Parent,
         parentId: sourceModule.id
     });
 };
@@ -852,7 +884,7 @@ function applyPhase(outdatedSelfAcceptedModules, newModuleFactories, outdatedMod
     for (const { moduleId, errorHandler } of outdatedSelfAcceptedModules){
         try {
             instantiateModule(moduleId, {
-                type: 2,
+                type: SourceType.Update,
                 parents: outdatedModuleParents.get(moduleId)
             });
         } catch (err) {
@@ -898,14 +930,14 @@ function applyChunkListUpdate(update) {
             switch(chunkUpdate.type){
                 case "added":
                     BACKEND.loadChunk(chunkPath, {
-                        type: 2
+                        type: SourceType.Update
                     });
                     break;
                 case "total":
-                    BACKEND.reloadChunk?.(chunkPath);
+                    DEV_BACKEND.reloadChunk?.(chunkPath);
                     break;
                 case "deleted":
-                    BACKEND.unloadChunk?.(chunkPath);
+                    DEV_BACKEND.unloadChunk?.(chunkPath);
                     break;
                 case "partial":
                     invariant(chunkUpdate.instruction, (instruction)=>`Unknown partial instruction: ${JSON.stringify(instruction)}.`);
@@ -1104,7 +1136,7 @@ function handleApply(chunkListPath, update) {
                 // This indicates that there is no way to apply the update to the
                 // current state of the application, and that the application must be
                 // restarted.
-                BACKEND.restart();
+                DEV_BACKEND.restart();
                 break;
             }
         case "notFound":
@@ -1114,7 +1146,7 @@ function handleApply(chunkListPath, update) {
                 // If it is a dynamic import, we simply discard all modules that the chunk has exclusive access to.
                 // If it is a runtime chunk list, we restart the application.
                 if (runtimeChunkLists.has(chunkListPath)) {
-                    BACKEND.restart();
+                    DEV_BACKEND.restart();
                 } else {
                     disposeChunkList(chunkListPath);
                 }
@@ -1256,7 +1288,7 @@ function createModuleHot(moduleId, hotData) {
     }
     // We must also dispose of the chunk list's chunk itself to ensure it may
     // be reloaded properly in the future.
-    BACKEND.unloadChunk?.(chunkListPath);
+    DEV_BACKEND.unloadChunk?.(chunkListPath);
     return true;
 }
 /**
@@ -1266,7 +1298,7 @@ function createModuleHot(moduleId, hotData) {
  */ function disposeChunk(chunkPath) {
     // This should happen whether the chunk has any modules in it or not.
     // For instance, CSS chunks have no modules in them, but they still need to be unloaded.
-    BACKEND.unloadChunk?.(chunkPath);
+    DEV_BACKEND.unloadChunk?.(chunkPath);
     const chunkModules = chunkModulesMap.get(chunkPath);
     if (chunkModules == null) {
         return false;
@@ -1288,30 +1320,10 @@ function createModuleHot(moduleId, hotData) {
  * Instantiates a runtime module.
  */ function instantiateRuntimeModule(moduleId, chunkPath) {
     return instantiateModule(moduleId, {
-        type: 0,
-        chunkPath
-    });
-}
-/**
- * Gets or instantiates a runtime module.
- */ function getOrInstantiateRuntimeModule(moduleId, chunkPath) {
-    const module = moduleCache[moduleId];
-    if (module) {
-        if (module.error) {
-            throw module.error;
-        }
-        return module;
-    }
-    return instantiateModule(moduleId, {
-        type: 0,
+        type: SourceType.Runtime,
         chunkPath
     });
 }
-/**
- * Returns the URL relative to the origin where a chunk can be fetched from.
- */ function getChunkRelativeUrl(chunkPath) {
-    return `${CHUNK_BASE_PATH}${chunkPath.split("/").map((p)=>encodeURIComponent(p)).join("/")}`;
-}
 /**
  * Subscribes to chunk list updates from the update server and applies them.
  */ function registerChunkList(chunkUpdateProvider, chunkList) {
@@ -1366,13 +1378,17 @@ globalThis.TURBOPACK_CHUNK_LISTS = {
     }
 };
 /**
- * This file contains the runtime code specific to the Turbopack development
- * ECMAScript DOM runtime.
+ * This file contains the runtime code specific to the Turbopack ECMAScript DOM
+ * runtime.
  *
- * It will be appended to the base development runtime code.
+ * It will be appended to the base runtime code. The development runtime
+ * additionally appends `dev/runtime/dom/dev-backend-dom.ts`.
  */ /* eslint-disable @typescript-eslint/no-unused-vars */ /// <reference path="../base/runtime-base.ts" />
-/// <reference path="../../../../shared/require-type.d.ts" />
+/// <reference path="../../../shared/require-type.d.ts" />
 let BACKEND;
+/**
+ * Maps chunk paths to the corresponding resolver.
+ */ const chunkResolvers = new Map();
 function augmentContext(context) {
     return context;
 }
@@ -1406,87 +1422,17 @@ async function loadWebAssemblyModule(_source, wasmChunkPath) {
                     type: SourceType.Runtime,
                     chunkPath
                 }, otherChunkData)));
-            if (params.runtimeModuleIds.length > 0) {
-                for (const moduleId of params.runtimeModuleIds){
-                    getOrInstantiateRuntimeModule(moduleId, chunkPath);
-                }
+            for (const moduleId of params.runtimeModuleIds){
+                getOrInstantiateRuntimeModule(moduleId, chunkPath);
             }
         },
         loadChunk (chunkPath, source) {
             return doLoadChunk(chunkPath, source);
-        },
-        unloadChunk (chunkPath) {
-            deleteResolver(chunkPath);
-            const chunkUrl = getChunkRelativeUrl(chunkPath);
-            // TODO(PACK-2140): remove this once all filenames are guaranteed to be escaped.
-            const decodedChunkUrl = decodeURI(chunkUrl);
-            if (chunkPath.endsWith(".css")) {
-                const links = document.querySelectorAll(`link[href="${chunkUrl}"],link[href^="${chunkUrl}?"],link[href="${decodedChunkUrl}"],link[href^="${decodedChunkUrl}?"]`);
-                for (const link of Array.from(links)){
-                    link.remove();
-                }
-            } else if (chunkPath.endsWith(".js")) {
-                // Unloading a JS chunk would have no effect, as it lives in the JS
-                // runtime once evaluated.
-                // However, we still want to remove the script tag from the DOM to keep
-                // the HTML somewhat consistent from the user's perspective.
-                const scripts = document.querySelectorAll(`script[src="${chunkUrl}"],script[src^="${chunkUrl}?"],script[src="${decodedChunkUrl}"],script[src^="${decodedChunkUrl}?"]`);
-                for (const script of Array.from(scripts)){
-                    script.remove();
-                }
-            } else {
-                throw new Error(`can't infer type of chunk from path ${chunkPath}`);
-            }
-        },
-        reloadChunk (chunkPath) {
-            return new Promise((resolve, reject)=>{
-                if (!chunkPath.endsWith(".css")) {
-                    reject(new Error("The DOM backend can only reload CSS chunks"));
-                    return;
-                }
-                const chunkUrl = getChunkRelativeUrl(chunkPath);
-                const decodedChunkUrl = decodeURI(chunkUrl);
-                const previousLinks = document.querySelectorAll(`link[rel=stylesheet][href="${chunkUrl}"],link[rel=stylesheet][href^="${chunkUrl}?"],link[rel=stylesheet][href="${decodedChunkUrl}"],link[rel=stylesheet][href^="${decodedChunkUrl}?"]`);
-                if (previousLinks.length === 0) {
-                    reject(new Error(`No link element found for chunk ${chunkPath}`));
-                    return;
-                }
-                const link = document.createElement("link");
-                link.rel = "stylesheet";
-                if (navigator.userAgent.includes("Firefox")) {
-                    // Firefox won't reload CSS files that were previously loaded on the current page,
-                    // we need to add a query param to make sure CSS is actually reloaded from the server.
-                    //
-                    // I believe this is this issue: https://bugzilla.mozilla.org/show_bug.cgi?id=1037506
-                    //
-                    // Safari has a similar issue, but only if you have a `<link rel=preload ... />` tag
-                    // pointing to the same URL as the stylesheet: https://bugs.webkit.org/show_bug.cgi?id=187726
-                    link.href = `${chunkUrl}?ts=${Date.now()}`;
-                } else {
-                    link.href = chunkUrl;
-                }
-                link.onerror = ()=>{
-                    reject();
-                };
-                link.onload = ()=>{
-                    // First load the new CSS, then remove the old ones. This prevents visible
-                    // flickering that would happen in-between removing the previous CSS and
-                    // loading the new one.
-                    for (const previousLink of Array.from(previousLinks))previousLink.remove();
-                    // CSS chunks do not register themselves, and as such must be marked as
-                    // loaded instantly.
-                    resolve();
-                };
-                // Make sure to insert the new CSS right after the previous one, so that
-                // its precedence is higher.
-                previousLinks[0].parentElement.insertBefore(link, previousLinks[0].nextSibling);
-            });
-        },
-        restart: ()=>self.location.reload()
+        }
     };
     /**
-   * Maps chunk paths to the corresponding resolver.
-   */ const chunkResolvers = new Map();
+   * Web workers have no DOM, so chunks have to be loaded with the worker APIs.
+   */ const isWorker = typeof document === "undefined";
     function getOrCreateResolver(chunkPath) {
         let resolver = chunkResolvers.get(chunkPath);
         if (!resolver) {
@@ -1509,9 +1455,6 @@ async function loadWebAssemblyModule(_source, wasmChunkPath) {
         }
         return resolver;
     }
-    function deleteResolver(chunkPath) {
-        chunkResolvers.delete(chunkPath);
-    }
     /**
    * Loads the given chunk, and returns a promise that resolves once the chunk
    * has been loaded.
@@ -1520,7 +1463,7 @@ async function loadWebAssemblyModule(_source, wasmChunkPath) {
         if (resolver.resolved) {
             return resolver.promise;
         }
-        if (source.type === SourceType.Runtime) {
+        if (source.type === SourceType.Runtime && !isWorker) {
             // We don't need to load chunks references from runtime code, as they're already
             // present in the DOM.
             if (chunkPath.endsWith(".css")) {
@@ -1534,6 +1477,10 @@ async function loadWebAssemblyModule(_source, wasmChunkPath) {
             return resolver.promise;
         }
         const chunkUrl = getChunkRelativeUrl(chunkPath);
+        if (isWorker) {
+            loadChunkInWorker(chunkPath, chunkUrl, resolver);
+            return resolver.promise;
+        }
         const decodedChunkUrl = decodeURI(chunkUrl);
         if (chunkPath.endsWith(".css")) {
             const previousLinks = document.querySelectorAll(`link[rel=stylesheet][href="${chunkUrl}"],link[rel=stylesheet][href^="${chunkUrl}?"],link[rel=stylesheet][href="${decodedChunkUrl}"],link[rel=stylesheet][href^="${decodedChunkUrl}?"]`);
@@ -1581,6 +1528,114 @@ async function loadWebAssemblyModule(_source, wasmChunkPath) {
         }
         return resolver.promise;
     }
+    /**
+   * Loads a chunk from within a web worker. Classic workers load scripts with
+   * `importScripts`, while module workers only support `import()`.
+   */ function loadChunkInWorker(chunkPath, chunkUrl, resolver) {
+        if (chunkPath.endsWith(".css")) {
+            // Stylesheets can't be applied within a worker.
+            resolver.resolve();
+        } else if (chunkPath.endsWith(".js")) {
+            // We'll only mark the chunk as loaded once the script has been executed,
+            // which happens in `registerChunk`.
+            try {
+                importScripts(chunkUrl);
+            } catch (error) {
+                if (!(error instanceof TypeError)) {
+                    resolver.reject(error);
+                    return;
+                }
+                // `importScripts` throws a `TypeError` in module workers.
+                import(chunkUrl).catch((error)=>resolver.reject(error));
+            }
+        } else {
+            throw new Error(`can't infer type of chunk from path ${chunkPath}`);
+        }
+    }
+})();
+/**
+ * This file contains the development-only runtime code specific to the
+ * Turbopack ECMAScript DOM runtime: unloading and reloading chunks for HMR.
+ *
+ * It will be appended to the base development runtime code and the shared DOM
+ * runtime backend.
+ */ /* eslint-disable @typescript-eslint/no-unused-vars */ /// <reference path="../base/runtime-base.ts" />
+/// <reference path="../../../runtime/dom/runtime-backend-dom.ts" />
+let DEV_BACKEND;
+(()=>{
+    DEV_BACKEND = {
+        unloadChunk (chunkPath) {
+            deleteResolver(chunkPath);
+            const chunkUrl = getChunkRelativeUrl(chunkPath);
+            // TODO(PACK-2140): remove this once all filenames are guaranteed to be escaped.
+            const decodedChunkUrl = decodeURI(chunkUrl);
+            if (chunkPath.endsWith(".css")) {
+                const links = document.querySelectorAll(`link[href="${chunkUrl}"],link[href^="${chunkUrl}?"],link[href="${decodedChunkUrl}"],link[href^="${decodedChunkUrl}?"]`);
+                for (const link of Array.from(links)){
+                    link.remove();
+                }
+            } else if (chunkPath.endsWith(".js")) {
+                // Unloading a JS chunk would have no effect, as it lives in the JS
+                // runtime once evaluated.
+                // However, we still want to remove the script tag from the DOM to keep
+                // the HTML somewhat consistent from the user's perspective.
+                const scripts = document.querySelectorAll(`script[src="${chunkUrl}"],script[src^="${chunkUrl}?"],script[src="${decodedChunkUrl}"],script[src^="${decodedChunkUrl}?"]`);
+                for (const script of Array.from(scripts)){
+                    script.remove();
+                }
+            } else {
+                throw new Error(`can't infer type of chunk from path ${chunkPath}`);
+            }
+        },
+        reloadChunk (chunkPath) {
+            return new Promise((resolve, reject)=>{
+                if (!chunkPath.endsWith(".css")) {
+                    reject(new Error("The DOM backend can only reload CSS chunks"));
+                    return;
+                }
+                const chunkUrl = getChunkRelativeUrl(chunkPath);
+                const decodedChunkUrl = decodeURI(chunkUrl);
+                const previousLinks = document.querySelectorAll(`link[rel=stylesheet][href="${chunkUrl}"],link[rel=stylesheet][href^="${chunkUrl}?"],link[rel=stylesheet][href="${decodedChunkUrl}"],link[rel=stylesheet][href^="${decodedChunkUrl}?"]`);
+                if (previousLinks.length === 0) {
+                    reject(new Error(`No link element found for chunk ${chunkPath}`));
+                    return;
+                }
+                const link = document.createElement("link");
+                link.rel = "stylesheet";
+                if (navigator.userAgent.includes("Firefox")) {
+                    // Firefox won't reload CSS files that were previously loaded on the current page,
+                    // we need to add a query param to make sure CSS is actually reloaded from the server.
+                    //
+                    // I believe this is this issue: https://bugzilla.mozilla.org/show_bug.cgi?id=1037506
+                    //
+                    // Safari has a similar issue, but only if you have a `<link rel=preload ... />` tag
+                    // pointing to the same URL as the stylesheet: https://bugs.webkit.org/show_bug.cgi?id=187726
+                    link.href = `${chunkUrl}?ts=${Date.now()}`;
+                } else {
+                    link.href = chunkUrl;
+                }
+                link.onerror = ()=>{
+                    reject();
+                };
+                link.onload = ()=>{
+                    // First load the new CSS, then remove the old ones. This prevents visible
+                    // flickering that would happen in-between removing the previous CSS and
+                    // loading the new one.
+                    for (const previousLink of Array.from(previousLinks))previousLink.remove();
+                    // CSS chunks do not register themselves, and as such must be marked as
+                    // loaded instantly.
+                    resolve();
+                };
+                // Make sure to insert the new CSS right after the previous one, so that
+                // its precedence is higher.
+                previousLinks[0].parentElement.insertBefore(link, previousLinks[0].nextSibling);
+            });
+        },
+        restart: ()=>self.location.reload()
+    };
+    function deleteResolver(chunkPath) {
+        chunkResolvers.delete(chunkPath);
+    }
 })();
 function _eval({ code, url, map }) {
     code += `\n\n//# sourceURL=${encodeURI(location.origin + CHUNK_BASE_PATH + url)}`;
@@ -0,0 +1,6 @@
+const worker = new Worker(new URL("./worker.js", import.meta.url));
+
+worker.addEventListener("message", (event) => {
+  console.log(event.data);
+});
+worker.postMessage("ping");
@@ -0,0 +1,12 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/4e721_crates_turbopack-tests_tests_snapshot_workers_basic_input_worker_da77c8.js", {
+
+"[project]/turbopack/crates/turbopack-tests/tests/snapshot/workers/basic/input/worker.js [test] (ecmascript)": (function({ r: __turbopack_require__, f: __turbopack_module_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, M: __turbopack_modules__, l: __turbopack_load__, j: __turbopack_dynamic__, P: __turbopack_resolve_absolute_path__, U: __turbopack_relative_url__, R: __turbopack_resolve_module_id_path__, g: global, __dirname, m: module, e: exports, t: require }) { !function() {
+
+self.addEventListener("message", (event)=>{
+    self.postMessage(`${event.data} pong`);
+});
+
+}.call(this) }),
+}]);
+
+//# sourceMappingURL=4e721_crates_turbopack-tests_tests_snapshot_workers_basic_input_worker_da77c8.js.map
\ No newline at end of file
@@ -0,0 +1,3 @@
+const worker = new Worker(new URL("./worker.css", import.meta.url));
+
+console.log(worker);
@@ -0,0 +1,22 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push(["output/4e721_crates_turbopack-tests_tests_snapshot_workers_not-evaluatable_input_d67769._.js", {
+
+"[project]/turbopack/crates/turbopack-tests/tests/snapshot/workers/not-evaluatable/input/worker.css [test] (css, worker loader)": (({ r: __turbopack_require__, f: __turbopack_module_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, M: __turbopack_modules__, l: __turbopack_load__, j: __turbopack_dynamic__, P: __turbopack_resolve_absolute_path__, U: __turbopack_relative_url__, R: __turbopack_resolve_module_id_path__, g: global, __dirname }) => (() => {
+
+throw new Error("worker module is not evaluatable");
+
+})()),
+"[project]/turbopack/crates/turbopack-tests/tests/snapshot/workers/not-evaluatable/input/index.js [test] (ecmascript)": (function({ r: __turbopack_require__, f: __turbopack_module_context__, i: __turbopack_import__, s: __turbopack_esm__, v: __turbopack_export_value__, n: __turbopack_export_namespace__, c: __turbopack_cache__, M: __turbopack_modules__, l: __turbopack_load__, j: __turbopack_dynamic__, P: __turbopack_resolve_absolute_path__, U: __turbopack_relative_url__, R: __turbopack_resolve_module_id_path__, g: global, __dirname, m: module, e: exports, t: require }) { !function() {
+
+const __TURBOPACK__import$2e$meta__ = {
+    get url () {
+        return `file://${__turbopack_resolve_absolute_path__("turbopack/crates/turbopack-tests/tests/snapshot/workers/not-evaluatable/input/index.js")}`;
+    }
+};
+"__TURBOPACK__ecmascript__hoisting__location__";
+const worker = new Worker(__turbopack_require__("[project]/turbopack/crates/turbopack-tests/tests/snapshot/workers/not-evaluatable/input/worker.css [test] (css, worker loader)"));
+console.log(worker);
+
+}.call(this) }),
+}]);
+
+//# sourceMappingURL=4e721_crates_turbopack-tests_tests_snapshot_workers_not-evaluatable_input_d67769._.js.map
\ No newline at end of file
@@ -0,0 +1,6 @@
+(globalThis.TURBOPACK = globalThis.TURBOPACK || []).push([
+    "output/b1abf_turbopack-tests_tests_snapshot_workers_not-evaluatable_input_index_34a362.js",
+    {},
+    {"otherChunks":["output/4e721_crates_turbopack-tests_tests_snapshot_workers_not-evaluatable_input_d67769._.js"],"runtimeModuleIds":["[project]/turbopack/crates/turbopack-tests/tests/snapshot/workers/not-evaluatable/input/index.js [test] (ecmascript)"]}
+]);
+// Dummy runtime
\ No newline at end of file
@@ -1,24 +1,44 @@
 use std::collections::BTreeMap;
 
-use anyhow::Result;
-use turbo_tasks::Vc;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use turbo_tasks::{debug::ValueDebugFormat, trace::TraceRawVcs, Vc};
 use turbo_tasks_fs::FileContent;
 use turbopack_core::asset::Asset;
-use wasmparser::{Chunk, Parser, Payload};
+use wasmparser::{Chunk, ExternalKind, Parser, Payload, TypeRef};
 
 use crate::source::WebAssemblySource;
 
+/// The kind of an exported item of a WebAssembly module.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs, ValueDebugFormat,
+)]
+pub(crate) enum WebAssemblyExportKind {
+    Function,
+    Table,
+    Memory,
+    Global { mutable: bool },
+    Tag,
+}
+
 /// Imports and exports of a WebAssembly file.
 #[turbo_tasks::value]
 #[derive(Default)]
 pub(crate) struct WebAssemblyAnalysis {
+    /// The imported names, grouped by module.
     pub imports: BTreeMap<String, Vec<String>>,
-    pub exports: Vec<String>,
+    pub exports: Vec<(String, WebAssemblyExportKind)>,
+}
+
+impl WebAssemblyAnalysis {
+    pub fn has_export(&self, name: &str) -> bool {
+        self.exports.iter().any(|(export, _)| export == name)
+    }
 }
 
 /// Analyse a WebAssembly file.
 ///
-/// Extracts imports and exports.
+/// Extracts imports and exports, including the kinds of the exports.
 #[turbo_tasks::function]
 pub(crate) async fn analyze(source: Vc<WebAssemblySource>) -> Result<Vc<WebAssemblyAnalysis>> {
     let content = source.content().file_content().await?;
@@ -31,6 +51,10 @@ pub(crate) async fn analyze(source: Vc<WebAssemblySource>) -> Result<Vc<WebAssem
 
     let mut bytes = &*file.content().to_bytes()?;
 
+    // The mutability of the globals in the global index space, which starts
+    // with the imported globals.
+    let mut globals = Vec::new();
+
     let mut parser = Parser::new(0);
     loop {
         let payload = match parser.parse(bytes, true)? {
@@ -47,18 +71,41 @@ pub(crate) async fn analyze(source: Vc<WebAssemblySource>) -> Result<Vc<WebAssem
                 for import in s {
                     let import = import?;
 
-                    analysis
+                    if let TypeRef::Global(ty) = import.ty {
+                        globals.push(ty.mutable);
+                    }
+
+                    let items = analysis
                         .imports
                         .entry(import.module.to_string())
-                        .or_default()
-                        .push(import.name.to_string());
+                        .or_default();
+                    if !items.iter().any(|item| item == import.name) {
+                        items.push(import.name.to_string());
+                    }
+                }
+            }
+            Payload::GlobalSection(s) => {
+                for global in s {
+                    globals.push(global?.ty.mutable);
                 }
             }
             Payload::ExportSection(s) => {
                 for export in s {
                     let export = export?;
 
-                    analysis.exports.push(export.name.to_string());
+                    let kind = match export.kind {
+                        ExternalKind::Func => WebAssemblyExportKind::Function,
+                        ExternalKind::Table => WebAssemblyExportKind::Table,
+                        ExternalKind::Memory => WebAssemblyExportKind::Memory,
+                        ExternalKind::Global => WebAssemblyExportKind::Global {
+                            mutable: *globals.get(export.index as usize).with_context(|| {
+                                format!("export `{}` refers to a missing global", export.name)
+                            })?,
+                        },
+                        ExternalKind::Tag => WebAssemblyExportKind::Tag,
+                    };
+
+                    analysis.exports.push((export.name.to_string(), kind));
                 }
             }
 
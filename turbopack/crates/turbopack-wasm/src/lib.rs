@@ -3,7 +3,8 @@
 //! WASM assets are copied directly to the output folder.
 //!
 //! When imported from ES modules, they produce a thin module that loads and
-//! instantiates the WebAssembly module.
+//! instantiates the WebAssembly module. WASI preview 1 imports can be provided
+//! by `node:wasi`, see [wasi::WasiOptions].
 
 #![feature(min_specialization)]
 #![feature(arbitrary_self_types)]
@@ -14,6 +15,7 @@ pub mod module_asset;
 pub(crate) mod output_asset;
 pub mod raw;
 pub mod source;
+pub mod wasi;
 
 pub fn register() {
     turbo_tasks::register();
@@ -7,50 +7,178 @@ use turbo_tasks_fs::File;
 use turbopack_core::{asset::AssetContent, source::Source, virtual_source::VirtualSource};
 use turbopack_ecmascript::utils::StringifyJs;
 
-use crate::{analysis::analyze, source::WebAssemblySource};
+use crate::{
+    analysis::{analyze, WebAssemblyExportKind},
+    source::WebAssemblySource,
+    wasi::{WasiOptions, WASI_PREVIEW1_MODULE},
+};
+
+/// Returns `name` as it can be used in import and export specifiers, which
+/// is a string literal when it isn't an identifier.
+fn module_export_name(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        name.to_string()
+    } else {
+        StringifyJs(name).to_string()
+    }
+}
 
 /// Create a javascript loader to instantiate the WebAssembly module with the
 /// necessary imports and exports to be processed by [turbopack_ecmascript].
+///
+/// This follows the semantics of the WebAssembly ESM integration proposal:
+/// Imports are resolved as javascript modules and passed to the instance as
+/// they are, so they can be functions, `WebAssembly.Memory`,
+/// `WebAssembly.Table` or `WebAssembly.Global` objects. Exported globals are
+/// exposed as their values, which are kept up to date after calls of exported
+/// functions when the globals are mutable.
+///
+/// When `wasi` is set, the `wasi_snapshot_preview1` imports are provided by
+/// `node:wasi` instead.
 #[turbo_tasks::function]
 pub(crate) async fn instantiating_loader_source(
     source: Vc<WebAssemblySource>,
+    wasi: Option<Vc<WasiOptions>>,
 ) -> Result<Vc<Box<dyn Source>>> {
     let analysis = analyze(source).await?;
+    let wasi = match wasi {
+        Some(wasi) if analysis.imports.contains_key(WASI_PREVIEW1_MODULE) => Some(wasi.await?),
+        _ => None,
+    };
 
     let mut code = String::new();
 
+    if wasi.is_some() {
+        writeln!(code, "import {{ WASI }} from \"node:wasi\";")?;
+    }
+
     let mut imports_obj = "{".to_string();
+    let mut import_count = 0;
     for (path, items) in &analysis.imports {
-        writeln!(
-            code,
-            "import {{ {} }} from {};",
-            items.join(", "),
-            StringifyJs(path)
-        )?;
+        if wasi.is_some() && path == WASI_PREVIEW1_MODULE {
+            writeln!(
+                imports_obj,
+                "\n    {}: __wasi.wasiImport,",
+                StringifyJs(path)
+            )?;
+            continue;
+        }
 
+        let mut specifiers = Vec::with_capacity(items.len());
         writeln!(imports_obj, "\n    {}: {{", StringifyJs(path))?;
         for item in items {
-            writeln!(imports_obj, "        {}: {},", StringifyJs(item), item)?;
+            let local = format!("__wasm_import_{import_count}");
+            import_count += 1;
+            specifiers.push(format!("{} as {local}", module_export_name(item)));
+            writeln!(imports_obj, "        {}: {local},", StringifyJs(item))?;
         }
         writeln!(imports_obj, "    }},")?;
+
+        writeln!(
+            code,
+            "import {{ {} }} from {};",
+            specifiers.join(", "),
+            StringifyJs(path)
+        )?;
     }
-    writeln!(imports_obj, "}}")?;
+    write!(imports_obj, "}}")?;
 
     writeln!(code, "import wasmPath from \"WASM_PATH\";")?;
 
     writeln!(code)?;
 
-    writedoc!(
-        code,
-        r#"
-            const {{ {exports} }} = await __turbopack_wasm__(wasmPath, {imports});
+    if let Some(wasi) = &wasi {
+        let (args, env) = if wasi.inherit_env {
+            ("process.argv", "process.env")
+        } else {
+            ("[]", "{}")
+        };
+        writedoc!(
+            code,
+            r#"
+                const __wasi = new WASI({{
+                    version: "preview1",
+                    args: {args},
+                    env: {env},
+                    preopens: {preopens},
+                }});
+
+            "#,
+            preopens = StringifyJs(&wasi.preopens),
+        )?;
+    }
 
-            export {{ {exports} }};
-        "#,
-        imports = imports_obj,
-        exports = analysis.exports.join(", "),
+    writeln!(
+        code,
+        "const __wasm_exports = await __turbopack_wasm__(wasmPath, {imports_obj});",
     )?;
 
+    if wasi.is_some() {
+        // Commands run their `_start` function when loaded, reactors are
+        // initialized.
+        let method = if analysis.has_export("_start") {
+            "start"
+        } else {
+            "initialize"
+        };
+        writeln!(code, "\n__wasi.{method}({{ exports: __wasm_exports }});")?;
+    }
+
+    let has_mutable_globals = analysis
+        .exports
+        .iter()
+        .any(|(_, kind)| matches!(kind, WebAssemblyExportKind::Global { mutable: true }));
+
+    let mut exports = Vec::with_capacity(analysis.exports.len());
+    let mut sync_globals = String::new();
+    writeln!(code)?;
+    for (i, (name, kind)) in analysis.exports.iter().enumerate() {
+        let local = format!("__wasm_export_{i}");
+        let export = format!("__wasm_exports[{}]", StringifyJs(name));
+        match kind {
+            WebAssemblyExportKind::Global { mutable: true } => {
+                writeln!(code, "let {local} = {export}.value;")?;
+                writeln!(sync_globals, "    {local} = {export}.value;")?;
+            }
+            WebAssemblyExportKind::Global { mutable: false } => {
+                writeln!(code, "const {local} = {export}.value;")?;
+            }
+            // Mutable globals can only change while the module runs, so they
+            // are synced after each call.
+            WebAssemblyExportKind::Function if has_mutable_globals => {
+                writeln!(
+                    code,
+                    "const {local} = (...args) => {{ try {{ return {export}(...args); }} finally \
+                     {{ __wasm_sync_globals(); }} }};"
+                )?;
+            }
+            _ => {
+                writeln!(code, "const {local} = {export};")?;
+            }
+        }
+        exports.push(format!("{local} as {}", module_export_name(name)));
+    }
+
+    if has_mutable_globals {
+        writedoc!(
+            code,
+            r#"
+
+                function __wasm_sync_globals() {{
+                {sync_globals}}}
+            "#,
+        )?;
+    }
+
+    if !exports.is_empty() {
+        writeln!(code, "\nexport {{ {} }};", exports.join(", "))?;
+    }
+
     let code: RcStr = code.into();
 
     Ok(Vc::upcast(VirtualSource::new(
@@ -26,6 +26,7 @@ use crate::{
     output_asset::WebAssemblyAsset,
     raw::RawWebAssemblyModuleAsset,
     source::WebAssemblySource,
+    wasi::WasiOptions,
 };
 
 #[turbo_tasks::function]
@@ -40,6 +41,7 @@ fn modifier() -> Vc<RcStr> {
 pub struct WebAssemblyModuleAsset {
     source: Vc<WebAssemblySource>,
     asset_context: Vc<Box<dyn AssetContext>>,
+    wasi: Option<Vc<WasiOptions>>,
 }
 
 #[turbo_tasks::value_impl]
@@ -48,10 +50,12 @@ impl WebAssemblyModuleAsset {
     pub fn new(
         source: Vc<WebAssemblySource>,
         asset_context: Vc<Box<dyn AssetContext>>,
+        wasi: Option<Vc<WasiOptions>>,
     ) -> Vc<Self> {
         Self::cell(WebAssemblyModuleAsset {
             source,
             asset_context,
+            wasi,
         })
     }
 
@@ -68,7 +72,7 @@ impl WebAssemblyModuleAsset {
         let loader_source = if query == "?module" {
             compiling_loader_source(this.source)
         } else {
-            instantiating_loader_source(this.source)
+            instantiating_loader_source(this.source, this.wasi)
         };
 
         let module = this.asset_context.process(
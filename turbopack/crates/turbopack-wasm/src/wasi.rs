@@ -0,0 +1,21 @@
+use std::collections::BTreeMap;
+
+use turbo_tasks::RcStr;
+
+/// The import module name of WASI preview 1.
+pub const WASI_PREVIEW1_MODULE: &str = "wasi_snapshot_preview1";
+
+/// Options for the WASI preview 1 shim, which provides the
+/// `wasi_snapshot_preview1` imports of WebAssembly modules via `node:wasi`.
+///
+/// The shim is only supported when targeting Node.js.
+#[turbo_tasks::value(shared)]
+#[derive(Clone, Debug, Default)]
+pub struct WasiOptions {
+    /// Passes the command line arguments and the environment variables of the
+    /// Node.js process to the WebAssembly module.
+    pub inherit_env: bool,
+    /// The directories the WebAssembly module can access, mapped from the
+    /// path seen by the module to the path on the host.
+    pub preopens: BTreeMap<RcStr, RcStr>,
+}
@@ -0,0 +1,464 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use anyhow::Result;
+use petgraph::{algo::tarjan_scc, graph::NodeIndex, visit::EdgeRef, Graph};
+use turbo_tasks::{RcStr, TryJoinIterExt, ValueToString, Vc};
+use turbo_tasks_fs::{glob::Glob, FileSystemPath};
+use turbopack_core::{
+    chunk::{ChunkableModuleReference, ChunkingType},
+    issue::{
+        Issue, IssueExt, IssueSeverity, IssueSource, IssueStage, OptionIssueSource,
+        OptionStyledString, StyledString,
+    },
+    module::{Module, Modules},
+    reference::ModuleReference,
+};
+use turbopack_ecmascript::{
+    analyzer::graph::is_in_function,
+    chunk::EcmascriptChunkPlaceable,
+    references::{
+        cjs::{CjsAssetReference, CjsRequireAssetReference},
+        esm::EsmAssetReference,
+    },
+};
+
+use super::is_in_node_modules;
+
+/// Configures the detection of circular dependencies.
+#[turbo_tasks::value(shared)]
+#[derive(Clone, Debug)]
+pub struct CircularDependencyOptions {
+    /// Glob patterns of module paths, relative to the project, which are
+    /// allowed to be part of a cycle.
+    /// A cycle is only reported when at least one of its modules doesn't match
+    /// any of the patterns, so known cycles can be allowlisted while new
+    /// cycles are still reported.
+    pub allow: Vec<RcStr>,
+    /// The severity of the reported issues. Use [IssueSeverity::Error] to fail
+    /// the build on cycles.
+    pub severity: IssueSeverity,
+}
+
+impl Default for CircularDependencyOptions {
+    fn default() -> Self {
+        Self {
+            allow: Vec::new(),
+            severity: IssueSeverity::Warning,
+        }
+    }
+}
+
+/// A reference which is evaluated synchronously when the referencing module is
+/// evaluated.
+struct Edge {
+    to: Vc<Box<dyn Module>>,
+    issue_source: Option<Vc<IssueSource>>,
+}
+
+async fn issue_source(reference: Vc<Box<dyn ModuleReference>>) -> Result<Option<Vc<IssueSource>>> {
+    if let Some(r) = Vc::try_resolve_downcast_type::<EsmAssetReference>(reference).await? {
+        return Ok(r.await?.issue_source);
+    }
+    if let Some(r) = Vc::try_resolve_downcast_type::<CjsRequireAssetReference>(reference).await? {
+        return Ok(Some(r.await?.issue_source));
+    }
+    Ok(None)
+}
+
+/// Returns whether modules referenced by `reference` are evaluated
+/// synchronously when the referencing module is evaluated, e.g. by an ESM
+/// import or a top level `require()`, in contrast to `import()` or a
+/// `require()` in a function body.
+async fn is_sync_reference(reference: Vc<Box<dyn ModuleReference>>) -> Result<bool> {
+    // ESM imports are hoisted, so they are always evaluated with the module.
+    if let Some(r) = Vc::try_resolve_downcast_type::<CjsRequireAssetReference>(reference).await? {
+        if is_in_function(&r.await?.path.await?) {
+            return Ok(false);
+        }
+    }
+    // These reference files which are spawned as processes or resolved, but not
+    // evaluated by the module, e.g. `child_process.spawn("node", [file])`.
+    if Vc::try_resolve_downcast_type::<CjsAssetReference>(reference)
+        .await?
+        .is_some()
+    {
+        return Ok(false);
+    }
+    let Some(chunkable) =
+        Vc::try_resolve_sidecast::<Box<dyn ChunkableModuleReference>>(reference).await?
+    else {
+        return Ok(false);
+    };
+    Ok(matches!(
+        *chunkable.chunking_type().await?,
+        Some(
+            ChunkingType::Parallel | ChunkingType::ParallelInheritAsync | ChunkingType::Passthrough
+        )
+    ))
+}
+
+/// Returns the synchronous references between ecmascript modules, and all
+/// referenced modules, to continue the traversal.
+async fn module_edges(
+    module: Vc<Box<dyn Module>>,
+) -> Result<(Vec<Edge>, Vec<Vc<Box<dyn Module>>>)> {
+    let mut edges = Vec::new();
+    let mut referenced = Vec::new();
+    for &reference in module.references().await?.iter() {
+        let modules = reference.resolve_reference().primary_modules().await?;
+        referenced.extend(modules.iter().copied());
+        if !is_sync_reference(reference).await? {
+            continue;
+        }
+        let issue_source = issue_source(reference).await?;
+        for &to in modules.iter() {
+            if Vc::try_resolve_sidecast::<Box<dyn EcmascriptChunkPlaceable>>(to)
+                .await?
+                .is_some()
+            {
+                edges.push(Edge {
+                    to: to.resolve().await?,
+                    issue_source,
+                });
+            }
+        }
+    }
+    Ok((edges, referenced))
+}
+
+/// Finds cycles of synchronous references between ecmascript modules in the
+/// module graph starting at `entries`, and reports each of them as an issue.
+///
+/// One issue is emitted per strongly connected component, showing the shortest
+/// import chain through it. The allowlist of the `options` is matched against
+/// paths relative to `project_path`.
+#[turbo_tasks::function]
+pub async fn detect_circular_dependencies(
+    entries: Vc<Modules>,
+    project_path: Vc<FileSystemPath>,
+    options: Vc<CircularDependencyOptions>,
+) -> Result<Vc<()>> {
+    let options = options.await?;
+    let project = project_path.await?;
+
+    let mut graph = Graph::<Vc<Box<dyn Module>>, Option<Vc<IssueSource>>>::new();
+    let mut nodes = HashMap::new();
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+
+    for &entry in entries.await?.iter() {
+        let entry = entry.resolve().await?;
+        if visited.insert(entry) {
+            queue.push_back(entry);
+        }
+    }
+
+    while let Some(module) = queue.pop_front() {
+        let (edges, referenced) = module_edges(module).await?;
+        let from = *nodes
+            .entry(module)
+            .or_insert_with(|| graph.add_node(module));
+        for Edge { to, issue_source } in edges {
+            let to_node = *nodes.entry(to).or_insert_with(|| graph.add_node(to));
+            graph.add_edge(from, to_node, issue_source);
+        }
+        for module in referenced {
+            let module = module.resolve().await?;
+            if visited.insert(module) {
+                queue.push_back(module);
+            }
+        }
+    }
+
+    let allow = options
+        .allow
+        .iter()
+        .map(|pattern| Glob::new(pattern.clone()))
+        .try_join()
+        .await?;
+
+    for component in tarjan_scc(&graph) {
+        let is_cycle = match component.as_slice() {
+            [node] => graph.contains_edge(*node, *node),
+            _ => true,
+        };
+        if !is_cycle {
+            continue;
+        }
+
+        let graph = &graph;
+        let paths = component
+            .iter()
+            .map(|&node| async move { graph[node].ident().path().await })
+            .try_join()
+            .await?;
+        if paths.iter().all(|path| is_in_node_modules(&path.path)) {
+            // Cycles in third party code can't be fixed by the user
+            continue;
+        }
+        // Modules outside of the project never match the allowlist.
+        if !allow.is_empty()
+            && paths.iter().all(|path| {
+                project
+                    .get_path_to(path)
+                    .is_some_and(|path| allow.iter().any(|glob| glob.execute(path)))
+            })
+        {
+            continue;
+        }
+
+        // Start the cycle at the module with the smallest path, so the reported
+        // chain is stable.
+        let (start, _) = component
+            .iter()
+            .zip(paths.iter())
+            .min_by_key(|(_, path)| &path.path)
+            .unwrap();
+        let cycle = shortest_cycle(&graph, *start, &component);
+
+        let mut chain = Vec::new();
+        for (node, issue_source) in cycle {
+            let location = match issue_source {
+                Some(issue_source) => issue_source
+                    .into_plain()
+                    .await?
+                    .range
+                    .map(|(start, _)| (start.line + 1, start.column + 1)),
+                None => None,
+            };
+            chain.push(ChainItem {
+                node,
+                description: graph[node].ident().path().to_string().await?.clone_value(),
+                location,
+                issue_source,
+            });
+        }
+        collapse_chain(&mut chain);
+        if chain.len() < 2 {
+            // All modules of the cycle belong to the same file, e.g. a facade
+            // and the module it wraps, which is not a cycle between files.
+            continue;
+        }
+
+        CircularDependencyIssue {
+            path: graph[chain[0].node].ident().path(),
+            chain: chain
+                .iter()
+                .map(|item| match item.location {
+                    Some((line, column)) => {
+                        format!("{}:{}:{}", item.description, line, column).into()
+                    }
+                    None => item.description.clone(),
+                })
+                .collect(),
+            modules: component.len(),
+            source: chain[0].issue_source,
+            severity: options.severity,
+        }
+        .cell()
+        .emit();
+    }
+
+    Ok(Default::default())
+}
+
+struct ChainItem {
+    node: NodeIndex,
+    description: RcStr,
+    /// The 1-based line and column of the reference to the next module.
+    location: Option<(usize, usize)>,
+    issue_source: Option<Vc<IssueSource>>,
+}
+
+/// Collapses consecutive modules of the same file, e.g. facades and the
+/// modules they wrap, into a single step of the cycle.
+fn collapse_chain(chain: &mut Vec<ChainItem>) {
+    chain.dedup_by(|next, prev| {
+        if prev.description == next.description {
+            prev.location = next.location.or(prev.location);
+            prev.issue_source = next.issue_source.or(prev.issue_source);
+            true
+        } else {
+            false
+        }
+    });
+    // The chain is a cycle, so the last step continues with the first one.
+    if chain.len() > 1 && chain[0].description == chain[chain.len() - 1].description {
+        let last = chain.pop().unwrap();
+        chain[0].location = chain[0].location.or(last.location);
+        chain[0].issue_source = chain[0].issue_source.or(last.issue_source);
+    }
+}
+
+/// Finds the shortest cycle through `start` within the strongly connected
+/// `component`. Returns each module of the cycle together with the source of
+/// its reference to the next module.
+fn shortest_cycle<N, E: Copy + Default>(
+    graph: &Graph<N, E>,
+    start: NodeIndex,
+    component: &[NodeIndex],
+) -> Vec<(NodeIndex, E)> {
+    let component = component.iter().copied().collect::<HashSet<_>>();
+    let mut parents = HashMap::<NodeIndex, (NodeIndex, E)>::new();
+    let mut queue = VecDeque::from([start]);
+    let mut last = None;
+    'search: while let Some(node) = queue.pop_front() {
+        for edge in graph.edges(node) {
+            let target = edge.target();
+            if !component.contains(&target) {
+                continue;
+            }
+            if target == start {
+                last = Some((node, *edge.weight()));
+                break 'search;
+            }
+            if !parents.contains_key(&target) {
+                parents.insert(target, (node, *edge.weight()));
+                queue.push_back(target);
+            }
+        }
+    }
+
+    let Some((mut node, mut issue_source)) = last else {
+        return vec![(start, E::default())];
+    };
+    let mut cycle = Vec::new();
+    loop {
+        cycle.push((node, issue_source));
+        if node == start {
+            break;
+        }
+        (node, issue_source) = parents[&node];
+    }
+    cycle.reverse();
+    cycle
+}
+
+#[turbo_tasks::value(shared)]
+struct CircularDependencyIssue {
+    path: Vc<FileSystemPath>,
+    /// The modules of the cycle, each one importing the next one, and the last
+    /// one importing the first one.
+    chain: Vec<RcStr>,
+    /// The number of modules in the strongly connected component.
+    modules: usize,
+    source: Option<Vc<IssueSource>>,
+    severity: IssueSeverity,
+}
+
+#[turbo_tasks::value_impl]
+impl Issue for CircularDependencyIssue {
+    #[turbo_tasks::function]
+    fn file_path(&self) -> Vc<FileSystemPath> {
+        self.path
+    }
+
+    #[turbo_tasks::function]
+    fn title(&self) -> Vc<StyledString> {
+        StyledString::Text("Circular dependency detected".into()).cell()
+    }
+
+    #[turbo_tasks::function]
+    fn description(&self) -> Vc<OptionStyledString> {
+        let mut lines = vec![StyledString::Text(
+            "Modules in an import cycle might be accessed before they are evaluated:".into(),
+        )];
+        lines.extend(
+            self.chain
+                .iter()
+                .chain(self.chain.first())
+                .map(|item| StyledString::Code(format!("-> {item}").into())),
+        );
+        if self.modules > self.chain.len() {
+            lines.push(StyledString::Text(
+                format!(
+                    "The cycle is part of a group of {} modules which depend on each other.",
+                    self.modules
+                )
+                .into(),
+            ));
+        }
+        Vc::cell(Some(StyledString::Stack(lines).cell()))
+    }
+
+    #[turbo_tasks::function]
+    fn severity(&self) -> Vc<IssueSeverity> {
+        self.severity.cell()
+    }
+
+    #[turbo_tasks::function]
+    fn stage(&self) -> Vc<IssueStage> {
+        IssueStage::Analysis.into()
+    }
+
+    #[turbo_tasks::function]
+    fn source(&self) -> Vc<OptionIssueSource> {
+        Vc::cell(self.source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use petgraph::{graph::NodeIndex, Graph};
+
+    use super::{collapse_chain, shortest_cycle, ChainItem};
+
+    fn chain_item(description: &str, location: Option<(usize, usize)>) -> ChainItem {
+        ChainItem {
+            node: NodeIndex::new(0),
+            description: description.into(),
+            location,
+            issue_source: None,
+        }
+    }
+
+    fn descriptions(chain: &[ChainItem]) -> Vec<&str> {
+        chain.iter().map(|item| item.description.as_str()).collect()
+    }
+
+    #[test]
+    fn finds_the_shortest_cycle() {
+        let mut graph = Graph::<&str, Option<u32>>::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b, Some(1));
+        graph.add_edge(b, c, Some(2));
+        graph.add_edge(c, a, Some(3));
+        graph.add_edge(b, a, Some(4));
+
+        assert_eq!(
+            shortest_cycle(&graph, a, &[a, b, c]),
+            vec![(a, Some(1)), (b, Some(4))]
+        );
+    }
+
+    #[test]
+    fn collapses_modules_of_the_same_file() {
+        let mut chain = vec![
+            chain_item("a.js", None),
+            chain_item("a.js", Some((1, 1))),
+            chain_item("b.js", Some((2, 1))),
+            chain_item("b.js", None),
+        ];
+        collapse_chain(&mut chain);
+        assert_eq!(descriptions(&chain), ["a.js", "b.js"]);
+        assert_eq!(chain[0].location, Some((1, 1)));
+        assert_eq!(chain[1].location, Some((2, 1)));
+    }
+
+    #[test]
+    fn collapses_the_end_of_the_cycle_into_its_start() {
+        let mut chain = vec![
+            chain_item("a.js", Some((1, 1))),
+            chain_item("b.js", Some((3, 1))),
+            chain_item("a.js", None),
+        ];
+        collapse_chain(&mut chain);
+        assert_eq!(descriptions(&chain), ["a.js", "b.js"]);
+
+        let mut chain = vec![chain_item("a.js", None), chain_item("a.js", Some((1, 1)))];
+        collapse_chain(&mut chain);
+        assert_eq!(descriptions(&chain), ["a.js"]);
+    }
+}
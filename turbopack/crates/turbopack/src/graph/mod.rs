@@ -1,9 +1,17 @@
+pub mod circular_dependencies;
+
 use std::collections::HashSet;
 
 use anyhow::Result;
 use turbo_tasks::Vc;
 use turbopack_core::output::OutputAsset;
 
+/// Whether a path, relative to a project or a file system root, is inside of
+/// a `node_modules` directory, i.e. part of a package.
+pub fn is_in_node_modules(path: &str) -> bool {
+    path.starts_with("node_modules/") || path.contains("/node_modules/")
+}
+
 #[turbo_tasks::value(shared)]
 pub enum AggregatedGraph {
     Leaf(Vc<Box<dyn OutputAsset>>),
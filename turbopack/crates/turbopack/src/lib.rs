@@ -8,11 +8,10 @@
 #![feature(arbitrary_self_types)]
 
 pub mod evaluate_context;
-mod graph;
+pub mod graph;
 pub mod module_options;
 pub mod rebase;
 pub mod transition;
-pub(crate) mod unsupported_sass;
 
 use std::{
     collections::{HashMap, HashSet},
@@ -43,8 +42,7 @@ use turbopack_core::{
     output::OutputAsset,
     raw_module::RawModule,
     reference_type::{
-        CssReferenceSubType, EcmaScriptModulesReferenceSubType, ImportWithType, InnerAssets,
-        ReferenceType,
+        CssReferenceSubType, EcmaScriptModulesReferenceSubType, InnerAssets, ReferenceType,
     },
     resolve::{
         options::ResolveOptions, origin::PlainResolveOrigin, parse::Request, resolve, ExternalType,
@@ -247,7 +245,23 @@ async fn apply_module_type(
                 }
             }
         }
-        ModuleType::Json => Vc::upcast(JsonModuleAsset::new(source)),
+        ModuleType::Json {
+            source_ty,
+            object_literal_threshold,
+        } => {
+            let module = JsonModuleAsset::new(source, *source_ty, *object_literal_threshold);
+            match part {
+                Some(part) => match *part.await? {
+                    // JSON modules have no side effects.
+                    ModulePart::Evaluation => return Ok(ProcessResult::Ignore.cell()),
+                    ModulePart::Export(export) => {
+                        Vc::upcast(module.with_export(export.await?.clone_value()))
+                    }
+                    _ => Vc::upcast(module),
+                },
+                None => Vc::upcast(module),
+            }
+        }
         ModuleType::Raw => Vc::upcast(RawModule::new(source)),
         ModuleType::CssGlobal => {
             return Ok(module_asset_context.process(
@@ -272,13 +286,17 @@ async fn apply_module_type(
                 None
             },
         )),
-        ModuleType::Static => Vc::upcast(StaticModuleAsset::new(
-            source,
-            Vc::upcast(module_asset_context),
-        )),
-        ModuleType::WebAssembly { source_ty } => Vc::upcast(WebAssemblyModuleAsset::new(
+        ModuleType::Static { inline_limit } => {
+            let module = StaticModuleAsset::new(source, Vc::upcast(module_asset_context));
+            Vc::upcast(match inline_limit {
+                Some(inline_limit) => module.with_inline_limit(*inline_limit),
+                None => module,
+            })
+        }
+        ModuleType::WebAssembly { source_ty, wasi } => Vc::upcast(WebAssemblyModuleAsset::new(
             WebAssemblySource::new(source, *source_ty),
             Vc::upcast(module_asset_context),
+            *wasi,
         )),
         ModuleType::Custom(custom) => custom.create_module(source, module_asset_context, part),
     })
@@ -465,24 +483,10 @@ async fn process_default_internal(
         _ => None,
     };
 
-    let mut has_type_attribute = false;
-
     let mut current_source = source;
-    let mut current_module_type = match &reference_type {
-        ReferenceType::EcmaScriptModules(EcmaScriptModulesReferenceSubType::ImportWithType(ty)) => {
-            has_type_attribute = true;
-
-            match ty {
-                ImportWithType::Json => Some(ModuleType::Json),
-            }
-        }
-        _ => None,
-    };
+    let mut current_module_type = None;
 
     for (i, rule) in options.await?.rules.iter().enumerate() {
-        if has_type_attribute && current_module_type.is_some() {
-            continue;
-        }
         if processed_rules.contains(&i) {
             continue;
         }
@@ -560,6 +564,9 @@ async fn process_default_internal(
                     }
                 }
             }
+            if rule.is_exclusive() {
+                break;
+            }
         }
     }
 
@@ -11,17 +11,26 @@ pub use rule_condition::*;
 use turbo_tasks::{RcStr, Vc};
 use turbo_tasks_fs::{glob::Glob, FileSystemPath};
 use turbopack_core::{
-    reference_type::{CssReferenceSubType, ReferenceType, UrlReferenceSubType},
+    reference_type::{
+        CssReferenceSubType, EcmaScriptModulesReferenceSubType, ImportWithType, ReferenceType,
+        UrlReferenceSubType,
+    },
     resolve::options::{ImportMap, ImportMapping},
 };
-use turbopack_css::CssModuleAssetType;
-use turbopack_ecmascript::{EcmascriptInputTransform, EcmascriptOptions, SpecifiedModuleType};
+use turbopack_css::{sass::SassTransform, CssModuleAssetType};
+use turbopack_ecmascript::{
+    bytes::BytesContentTransform, text::TextContentTransform, EcmascriptInputTransform,
+    EcmascriptOptions, SpecifiedModuleType,
+};
+use turbopack_image::svg::{SvgComponentTransform, SvgMinifyTransform};
+use turbopack_json::JsonSourceType;
 use turbopack_mdx::MdxTransform;
 use turbopack_node::transforms::{postcss::PostCssTransform, webpack::WebpackLoaders};
 use turbopack_wasm::source::WebAssemblySourceType;
 
 use crate::{
-    evaluate_context::node_evaluate_asset_context, resolve_options_context::ResolveOptionsContext,
+    evaluate_context::node_evaluate_asset_context, resolve_options,
+    resolve_options_context::ResolveOptionsContext,
 };
 
 #[turbo_tasks::function]
@@ -73,15 +82,23 @@ impl ModuleOptions {
                     ignore_dynamic_requests,
                     import_externals,
                     esm_url_rewrite_behavior,
+                    native_binding_detectors,
                     ref enable_typeof_window_inlining,
+                    enable_hot_module_replacement,
                     ..
                 },
             enable_mdx,
             enable_mdx_rs,
+            enable_svg_minification,
+            enable_svg_components,
+            enable_wasi,
+            json_object_literal_threshold,
+            static_inline_limit,
             css:
                 CssOptionsContext {
                     enable_raw_css,
                     use_swc_css,
+                    enable_sass,
                     ..
                 },
             ref enable_postcss_transform,
@@ -132,7 +149,9 @@ impl ModuleOptions {
             url_rewrite_behavior: esm_url_rewrite_behavior,
             import_externals,
             ignore_dynamic_requests,
+            native_binding_detectors,
             refresh,
+            hot_module_replacement: enable_hot_module_replacement,
             ..Default::default()
         };
         let ecmascript_options_vc = ecmascript_options.cell();
@@ -212,10 +231,74 @@ impl ModuleOptions {
             .collect(),
         );
 
+        let import_with_type = |ty| {
+            ModuleRuleCondition::ReferenceType(ReferenceType::EcmaScriptModules(
+                EcmaScriptModulesReferenceSubType::ImportWithType(ty),
+            ))
+        };
+
         let mut rules = vec![
+            // Import attributes and queries explicitly request a module type, so
+            // no other rules are applied to these modules.
+            ModuleRule::new(
+                import_with_type(ImportWithType::Json),
+                vec![ModuleRuleEffect::ModuleType(ModuleType::Json {
+                    source_ty: JsonSourceType::Json,
+                    object_literal_threshold: json_object_literal_threshold,
+                })],
+            )
+            .exclusive(),
+            ModuleRule::new(
+                ModuleRuleCondition::any(vec![
+                    import_with_type(ImportWithType::Text),
+                    ModuleRuleCondition::ResourceQueryEquals("?raw".to_string()),
+                ]),
+                vec![ModuleRuleEffect::SourceTransforms(Vc::cell(vec![
+                    Vc::upcast(TextContentTransform::new()),
+                ]))],
+            )
+            .exclusive(),
+            ModuleRule::new(
+                import_with_type(ImportWithType::Bytes),
+                vec![ModuleRuleEffect::SourceTransforms(Vc::cell(vec![
+                    Vc::upcast(BytesContentTransform::new()),
+                ]))],
+            )
+            .exclusive(),
+            ModuleRule::new(
+                ModuleRuleCondition::ResourceQueryEquals("?url".to_string()),
+                vec![ModuleRuleEffect::ModuleType(ModuleType::Static {
+                    inline_limit: None,
+                })],
+            )
+            .exclusive(),
+            ModuleRule::new(
+                ModuleRuleCondition::ResourceQueryEquals("?inline".to_string()),
+                vec![ModuleRuleEffect::ModuleType(ModuleType::Static {
+                    inline_limit: Some(usize::MAX),
+                })],
+            )
+            .exclusive(),
             ModuleRule::new_all(
                 ModuleRuleCondition::ResourcePathEndsWith(".json".to_string()),
-                vec![ModuleRuleEffect::ModuleType(ModuleType::Json)],
+                vec![ModuleRuleEffect::ModuleType(ModuleType::Json {
+                    source_ty: JsonSourceType::Json,
+                    object_literal_threshold: json_object_literal_threshold,
+                })],
+            ),
+            ModuleRule::new_all(
+                ModuleRuleCondition::ResourcePathEndsWith(".jsonc".to_string()),
+                vec![ModuleRuleEffect::ModuleType(ModuleType::Json {
+                    source_ty: JsonSourceType::Jsonc,
+                    object_literal_threshold: json_object_literal_threshold,
+                })],
+            ),
+            ModuleRule::new_all(
+                ModuleRuleCondition::ResourcePathEndsWith(".json5".to_string()),
+                vec![ModuleRuleEffect::ModuleType(ModuleType::Json {
+                    source_ty: JsonSourceType::Json5,
+                    object_literal_threshold: json_object_literal_threshold,
+                })],
             ),
             ModuleRule::new_all(
                 ModuleRuleCondition::any(vec![
@@ -341,7 +424,9 @@ impl ModuleOptions {
                     ModuleRuleCondition::ResourcePathEndsWith(".webp".to_string()),
                     ModuleRuleCondition::ResourcePathEndsWith(".woff2".to_string()),
                 ]),
-                vec![ModuleRuleEffect::ModuleType(ModuleType::Static)],
+                vec![ModuleRuleEffect::ModuleType(ModuleType::Static {
+                    inline_limit: static_inline_limit,
+                })],
             ),
             ModuleRule::new(
                 ModuleRuleCondition::any(vec![ModuleRuleCondition::ResourcePathEndsWith(
@@ -355,6 +440,7 @@ impl ModuleOptions {
                 )]),
                 vec![ModuleRuleEffect::ModuleType(ModuleType::WebAssembly {
                     source_ty: WebAssemblySourceType::Binary,
+                    wasi: enable_wasi,
                 })],
             ),
             ModuleRule::new(
@@ -363,6 +449,7 @@ impl ModuleOptions {
                 )]),
                 vec![ModuleRuleEffect::ModuleType(ModuleType::WebAssembly {
                     source_ty: WebAssemblySourceType::Text,
+                    wasi: enable_wasi,
                 })],
             ),
             ModuleRule::new(
@@ -376,10 +463,63 @@ impl ModuleOptions {
                 ModuleRuleCondition::ReferenceType(ReferenceType::Url(
                     UrlReferenceSubType::Undefined,
                 )),
-                vec![ModuleRuleEffect::ModuleType(ModuleType::Static)],
+                vec![ModuleRuleEffect::ModuleType(ModuleType::Static {
+                    inline_limit: None,
+                })],
             ),
         ];
 
+        if enable_sass {
+            let resolve_options = resolve_options(path, resolve_options_context);
+            let sass_module = ModuleRuleCondition::any(vec![
+                ModuleRuleCondition::ResourcePathEndsWith(".module.scss".to_string()),
+                ModuleRuleCondition::ResourcePathEndsWith(".module.sass".to_string()),
+            ]);
+            // The compiled stylesheets are renamed, so the CSS rules below apply to them.
+            rules.extend([
+                ModuleRule::new(
+                    sass_module.clone(),
+                    vec![ModuleRuleEffect::SourceTransforms(Vc::cell(vec![
+                        Vc::upcast(SassTransform::new(resolve_options, "*.module.css".into())),
+                    ]))],
+                ),
+                ModuleRule::new(
+                    ModuleRuleCondition::all(vec![
+                        ModuleRuleCondition::any(vec![
+                            ModuleRuleCondition::ResourcePathEndsWith(".scss".to_string()),
+                            ModuleRuleCondition::ResourcePathEndsWith(".sass".to_string()),
+                        ]),
+                        ModuleRuleCondition::not(sass_module),
+                    ]),
+                    vec![ModuleRuleEffect::SourceTransforms(Vc::cell(vec![
+                        Vc::upcast(SassTransform::new(resolve_options, "*.css".into())),
+                    ]))],
+                ),
+            ]);
+        }
+
+        if enable_svg_components {
+            rules.push(ModuleRule::new(
+                ModuleRuleCondition::all(vec![
+                    ModuleRuleCondition::ResourcePathEndsWith(".svg".to_string()),
+                    ModuleRuleCondition::ResourceQueryEquals("?react".to_string()),
+                ]),
+                // The component is renamed to `.jsx`, so the ecmascript rules apply to it.
+                vec![ModuleRuleEffect::SourceTransforms(Vc::cell(vec![
+                    Vc::upcast(SvgComponentTransform::new()),
+                ]))],
+            ));
+        }
+
+        if let Some(options) = enable_svg_minification {
+            rules.push(ModuleRule::new(
+                ModuleRuleCondition::ResourcePathEndsWith(".svg".to_string()),
+                vec![ModuleRuleEffect::SourceTransforms(Vc::cell(vec![
+                    Vc::upcast(SvgMinifyTransform::new(options)),
+                ]))],
+            ));
+        }
+
         if enable_raw_css {
             rules.extend([
                 ModuleRule::new(
@@ -5,11 +5,14 @@ use turbopack_core::{
     condition::ContextCondition, environment::Environment, resolve::options::ImportMapping,
 };
 use turbopack_ecmascript::{references::esm::UrlRewriteBehavior, TreeShakingMode};
+use turbopack_image::svg::SvgMinifyOptions;
 pub use turbopack_mdx::MdxTransformOptions;
 use turbopack_node::{
     execution_context::ExecutionContext,
     transforms::{postcss::PostCssTransformOptions, webpack::WebpackLoaderItems},
 };
+use turbopack_resolve::node_native_binding::NativeBindingDetectors;
+use turbopack_wasm::wasi::WasiOptions;
 
 use super::ModuleRule;
 
@@ -121,6 +124,27 @@ pub struct ModuleOptionsContext {
     // however we might want to unify them in the future.
     pub enable_mdx: bool,
     pub enable_mdx_rs: Option<Vc<MdxTransformOptions>>,
+    /// Minifies SVGs, before they are emitted as files or processed by other
+    /// rules.
+    pub enable_svg_minification: Option<Vc<SvgMinifyOptions>>,
+    /// Turns SVGs imported with a `?react` query into JSX modules which export
+    /// a React component. The JSX transform must be enabled.
+    pub enable_svg_components: bool,
+    /// Provides the WASI preview 1 imports of WebAssembly modules via
+    /// `node:wasi`. Only supported when targeting Node.js.
+    pub enable_wasi: Option<Vc<WasiOptions>>,
+    /// JSON values shorter than this many bytes are emitted as object literals
+    /// instead of `JSON.parse` calls.
+    ///
+    /// Named imports of top-level properties get a module which only contains
+    /// that property. The property is not shared with the module of the whole
+    /// value, so a chunk which also contains a default import includes it
+    /// twice, and each module is compared against this threshold separately.
+    pub json_object_literal_threshold: Option<usize>,
+    /// Static assets smaller than this many bytes are inlined as data URLs
+    /// when imported from ES modules. Assets imported with an `?inline` query
+    /// are always inlined, assets imported with an `?url` query never.
+    pub static_inline_limit: Option<usize>,
 
     pub preset_env_versions: Option<Vc<Environment>>,
     pub execution_context: Option<Vc<ExecutionContext>>,
@@ -156,6 +180,14 @@ pub struct EcmascriptOptionsContext {
     /// If false, they will reference the whole directory. If true, they won't
     /// reference anything and lead to an runtime error instead.
     pub ignore_dynamic_requests: bool,
+    /// Detects native addons of packages that are loaded in a way the code
+    /// analysis can't follow, e.g. napi-rs platform packages. This is useful
+    /// when tracing the files needed at runtime, e.g. for node-file-trace.
+    pub native_binding_detectors: Option<Vc<NativeBindingDetectors>>,
+    /// Exposes the hot module replacement API as `module.hot` and
+    /// `import.meta.hot`. When disabled, both evaluate to `undefined` and calls
+    /// on them are removed.
+    pub enable_hot_module_replacement: bool,
 
     pub placeholder_for_future_extensions: (),
 }
@@ -171,6 +203,9 @@ pub struct CssOptionsContext {
     /// the module graph, but neither asset types can be emitted directly.
     pub enable_raw_css: bool,
     pub use_swc_css: bool,
+    /// Compiles Sass stylesheets (`.scss` and `.sass`) to CSS. `.module.scss`
+    /// and `.module.sass` files are compiled to CSS modules.
+    pub enable_sass: bool,
 
     pub placeholder_for_future_extensions: (),
 }
@@ -7,7 +7,8 @@ use turbopack_core::{
 };
 use turbopack_css::CssModuleAssetType;
 use turbopack_ecmascript::{EcmascriptInputTransforms, EcmascriptOptions};
-use turbopack_wasm::source::WebAssemblySourceType;
+use turbopack_json::JsonSourceType;
+use turbopack_wasm::{source::WebAssemblySourceType, wasi::WasiOptions};
 
 use super::{CustomModuleType, ModuleRuleCondition};
 
@@ -16,6 +17,7 @@ pub struct ModuleRule {
     condition: ModuleRuleCondition,
     effects: Vec<ModuleRuleEffect>,
     match_mode: MatchMode,
+    exclusive: bool,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TraceRawVcs)]
@@ -44,6 +46,7 @@ impl ModuleRule {
             condition,
             effects,
             match_mode: MatchMode::NonInternal,
+            exclusive: false,
         }
     }
 
@@ -53,6 +56,7 @@ impl ModuleRule {
             condition,
             effects,
             match_mode: MatchMode::Internal,
+            exclusive: false,
         }
     }
 
@@ -62,9 +66,22 @@ impl ModuleRule {
             condition,
             effects,
             match_mode: MatchMode::All,
+            exclusive: false,
         }
     }
 
+    /// Makes the rule exclusive: When it matches, no other rules are applied
+    /// to the module. This is used for module types which are explicitly
+    /// requested by the import, like import attributes or queries.
+    pub fn exclusive(mut self) -> Self {
+        self.exclusive = true;
+        self
+    }
+
+    pub fn is_exclusive(&self) -> bool {
+        self.exclusive
+    }
+
     pub fn effects(&self) -> impl Iterator<Item = &ModuleRuleEffect> {
         self.effects.iter()
     }
@@ -116,7 +133,10 @@ pub enum ModuleType {
         #[turbo_tasks(trace_ignore)]
         options: Vc<EcmascriptOptions>,
     },
-    Json,
+    Json {
+        source_ty: JsonSourceType,
+        object_literal_threshold: Option<usize>,
+    },
     Raw,
     CssGlobal,
     CssModule,
@@ -124,9 +144,13 @@ pub enum ModuleType {
         ty: CssModuleAssetType,
         use_swc_css: bool,
     },
-    Static,
+    Static {
+        /// Assets smaller than this many bytes are inlined as data URLs.
+        inline_limit: Option<usize>,
+    },
     WebAssembly {
         source_ty: WebAssemblySourceType,
+        wasi: Option<Vc<WasiOptions>>,
     },
     Custom(Vc<Box<dyn CustomModuleType>>),
 }
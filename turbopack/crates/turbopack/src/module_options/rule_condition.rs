@@ -20,6 +20,9 @@ pub enum ModuleRuleCondition {
     ResourcePathInDirectory(String),
     ResourcePathInExactDirectory(ReadRef<FileSystemPath>),
     ResourcePathRegex(#[turbo_tasks(trace_ignore)] Regex),
+    /// Matches the query of the resource including the leading `?`, e.g.
+    /// `?react`.
+    ResourceQueryEquals(String),
     /// For paths that are within the same filesystem as the `base`, it need to
     /// match the relative path from base to resource. This includes `./` or
     /// `../` prefix. For paths in a different filesystem, it need to match
@@ -79,6 +82,9 @@ impl ModuleRuleCondition {
             }
             ModuleRuleCondition::ResourcePathEquals(other) => path == &**other,
             ModuleRuleCondition::ResourcePathEndsWith(end) => path.path.ends_with(end),
+            ModuleRuleCondition::ResourceQueryEquals(query) => {
+                &**source.ident().query().await? == query.as_str()
+            }
             ModuleRuleCondition::ResourcePathHasNoExtension => {
                 if let Some(i) = path.path.rfind('.') {
                     if let Some(j) = path.path.rfind('/') {
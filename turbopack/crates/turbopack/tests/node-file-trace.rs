@@ -45,7 +45,10 @@ use turbopack_core::{
     output::OutputAsset,
     reference_type::ReferenceType,
 };
-use turbopack_resolve::resolve_options_context::ResolveOptionsContext;
+use turbopack_resolve::{
+    node_native_binding::default_native_binding_detectors,
+    resolve_options_context::ResolveOptionsContext,
+};
 
 #[global_allocator]
 static ALLOC: turbo_tasks_malloc::TurboMalloc = turbo_tasks_malloc::TurboMalloc;
@@ -125,6 +128,10 @@ static ALLOC: turbo_tasks_malloc::TurboMalloc = turbo_tasks_malloc::TurboMalloc;
 )]
 #[case::mongoose("integration/mongoose.js")]
 #[case::mysql("integration/mysql.js")]
+#[case::native_bindings_dlopen("integration/native-bindings/dlopen.js")]
+#[case::native_bindings_napi_rs("integration/native-bindings/napi-rs.js")]
+#[case::native_bindings_napi_rs_local("integration/native-bindings/napi-rs-local.js")]
+#[case::native_bindings_prebuildify("integration/native-bindings/prebuildify.js")]
 #[case::npm("integration/npm.js")]
 // unable to resolve esm request module 'spdx-license-ids' in
 // node-file-trace/node_modules/npm/node_modules/spdx-correct oracledb doesn't support non x86
@@ -430,6 +437,7 @@ fn node_file_trace<B: Backend + 'static>(
                     ModuleOptionsContext {
                         ecmascript: EcmascriptOptionsContext {
                             enable_types: true,
+                            native_binding_detectors: Some(default_native_binding_detectors()),
                             ..Default::default()
                         },
                         css: CssOptionsContext {
@@ -0,0 +1,31 @@
+// Loading the placeholder binaries fails, but with a different error than a
+// missing file, so the output tells whether the binary was traced.
+function describe(load) {
+  try {
+    load();
+    return "loaded";
+  } catch (e) {
+    if (
+      e.code === "MODULE_NOT_FOUND" ||
+      /no such file|not found|could not be found/i.test(e.message)
+    ) {
+      return "missing";
+    }
+    return "found";
+  }
+}
+
+// The platform triple napi-rs uses to name its binaries and packages.
+function napiTriple() {
+  const { platform, arch } = process;
+  if (platform === "linux") {
+    const { glibcVersionRuntime } = process.report.getReport().header;
+    return `linux-${arch}-${glibcVersionRuntime ? "gnu" : "musl"}`;
+  }
+  if (platform === "win32") {
+    return `win32-${arch}-msvc`;
+  }
+  return `${platform}-${arch}`;
+}
+
+module.exports = { describe, napiTriple };
@@ -0,0 +1,7 @@
+const path = require("path");
+const { describe } = require("./describe");
+
+const addon = { exports: {} };
+console.log(
+  describe(() => process.dlopen(addon, path.join(__dirname, "addon.node")))
+);
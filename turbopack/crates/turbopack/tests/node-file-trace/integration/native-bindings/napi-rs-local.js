@@ -0,0 +1,2 @@
+const binding = require("napi-rs-local-fixture");
+console.log(binding);
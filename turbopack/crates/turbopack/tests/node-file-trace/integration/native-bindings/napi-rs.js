@@ -0,0 +1,2 @@
+const binding = require("napi-rs-fixture");
+console.log(binding);
@@ -0,0 +1,2 @@
+const binding = require("prebuildify-fixture");
+console.log(binding);